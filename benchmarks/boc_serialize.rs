@@ -0,0 +1,45 @@
+mod benchmark_utils;
+use std::hint::black_box;
+use std::io::sink;
+use std::sync::LazyLock;
+
+use criterion::{Criterion, criterion_group, criterion_main};
+use ton_core::cell::{BoC, BocWriter, TonCell};
+
+static CELL_SHARD: LazyLock<TonCell> = LazyLock::new(|| {
+    let hex = include_str!("../resources/tests/shard_block_6000000000000000_52111590.hex");
+    BoC::from_hex(hex).unwrap().single_root().unwrap()
+});
+
+// cargo bench --bench boc_serialize
+fn benchmark_functions(c: &mut Criterion) {
+    run_bench!(c, to_bytes_shard);
+    run_bench!(c, to_bytes_shard_crc32);
+    run_bench!(c, boc_writer_shard);
+    run_bench!(c, boc_writer_shard_crc32);
+}
+
+fn to_bytes_shard() {
+    let bytes = BoC::new(CELL_SHARD.clone()).to_bytes(false).unwrap();
+    black_box(bytes);
+}
+
+fn to_bytes_shard_crc32() {
+    let bytes = BoC::new(CELL_SHARD.clone()).to_bytes(true).unwrap();
+    black_box(bytes);
+}
+
+fn boc_writer_shard() {
+    let mut writer = BocWriter::new();
+    writer.add_root(CELL_SHARD.clone());
+    writer.write_to(black_box(sink()), false).unwrap();
+}
+
+fn boc_writer_shard_crc32() {
+    let mut writer = BocWriter::new();
+    writer.add_root(CELL_SHARD.clone());
+    writer.write_to(black_box(sink()), true).unwrap();
+}
+
+criterion_group!(benches, benchmark_functions);
+criterion_main!(benches);
@@ -19,6 +19,9 @@ fn benchmark_functions(c: &mut Criterion) {
 
     run_bench!(c, boc_write_tonlib_core_old);
     run_bench!(c, boc_write_ton_rs_current);
+
+    run_bench!(c, cell_read_bits_ton_rs_current);
+    run_bench!(c, cell_read_bytes_aligned_ton_rs_current);
 }
 
 // tonlib-core (old)
@@ -55,5 +58,23 @@ fn boc_write_ton_rs_current() {
     }
 }
 
+fn cell_read_bits_ton_rs_current() {
+    let cell = TonCellCurrent::from_boc(SHARD_BLOCK_BOC.deref().clone()).unwrap();
+    for _ in 0..ITERATIONS_COUNT {
+        read_tree_bytes(&cell, &|c| black_box(c.parser().read_bits(c.data_len_bits() / 8 * 8).unwrap().len()));
+    }
+}
+
+fn cell_read_bytes_aligned_ton_rs_current() {
+    let cell = TonCellCurrent::from_boc(SHARD_BLOCK_BOC.deref().clone()).unwrap();
+    for _ in 0..ITERATIONS_COUNT {
+        read_tree_bytes(&cell, &|c| black_box(c.parser().read_bytes_aligned(c.data_len_bits() / 8).unwrap().len()));
+    }
+}
+
+fn read_tree_bytes(cell: &TonCellCurrent, read: &impl Fn(&TonCellCurrent) -> usize) -> usize {
+    read(cell) + cell.refs().iter().map(|c| read_tree_bytes(c, read)).sum::<usize>()
+}
+
 criterion_group!(benches, benchmark_functions);
 criterion_main!(benches);
@@ -16,7 +16,7 @@
 // use ton::emulators::tx_emulator::{TXEmulArgs, TXEmulOrdArgs, TXEmulationSuccess, TXEmulator};
 // use ton::emulators::tx_emulator::{TXEmulTask, TXEmulatorPool};
 // use ton::errors::TonResult;
-// use ton::sys_utils::sys_tonlib_set_verbosity_level;
+// use ton::sys_utils::{TonlibVerbosity, set_verbosity};
 // use ton_core::cell::TonHash;
 // use ton_core::traits::tlb::TLB;
 //
@@ -339,7 +339,7 @@
 //
 // fn main() {
 //     let aval_cores = std::thread::available_parallelism();
-//     sys_tonlib_set_verbosity_level(0);
+//     set_verbosity(TonlibVerbosity::Off);
 //
 //     // Check for --help-modes first (configure_criterion will exit if found)
 //     let args = parse_custom_args();
@@ -1,6 +1,8 @@
 use crate::block_tlb::*;
+use crate::errors::TonResult;
 use ton_core::TLB;
 use ton_core::cell::{TonCell, TonHash};
+use ton_core::types::Coins;
 use ton_core::types::tlb_core::TLBCoins;
 use ton_core::types::tlb_core::{MsgAddressInt, TLBRef};
 
@@ -37,6 +39,20 @@ impl ShardAccount {
         last_tx_hash: TonHash::ZERO,
         last_tx_lt: 0,
     };
+
+    // zero for non-existing account
+    pub fn balance(&self) -> TonResult<Coins> {
+        Ok(Coins::new(self.account.get_balance().map(|x| x.to_u128()).unwrap_or_default()))
+    }
+    pub fn last_tx_lt(&self) -> u64 { self.last_tx_lt }
+    pub fn last_tx_hash(&self) -> TonHash { self.last_tx_hash.clone() }
+    // None if account is not active
+    pub fn code_hash(&self) -> TonResult<Option<TonHash>> {
+        match self.account.get_code() {
+            Some(code) => Ok(Some(code.hash()?.clone())),
+            None => Ok(None),
+        }
+    }
 }
 
 impl Default for MaybeAccount {
@@ -62,6 +78,7 @@ impl MaybeAccount {
 mod tests {
     use super::*;
     use crate::block_tlb::{CurrencyCollection, SimpleLib, StateInit, TickTock};
+    use crate::test_fixtures::SHARD_ACCOUNT_BOC_HEX;
     use std::collections::HashMap;
 
     use crate::block_tlb::account_types::account_state::AccountState;
@@ -72,6 +89,25 @@ mod tests {
     use ton_core::traits::tlb::TLB;
     use ton_core::types::tlb_core::{MsgAddressIntStd, VarLen, VarLenBytes};
 
+    #[test]
+    fn test_block_tlb_shard_account_accessors() -> anyhow::Result<()> {
+        let shard_account = ShardAccount::from_boc_hex(SHARD_ACCOUNT_BOC_HEX)?;
+        assert_eq!(shard_account.balance()?, Coins::new(453670057));
+        assert_eq!(shard_account.last_tx_lt(), 53483469000003);
+        assert_eq!(
+            shard_account.last_tx_hash(),
+            TonHash::from_str("94fb2314023373e7b36b05b69e31508eba9ba24a60e994060fee1ca55302f8c2")?
+        );
+        assert_eq!(
+            shard_account.code_hash()?,
+            Some(TonHash::from_str("feb5ff6820e2ff0d9483e7e0d62c817d846789fb4ae580c878866d959dabd5c0")?)
+        );
+
+        assert_eq!(ShardAccount::NON_EXIST.balance()?, Coins::new(0));
+        assert_eq!(ShardAccount::NON_EXIST.code_hash()?, None);
+        Ok(())
+    }
+
     #[test]
     fn test_block_tlb_account_some() -> anyhow::Result<()> {
         let account_boc = "b5ee9c7201021d0100066d000271c00b113a994b5024a16719f69139328eb759596c38a25f59028b146fecdc3621dfe23a8bce83401229200000cc73d58b950d75499e8106934001020114ff00f4a413f4bcf2c80b030253705148e3baabcb0800c881fc78d28207072c728a2e7896228f37e17369ae121cb0eef7b4b0385f3330401a1b02016204050202cb0607020120161702f3d0cb434c0c05c6c238ecc200835c874c7c0608405e351466ea44c38601035c87e800c3b51343e803e903e90353534541168504d3214017e809400f3c58073c5b333327b55383e903e900c7e800c7d007e800c7e80004c5c3e0e80b4c7c04074cfc044bb51343e803e903e9035353449a084190adf41eeb8c089a0809001da23864658380e78b64814183fa0bc0019635355161c705f2e04904fa4021fa4430c000f2e14dfa00d4d120d0d31f018210178d4519baf2e0488040d721fa00fa4031fa4031fa0020d70b009ad74bc00101c001b0f2b19130e254431b0a03fa82107bdd97deba8ee7363805fa00fa40f82854120a70546004131503c8cb0358fa0201cf1601cf16c921c8cb0113f40012f400cb00c9f9007074c8cb02ca07cbffc9d05008c705f2e04a12a14414506603c85005fa025003cf1601cf16ccccc9ed54fa40d120d70b01c000b3915be30de02682102c76b973bae30235250c0d0e018e2191729171e2f839206e938124279120e2216e94318128739101e25023a813a0738103a370f83ca00270f83612a00170f836a07381040982100966018070f837a0bcf2b025597f0b00ec82103b9aca0070fb02f828450470546004131503c8cb0358fa0201cf1601cf16c921c8cb0113f40012f400cb00c920f9007074c8cb02ca07cbffc9d0c8801801cb0501cf1658fa02029858775003cb6bcccc9730017158cb6acce2c98011fb005005a04314c85005fa025003cf1601cf16ccccc9ed540044c8801001cb0501cf1670fa027001cb6a8210d53276db01cb1f0101cb3fc98042fb0001fc145f04323401fa40d2000101d195c821cf16c9916de2c8801001cb055004cf1670fa027001cb6a8210d173540001cb1f500401cb3f23fa4430c0008e35f828440470546004131503c8cb0358fa0201cf1601cf16c921c8cb0113f40012f400cb00c9f9007074c8cb02ca07cbffc9d012cf1697316c127001cb01e2f400c90f04f882106501f354ba8e223134365145c705f2e04902fa40d1103402c85005fa025003cf1601cf16ccccc9ed54e0258210fb88e119ba8e2132343603d15131c705f2e0498b025512c85005fa025003cf1601cf16ccccc9ed54e034248210235caf52bae30237238210cb862902bae302365b2082102508d66abae3026c311011121300088050fb0002ec3031325033c705f2e049fa40fa00d4d120d0d31f01018040d7212182100f8a7ea5ba8e4d36208210595f07bcba8e2c3004fa0031fa4031f401d120f839206e943081169fde718102f270f8380170f836a0811a7770f836a0bcf2b08e138210eed236d3ba9504d30331d19434f2c048e2e2e30d50037014150044335142c705f2e049c85003cf16c9134440c85005fa025003cf1601cf16ccccc9ed54001e3002c705f2e049d4d4d101ed54fb0400188210d372158cbadc840ff2f000ce31fa0031fa4031fa4031f401fa0020d70b009ad74bc00101c001b0f2b19130e25442162191729171e2f839206e938124279120e2216e94318128739101e25023a813a0738103a370f83ca00270f83612a00170f836a07381040982100966018070f837a0bcf2b000c082103b9aca0070fb02f828450470546004131503c8cb0358fa0201cf1601cf16c921c8cb0113f40012f400cb00c920f9007074c8cb02ca07cbffc9d0c8801801cb0501cf1658fa02029858775003cb6bcccc9730017158cb6acce2c98011fb000025bd9adf6a2687d007d207d206a6a6888122f82402027118190085adbcf6a2687d007d207d206a6a688a2f827c1400b82a3002098a81e46581ac7d0100e78b00e78b6490e4658089fa00097a00658064fc80383a6465816503e5ffe4e84000cfaf16f6a2687d007d207d206a6a68bf99e836c1783872ebdb514d9c97c283b7f0ae5179029e2b6119c39462719e4f46ed8f7413e62c780a417877407e978f01a40711411b1acb773a96bdd93fa83bb5ca8435013c8c4b3ac91f4589b4780a38646583fa0064a180400842028f452d7a4dfd74066b682365177259ed05734435be76b5fd4bd5d8af2b7c3d6801001c003e68747470733a2f2f7465746865722e746f2f757364742d746f6e2e6a736f6e";
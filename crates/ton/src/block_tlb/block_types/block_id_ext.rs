@@ -1,7 +1,10 @@
 use crate::block_tlb::ShardIdent;
-use ton_core::TLB;
+use std::fmt::{Display, Formatter};
+use std::str::FromStr;
 use ton_core::cell::TonHash;
 use ton_core::constants::{TON_MASTERCHAIN, TON_SHARD_FULL};
+use ton_core::errors::TonCoreError;
+use ton_core::{TLB, bail_ton_core_data};
 
 #[derive(Debug, Clone, PartialEq, Eq, Hash, TLB)]
 pub struct BlockIdExt {
@@ -34,3 +37,87 @@ impl BlockIdExt {
         file_hash: TonHash::from_slice_sized(&[103, 226, 10, 193, 132, 185, 224, 57, 166, 38, 103, 172, 195, 249, 192, 15, 144, 243, 89, 167, 103, 56, 35, 51, 121, 239, 164, 118, 4, 152, 12, 232]),
     };
 }
+
+// (-1,8000000000000000,45590082):ROOT_HASH:FILE_HASH
+impl Display for BlockIdExt {
+    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+        let ShardIdent { workchain, shard } = &self.shard_ident;
+        write!(f, "({workchain},{shard:016x},{}):{}:{}", self.seqno, self.root_hash, self.file_hash)
+    }
+}
+
+impl FromStr for BlockIdExt {
+    type Err = TonCoreError;
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        let Some((id, hashes)) = s.strip_prefix('(').and_then(|x| x.split_once(')')) else {
+            bail_ton_core_data!("Invalid BlockIdExt string: {s}");
+        };
+        let id_parts: Vec<_> = id.split(',').collect();
+        let hash_parts: Vec<_> = hashes.split(':').collect();
+        let ([wc, shard, seqno], ["", root_hash, file_hash]) = (id_parts.as_slice(), hash_parts.as_slice()) else {
+            bail_ton_core_data!("Invalid BlockIdExt string: {s}");
+        };
+        let Ok(workchain) = wc.parse::<i32>() else {
+            bail_ton_core_data!("Invalid BlockIdExt workchain: {wc}");
+        };
+        let shard = match u64::from_str_radix(shard, 16) {
+            Ok(val) if shard.len() == 16 && val != 0 => val,
+            _ => bail_ton_core_data!("Invalid BlockIdExt shard: {shard}"),
+        };
+        let Ok(seqno) = seqno.parse::<u32>() else {
+            bail_ton_core_data!("Invalid BlockIdExt seqno: {seqno}");
+        };
+        Ok(Self {
+            shard_ident: ShardIdent { workchain, shard },
+            seqno,
+            root_hash: parse_hex_hash(root_hash)?,
+            file_hash: parse_hex_hash(file_hash)?,
+        })
+    }
+}
+
+fn parse_hex_hash(hash: &str) -> Result<TonHash, TonCoreError> {
+    if hash.len() != 64 || !hash.bytes().all(|x| x.is_ascii_hexdigit()) {
+        bail_ton_core_data!("Invalid BlockIdExt hash: {hash}");
+    }
+    TonHash::from_str(hash)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use tokio_test::assert_err;
+
+    #[test]
+    fn test_block_id_ext_str() -> anyhow::Result<()> {
+        let block_id_str = "(-1,8000000000000000,46991999):CBEBAA6AC4270C987C90C5ED930FF37F9B73C705999585D6D8C1C5E9FA3DD6E3:A660FB144506617B0C0132B92CF41E436A6A87E924FD97C769C5D6E36320327B";
+        let block_id = BlockIdExt::from_str(block_id_str)?;
+        assert_eq!(block_id.shard_ident.workchain, TON_MASTERCHAIN);
+        assert_eq!(block_id.shard_ident.shard, TON_SHARD_FULL);
+        assert_eq!(block_id.seqno, 46991999);
+        assert_eq!(
+            block_id.root_hash,
+            TonHash::from_str("CBEBAA6AC4270C987C90C5ED930FF37F9B73C705999585D6D8C1C5E9FA3DD6E3")?
+        );
+        assert_eq!(block_id.to_string(), block_id_str);
+        assert_eq!(BlockIdExt::from_str(&BlockIdExt::ZERO_BLOCK_ID.to_string())?, BlockIdExt::ZERO_BLOCK_ID);
+
+        // lowercase hashes are accepted
+        assert_eq!(BlockIdExt::from_str(&block_id_str.to_lowercase())?, block_id);
+        Ok(())
+    }
+
+    #[test]
+    fn test_block_id_ext_str_invalid() {
+        let hash = "CBEBAA6AC4270C987C90C5ED930FF37F9B73C705999585D6D8C1C5E9FA3DD6E3";
+        assert_err!(BlockIdExt::from_str(&format!("-1,8000000000000000,1:{hash}:{hash}")));
+        assert_err!(BlockIdExt::from_str(&format!("(x,8000000000000000,1):{hash}:{hash}")));
+        assert_err!(BlockIdExt::from_str(&format!("(-1,800000000000000,1):{hash}:{hash}")));
+        assert_err!(BlockIdExt::from_str(&format!("(-1,0000000000000000,1):{hash}:{hash}")));
+        assert_err!(BlockIdExt::from_str(&format!("(-1,800000000000000g,1):{hash}:{hash}")));
+        assert_err!(BlockIdExt::from_str(&format!("(-1,8000000000000000,-1):{hash}:{hash}")));
+        assert_err!(BlockIdExt::from_str(&format!("(-1,8000000000000000,1):{hash}")));
+        assert_err!(BlockIdExt::from_str(&format!("(-1,8000000000000000,1):{hash}:{}", &hash[1..])));
+        assert_err!(BlockIdExt::from_str(&format!("(-1,8000000000000000,1):{hash}:{hash}:{hash}")));
+    }
+}
@@ -1,5 +1,6 @@
 use crate::block_tlb::msg_types::common_msg_info::CommonMsgInfo;
 use crate::block_tlb::*;
+use crate::tep::text_comment::{CellParserCommentExt, TextComment};
 use ton_core::TLB;
 use ton_core::cell::{TonCell, TonHash};
 use ton_core::errors::TonCoreError;
@@ -24,6 +25,16 @@ impl<Body: TLB> Msg<Body> {
         }
     }
 
+    pub fn info(&self) -> &CommonMsgInfo { &self.info }
+
+    // only internal messages carry value
+    pub fn value(&self) -> Option<&CurrencyCollection> {
+        match &self.info {
+            CommonMsgInfo::Int(info) => Some(&info.value),
+            _ => None,
+        }
+    }
+
     pub fn src(&self) -> MsgAddress {
         match &self.info {
             CommonMsgInfo::Int(info) => info.src.clone(),
@@ -84,11 +95,19 @@ impl<Body: TLB> Msg<Body> {
     }
 }
 
+impl Msg<TonCell> {
+    /// Returns None if body is not a text comment
+    pub fn text_comment(&self) -> Result<Option<TextComment>, TonCoreError> {
+        self.body.value.parser().read_text_comment()
+    }
+}
+
 #[cfg(test)]
 mod tests {
     use super::*;
+    use crate::test_fixtures::{EXPECTED_TX_BOC_HEX, EXT_IN_MSG_BOC_HEX};
     use std::str::FromStr;
-    use tokio_test::assert_ok;
+    use tokio_test::{assert_err, assert_ok};
     use ton_core::types::TonAddress;
     use ton_core::types::tlb_core::{Anycast, MsgAddressExtern, MsgAddressIntStd, VarLenBits};
 
@@ -141,6 +160,59 @@ mod tests {
         Ok(())
     }
 
+    #[test]
+    fn test_ext_in_msg_addr_none_src() -> anyhow::Result<()> {
+        let ext_in_msg = Msg::<TonCell>::from_boc_hex(EXT_IN_MSG_BOC_HEX)?;
+        assert_eq!(ext_in_msg.src(), MsgAddress::NONE);
+        assert_err!(TonAddress::try_from(ext_in_msg.src()));
+        assert_eq!(TonAddress::from_msg_address(ext_in_msg.src())?, TonAddress::ZERO);
+        let wallet = TonAddress::from_str("EQCS65EGyiApUTLOYXDs4jOLoQNCE0o8oNnkmfIcm0iX5AmW")?;
+        assert_eq!(TonAddress::try_from(ext_in_msg.dst())?, wallet);
+
+        let mut ext_src_msg = ext_in_msg.clone();
+        let CommonMsgInfo::ExtIn(info) = &mut ext_src_msg.info else {
+            panic!("Expected CommonMsgInfo::ExtIn");
+        };
+        info.src = MsgAddressExt::new(vec![0xab, 0xcd], 16);
+        let parsed = Msg::<TonCell>::from_cell(&ext_src_msg.to_cell()?)?;
+        assert_eq!(parsed.src(), MsgAddress::from(MsgAddressExt::new(vec![0xab, 0xcd], 16)));
+        assert_err!(TonAddress::try_from(parsed.src()));
+        assert_eq!(TonAddress::try_from(parsed.dst())?, wallet);
+        Ok(())
+    }
+
+    #[test]
+    fn test_msg_info_benchmark_fixtures() -> anyhow::Result<()> {
+        // ext_in_msg & expected_tx from benchmarks/tx_emulator_bench.rs
+        let ext_in_msg = Msg::<TonCell>::from_boc_hex(EXT_IN_MSG_BOC_HEX)?;
+        let wallet = TonAddress::from_str("EQCS65EGyiApUTLOYXDs4jOLoQNCE0o8oNnkmfIcm0iX5AmW")?;
+        let CommonMsgInfo::ExtIn(info) = ext_in_msg.info() else {
+            panic!("Expected CommonMsgInfo::ExtIn");
+        };
+        assert_eq!(info.src, MsgAddressExt::NONE);
+        assert_eq!(info.import_fee, TLBCoins::ZERO);
+        assert_eq!(TonAddress::from_msg_address(ext_in_msg.dst())?, wallet);
+        assert_eq!(ext_in_msg.value(), None);
+
+        let tx = Tx::from_boc_hex(EXPECTED_TX_BOC_HEX)?;
+        assert_eq!(tx.msgs.in_msg.as_ref().map(|msg| msg.info()), Some(ext_in_msg.info()));
+        let int_msg = &tx.msgs.out_msgs[0];
+        let CommonMsgInfo::Int(info) = int_msg.info() else {
+            panic!("Expected CommonMsgInfo::Int");
+        };
+        assert_eq!(TonAddress::from_msg_address(int_msg.src())?, wallet);
+        assert_eq!(
+            TonAddress::from_msg_address(int_msg.dst())?,
+            TonAddress::from_str("EQCS65EHXVI70mKFXJuERbAHjpy-Jh5v3hqF9mpxL_ofMMZe")?
+        );
+        assert_eq!(int_msg.value(), Some(&CurrencyCollection::from_num(&220000000u32)?));
+        assert_eq!(info.ihr_fee, TLBCoins::ZERO);
+        assert_eq!(info.fwd_fee, TLBCoins::new(672806));
+        assert_eq!(info.created_lt, 53483578000002);
+        assert_eq!(info.created_at, 1738323935);
+        Ok(())
+    }
+
     // reproducing https://github.com/tonkeeper/tongo/blob/5c0ce694d72b7024bcb62b3d0dcd008940a75419/tlb/messages_test.go#L23C1-L80C2
     #[test]
     fn test_ext_in_msg_hash_normalized() -> anyhow::Result<()> {
@@ -2,6 +2,7 @@ mod from_tvm_stack;
 mod to_tvm_stack;
 mod tvm_cell_slice;
 mod tvm_stack;
+mod tvm_stack_json;
 mod tvm_stack_value;
 mod tvm_tuple;
 
@@ -108,7 +108,13 @@ impl FromTVMStack for TonHash {
 }
 
 impl FromTVMStack for Coins {
-    fn from_stack(stack: &mut TVMStack) -> TonResult<Self> { Ok(Coins::try_from(stack.pop_num()?)?) }
+    fn from_stack(stack: &mut TVMStack) -> TonResult<Self> {
+        let num512 = stack.pop_num()?;
+        Coins::try_from(num512).map_err(|err| TonError::UnexpectedValue {
+            expected: "Coins".to_string(),
+            actual: err.to_string(),
+        })
+    }
 }
 
 impl FromTVMStack for TLBCoins {
@@ -139,12 +145,14 @@ impl<T: FromTVMStack> FromTVMStack for Option<T> {
 #[cfg(test)]
 mod tests {
     use crate::block_tlb::{FromTVMStack, TVMInt, TVMNull, TVMStack};
+    use crate::errors::TonError;
     use crate::tep::snake_data::SnakeData;
-    use fastnum::I256;
+    use fastnum::{I256, I512};
     use std::str::FromStr;
     use tokio_test::assert_err;
+    use ton_core::cell::TonCell;
     use ton_core::traits::tlb::TLB;
-    use ton_core::types::TonAddress;
+    use ton_core::types::{Coins, TonAddress};
     use ton_macros::FromTVMStack;
 
     #[derive(FromTVMStack, Debug)]
@@ -179,7 +187,37 @@ mod tests {
         stack.push_cell(TonAddress::from_str("EQBiMfDMivebQb052Z6yR3jHrmwNhw1kQ5bcAUOBYsK_VPuK")?.to_cell()?);
         stack.push_tiny_int(0);
 
-        assert_err!(TestStruct::from_stack(&mut stack));
+        let err = TestStruct::from_stack(&mut stack).unwrap_err();
+        assert!(matches!(&err, TonError::TVMStackNotEmpty(left) if left == &["TinyInt", "TinyInt"]));
+        assert!(err.to_string().ends_with("2 values left: [TinyInt, TinyInt]"), "{err}");
+        Ok(())
+    }
+
+    #[derive(FromTVMStack, Debug)]
+    #[from_tvm_stack(ensure_empty = true)]
+    struct TestStructSkip {
+        pub field1: i64,
+        #[from_tvm_stack(skip)]
+        pub _field2: Option<TonAddress>,
+        pub field3: bool,
+    }
+
+    #[test]
+    fn test_from_tvm_stack_skip_field() -> anyhow::Result<()> {
+        let mut stack = TVMStack::new(vec![]);
+        stack.push_tiny_int(5);
+        stack.push_cell(TonCell::empty().clone());
+        stack.push_tiny_int(-1);
+
+        let test_struct = TestStructSkip::from_stack(&mut stack)?;
+        assert_eq!(test_struct.field1, 5);
+        assert_eq!(test_struct._field2, None);
+        assert!(test_struct.field3);
+
+        let mut stack = TVMStack::new(vec![]);
+        stack.push_tiny_int(5);
+        stack.push_tiny_int(-1);
+        assert_err!(TestStructSkip::from_stack(&mut stack));
         Ok(())
     }
 
@@ -215,6 +253,23 @@ mod tests {
         Ok(())
     }
 
+    #[test]
+    fn test_from_tvm_stack_coins() -> anyhow::Result<()> {
+        let mut stack = TVMStack::default();
+        stack.push_int(I512::from_u128(1_000_000_000).unwrap());
+        assert_eq!(Coins::from_stack(&mut stack)?, Coins::new(1_000_000_000));
+
+        stack.push_tiny_int(-1);
+        let err = assert_err!(Coins::from_stack(&mut stack));
+        assert!(matches!(&err, TonError::UnexpectedValue { expected, .. } if expected == "Coins"));
+        assert!(err.to_string().contains("value is negative"), "{err}");
+
+        stack.push_int(I512::from_u128(1 << 120).unwrap());
+        let err = assert_err!(Coins::from_stack(&mut stack));
+        assert!(err.to_string().contains("exceeds VarUInteger 16 max"), "{err}");
+        Ok(())
+    }
+
     #[test]
     fn test_from_tvm_stack_string() -> anyhow::Result<()> {
         let original = "Hello, TVMStack!".to_string();
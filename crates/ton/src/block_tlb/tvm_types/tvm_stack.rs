@@ -1,4 +1,5 @@
-use crate::block_tlb::{TVMCell, TVMCellSlice, TVMInt, TVMStackValue, TVMTinyInt, TVMTuple};
+use super::tvm_tuple::with_max_depth;
+use crate::block_tlb::{TVMCell, TVMCellSlice, TVMInt, TVMStackValue, TVMTinyInt, TVMTuple, ToTVMStack};
 use crate::errors::{TonError, TonResult};
 use fastnum::I512;
 use std::fmt::Display;
@@ -6,6 +7,7 @@ use std::ops::{Deref, DerefMut};
 use ton_core::cell::{CellBuilder, CellParser, TonCell};
 use ton_core::errors::TonCoreResult;
 use ton_core::traits::tlb::TLB;
+use ton_core::types::{Coins, TonAddress};
 
 macro_rules! extract_stack_val {
     ($maybe_result:expr, $variant:ident) => {
@@ -37,9 +39,18 @@ impl TVMStack {
 
     pub fn new(items: Vec<TVMStackValue>) -> Self { Self(items) }
 
+    /// Builds get-method arguments in declaration order: the first argument ends up at the bottom of the stack
+    pub fn builder() -> TVMStackBuilder { TVMStackBuilder::default() }
+
+    /// Same as `read`, but accepts up to `max_depth` tuple nesting levels instead of `TVMTuple::DEFAULT_MAX_DEPTH`
+    pub fn read_with_max_tuple_depth(parser: &mut CellParser, max_depth: usize) -> TonCoreResult<Self> {
+        with_max_depth(max_depth, || Self::read(parser))
+    }
+
     pub fn ensure_empty(&self) -> TonResult<()> {
         if !self.is_empty() {
-            return Err(TonError::TVMStackNotEmpty);
+            let leftover = self.iter().map(TVMStackValue::type_name).collect();
+            return Err(TonError::TVMStackNotEmpty(leftover));
         }
 
         Ok(())
@@ -89,6 +100,45 @@ impl TVMStack {
     }
 }
 
+#[derive(Debug, Default)]
+pub struct TVMStackBuilder {
+    stack: TVMStack,
+    error: Option<TonError>, // first error, reported by build()
+}
+
+impl TVMStackBuilder {
+    pub fn push<T: ToTVMStack + ?Sized>(mut self, value: &T) -> Self {
+        if self.error.is_none() {
+            if let Err(err) = value.to_stack(&mut self.stack) {
+                self.error = Some(err);
+            }
+        }
+        self
+    }
+
+    pub fn int<T: Into<I512>>(self, value: T) -> Self { self.push(&value.into()) }
+    pub fn tiny_int(self, value: i64) -> Self { self.push(&value) }
+    // unwrap is safe: u128 always fits into I512
+    pub fn coins(self, value: Coins) -> Self { self.int(I512::from_u128(value.to_nano()).unwrap()) }
+    pub fn cell(self, value: TonCell) -> Self { self.push(&value) }
+    pub fn slice(mut self, value: TonCell) -> Self {
+        self.stack.push_cell_slice(value);
+        self
+    }
+    pub fn address(self, value: &TonAddress) -> Self { self.push(value) }
+    pub fn tuple(mut self, value: TVMTuple) -> Self {
+        self.stack.push_tuple(value);
+        self
+    }
+
+    pub fn build(self) -> TonResult<TVMStack> {
+        match self.error {
+            Some(err) => Err(err),
+            None => Ok(self.stack),
+        }
+    }
+}
+
 impl TLB for TVMStack {
     fn read_definition(parser: &mut CellParser) -> TonCoreResult<Self> {
         let depth: u32 = parser.read_num(24)?;
@@ -140,8 +190,74 @@ impl Display for TVMStack {
 mod tests {
     use super::*;
 
-    use tokio_test::assert_ok;
-    use ton_core::types::TonAddress;
+    use tokio_test::{assert_err, assert_ok};
+
+    #[test]
+    fn test_vm_stack_tuple_depth_limit() -> anyhow::Result<()> {
+        let make_stack_boc = |depth: usize| -> anyhow::Result<Vec<u8>> {
+            let mut tuple = TVMTuple::new(vec![]);
+            tuple.push_tiny_int(1);
+            for _ in 1..depth {
+                let mut outer = TVMTuple::new(vec![]);
+                outer.push_tuple(tuple);
+                tuple = outer;
+            }
+            let mut stack = TVMStack::default();
+            stack.push_tuple(tuple);
+            Ok(stack.to_boc()?)
+        };
+
+        let mut parsed = TVMStack::from_boc(make_stack_boc(TVMTuple::DEFAULT_MAX_DEPTH)?)?;
+        let mut tuple = parsed.pop_tuple()?;
+        for _ in 1..TVMTuple::DEFAULT_MAX_DEPTH {
+            tuple = tuple.get_tuple(0)?.clone();
+        }
+        assert_eq!(tuple.get_tiny_int(0)?, &1);
+
+        let err = TVMStack::from_boc(make_stack_boc(TVMTuple::DEFAULT_MAX_DEPTH + 1)?).unwrap_err();
+        assert!(err.to_string().contains("nesting depth exceeds limit"), "unexpected error: {err}");
+        // depth counter is restored after failure
+        assert_ok!(TVMStack::from_boc(make_stack_boc(10)?));
+
+        // explicit limit applies to the single call only
+        let cell = TonCell::from_boc(make_stack_boc(10)?)?;
+        let err = TVMStack::read_with_max_tuple_depth(&mut cell.parser(), 9).unwrap_err();
+        assert!(err.to_string().contains("nesting depth exceeds limit 9"), "unexpected error: {err}");
+        assert_ok!(TVMStack::read_with_max_tuple_depth(&mut cell.parser(), 10));
+        let deep_cell = TonCell::from_boc(make_stack_boc(TVMTuple::DEFAULT_MAX_DEPTH + 1)?)?;
+        assert_ok!(TVMStack::read_with_max_tuple_depth(&mut deep_cell.parser(), TVMTuple::DEFAULT_MAX_DEPTH + 1));
+        assert_err!(TVMStack::from_cell(&deep_cell));
+        Ok(())
+    }
+
+    #[test]
+    fn test_vm_stack_builder() -> anyhow::Result<()> {
+        let code = TonCell::builder().build()?;
+        let stack = TVMStack::builder()
+            .int(7)
+            .tiny_int(-1)
+            .coins(Coins::from_nano(1_000_000_000))
+            .address(&TonAddress::ZERO)
+            .slice(TonAddress::ZERO.to_cell()?)
+            .cell(code.clone())
+            .build()?;
+
+        let mut expected = TVMStack::default();
+        expected.push_int(7.into());
+        expected.push_tiny_int(-1);
+        expected.push_int(1_000_000_000.into());
+        expected.push_cell_slice(TonAddress::ZERO.to_cell()?);
+        expected.push_cell_slice(TonAddress::ZERO.to_cell()?);
+        expected.push_cell(code);
+        assert_eq!(stack, expected);
+        assert_eq!(stack.to_boc()?, expected.to_boc()?);
+
+        // first argument is at the bottom, last one is on top
+        let mut stack = stack;
+        assert_eq!(stack.pop_cell()?, TonCell::builder().build()?);
+        assert_eq!(stack.first(), Some(&TVMStackValue::Int(TVMInt { value: 7.into() })));
+        Ok(())
+    }
 
     #[test]
     fn test_vm_stack_empty() -> anyhow::Result<()> {
@@ -0,0 +1,147 @@
+use crate::bail_ton;
+use crate::block_tlb::{
+    TVMBuilder, TVMCell, TVMCellSlice, TVMCont, TVMInt, TVMNan, TVMNull, TVMStack, TVMStackValue, TVMTinyInt, TVMTuple,
+};
+use crate::errors::{TonError, TonResult};
+use fastnum::I512;
+use serde_json::{Value, json};
+use std::str::FromStr;
+use ton_core::cell::TonCell;
+use ton_core::traits::tlb::TLB;
+
+/// JSON form of the stack: array of `{"type": "...", "value": ...}` objects, bottom first
+///
+/// ints are decimal strings, cells/slices/builders/continuations are BoC base64, tuples are arrays
+impl TVMStack {
+    pub fn to_json(&self) -> TonResult<Value> { values_to_json(self) }
+
+    pub fn from_json(json: &Value) -> TonResult<TVMStack> {
+        Self::from_json_with_max_tuple_depth(json, TVMTuple::DEFAULT_MAX_DEPTH)
+    }
+
+    /// Same as `from_json`, but accepts up to `max_depth` tuple nesting levels
+    pub fn from_json_with_max_tuple_depth(json: &Value, max_depth: usize) -> TonResult<TVMStack> {
+        Ok(TVMStack::new(values_from_json(json, 0, max_depth)?))
+    }
+}
+
+fn values_to_json(values: &[TVMStackValue]) -> TonResult<Value> {
+    Ok(Value::Array(values.iter().map(value_to_json).collect::<TonResult<_>>()?))
+}
+
+fn value_to_json(value: &TVMStackValue) -> TonResult<Value> {
+    let json = match value {
+        TVMStackValue::Null(_) => json!({"type": "null"}),
+        TVMStackValue::TinyInt(v) => json!({"type": "tiny_int", "value": v.value.to_string()}),
+        TVMStackValue::Int(v) => json!({"type": "int", "value": v.value.to_string()}),
+        TVMStackValue::Nan(_) => json!({"type": "nan"}),
+        TVMStackValue::Cell(v) => json!({"type": "cell", "value": v.value.to_boc_base64()?}),
+        TVMStackValue::CellSlice(v) => json!({"type": "slice", "value": v.to_cell()?.to_boc_base64()?}),
+        TVMStackValue::Builder(v) => json!({"type": "builder", "value": v.cell.to_boc_base64()?}),
+        TVMStackValue::Cont(v) => json!({"type": "cont", "value": v.to_boc_base64()?}),
+        TVMStackValue::Tuple(v) => json!({"type": "tuple", "value": values_to_json(v)?}),
+    };
+    Ok(json)
+}
+
+fn values_from_json(json: &Value, depth: usize, max_depth: usize) -> TonResult<Vec<TVMStackValue>> {
+    if depth > max_depth {
+        bail_ton!("TVMStack JSON: tuple depth exceeds limit {max_depth}");
+    }
+    let Some(items) = json.as_array() else {
+        bail_ton!("TVMStack JSON: expected array, got {json}");
+    };
+    items.iter().map(|item| value_from_json(item, depth, max_depth)).collect()
+}
+
+fn value_from_json(json: &Value, depth: usize, max_depth: usize) -> TonResult<TVMStackValue> {
+    let Some(value_type) = json.get("type").and_then(Value::as_str) else {
+        bail_ton!("TVMStack JSON: no type in {json}");
+    };
+    let value = json.get("value");
+    let str_value = || match value.and_then(Value::as_str) {
+        Some(str_value) => Ok(str_value),
+        None => Err(TonError::Custom(format!("TVMStack JSON: no string value in {json}"))),
+    };
+    let value = match value_type {
+        "null" => TVMStackValue::Null(TVMNull),
+        "tiny_int" => TVMStackValue::TinyInt(TVMTinyInt {
+            value: i64::from_str(str_value()?)?,
+        }),
+        "int" => match I512::from_str(str_value()?) {
+            Ok(value) => TVMStackValue::Int(TVMInt { value }),
+            Err(err) => bail_ton!("TVMStack JSON: invalid int {}: {err}", str_value()?),
+        },
+        "nan" => TVMStackValue::Nan(TVMNan),
+        "cell" => TVMStackValue::Cell(TVMCell {
+            value: TonCell::from_boc_base64(str_value()?)?.into(),
+        }),
+        "slice" => TVMStackValue::CellSlice(TVMCellSlice::from_cell(TonCell::from_boc_base64(str_value()?)?)),
+        "builder" => TVMStackValue::Builder(TVMBuilder {
+            cell: TonCell::from_boc_base64(str_value()?)?.into(),
+        }),
+        "cont" => TVMStackValue::Cont(TVMCont::from_boc_base64(str_value()?)?),
+        "tuple" => match value {
+            Some(items) => TVMStackValue::Tuple(TVMTuple::new(values_from_json(items, depth + 1, max_depth)?)),
+            None => bail_ton!("TVMStack JSON: no tuple items in {json}"),
+        },
+        _ => bail_ton!("TVMStack JSON: unknown type {value_type}"),
+    };
+    Ok(value)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use tokio_test::{assert_err, assert_ok};
+
+    #[test]
+    fn test_tvm_stack_json_roundtrip() -> anyhow::Result<()> {
+        let mut cell_builder = TonCell::builder();
+        cell_builder.write_num(&0xDEADBEEFu32, 32)?;
+        cell_builder.write_ref(TonCell::empty().clone())?;
+        let cell = cell_builder.build()?;
+
+        let mut tuple = TVMTuple::default();
+        tuple.push_tiny_int(-1);
+        tuple.push(TVMStackValue::Null(TVMNull));
+        tuple.push_tuple(TVMTuple::new(vec![TVMStackValue::Nan(TVMNan)]));
+
+        let mut stack = TVMStack::default();
+        stack.push(TVMStackValue::Null(TVMNull));
+        stack.push_tiny_int(i64::MIN);
+        stack.push_int(I512::from_str(
+            "-115792089237316195423570985008687907853269984665640564039457584007913129639936",
+        )?);
+        stack.push(TVMStackValue::Nan(TVMNan));
+        stack.push_cell(cell.clone());
+        stack.push_cell_slice(cell.clone());
+        stack.push(TVMStackValue::Builder(TVMBuilder { cell: cell.into() }));
+        stack.push_tuple(tuple);
+
+        let json = stack.to_json()?;
+        assert_eq!(json[1], json!({"type": "tiny_int", "value": "-9223372036854775808"}));
+        assert_eq!(json[7]["value"][2]["value"][0], json!({"type": "nan"}));
+        assert_eq!(TVMStack::from_json(&json)?, stack);
+
+        // via string as well
+        let parsed: Value = serde_json::from_str(&json.to_string())?;
+        assert_eq!(TVMStack::from_json(&parsed)?, stack);
+        Ok(())
+    }
+
+    #[test]
+    fn test_tvm_stack_json_invalid() {
+        assert_err!(TVMStack::from_json(&json!({"type": "null"})));
+        assert_err!(TVMStack::from_json(&json!([{"value": "1"}])));
+        assert_err!(TVMStack::from_json(&json!([{"type": "int", "value": 1}])));
+        assert_err!(TVMStack::from_json(&json!([{"type": "int", "value": "abc"}])));
+        assert_err!(TVMStack::from_json(&json!([{"type": "cell", "value": "abc"}])));
+        assert_err!(TVMStack::from_json(&json!([{"type": "tuple"}])));
+        assert_err!(TVMStack::from_json(&json!([{"type": "unknown"}])));
+
+        let nested = json!([{"type": "tuple", "value": [{"type": "tuple", "value": []}]}]);
+        assert_err!(TVMStack::from_json_with_max_tuple_depth(&nested, 1));
+        assert_ok!(TVMStack::from_json_with_max_tuple_depth(&nested, 2));
+    }
+}
@@ -158,6 +158,22 @@ pub struct VMContPushInt {
     pub next: Arc<TLBRef<TVMCont>>,
 }
 
+impl TVMStackValue {
+    pub fn type_name(&self) -> &'static str {
+        match self {
+            TVMStackValue::Null(_) => "Null",
+            TVMStackValue::TinyInt(_) => "TinyInt",
+            TVMStackValue::Int(_) => "Int",
+            TVMStackValue::Nan(_) => "Nan",
+            TVMStackValue::Cell(_) => "Cell",
+            TVMStackValue::CellSlice(_) => "CellSlice",
+            TVMStackValue::Builder(_) => "Builder",
+            TVMStackValue::Cont(_) => "Cont",
+            TVMStackValue::Tuple(_) => "Tuple",
+        }
+    }
+}
+
 impl Debug for TVMStackValue {
     fn fmt(&self, f: &mut Formatter<'_>) -> fmt::Result { write!(f, "{self}") }
 }
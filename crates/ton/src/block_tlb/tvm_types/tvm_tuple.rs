@@ -1,21 +1,29 @@
 use crate::block_tlb::{TVMCell, TVMCellSlice, TVMInt, TVMStackValue, TVMTinyInt};
 use crate::errors::{TonError, TonResult};
 use fastnum::I512;
+use std::cell::Cell;
 use std::ops::{Deref, DerefMut};
+use ton_core::bail_ton_core_data;
 use ton_core::cell::{CellBuilder, CellParser, TonCell};
-use ton_core::errors::TonCoreResult;
+use ton_core::errors::{TonCoreError, TonCoreResult};
 use ton_core::traits::tlb::{TLB, TLBPrefix};
 
 macro_rules! extract_tuple_val {
-    ($maybe_result:expr, $variant:ident) => {
-        match &$maybe_result {
-            None => Err(TonError::TVMStackEmpty),
-            Some(TVMStackValue::$variant(val)) => Ok(&val.value),
-            Some(rest) => Err(TonError::TVMStackWrongType(stringify!($variant).to_string(), format!("{rest:?}"))),
+    ($tuple:expr, $index:expr, $variant:ident) => {
+        match $tuple.get_checked($index)? {
+            TVMStackValue::$variant(val) => Ok(&val.value),
+            rest => Err(wrong_type($index, stringify!($variant), rest)),
         }
     };
 }
 
+thread_local! {
+    // nesting level of tuples being parsed in current thread
+    static TUPLE_DEPTH: Cell<usize> = const { Cell::new(0) };
+    // nesting limit of the parsing in progress, see TVMTuple::read_with_max_depth
+    static TUPLE_MAX_DEPTH: Cell<usize> = const { Cell::new(TVMTuple::DEFAULT_MAX_DEPTH) };
+}
+
 // https://github.com/ton-blockchain/ton/blob/master/crypto/block/block.tlb#L872C30-L872C40
 // Doesn't implement tlb schema directly for convenience purposes
 // Very similar with VMStackValue, but random access to underlying values
@@ -41,31 +49,89 @@ impl TVMTuple {
     pub fn push_cell_slice(&mut self, cell: TonCell) { self.push(TVMStackValue::CellSlice(TVMCellSlice::from_cell(cell))); }
     pub fn push_tuple(&mut self, value: TVMTuple) { self.push(TVMStackValue::Tuple(value))}
 
-    pub fn get_tiny_int(&self, index: usize) -> TonResult<&i64> { extract_tuple_val!(self.get(index), TinyInt) }
-    pub fn get_int(&self, index: usize) -> TonResult<&I512> { extract_tuple_val!(self.get(index), Int) }
+    /// Same as `get`, but returns `TonError::TVMTupleIndexOutOfRange` instead of None
+    pub fn get_checked(&self, index: usize) -> TonResult<&TVMStackValue> {
+        self.get(index).ok_or(TonError::TVMTupleIndexOutOfRange { index, len: self.len() })
+    }
+    pub fn get_tiny_int(&self, index: usize) -> TonResult<&i64> { extract_tuple_val!(self, index, TinyInt) }
+    pub fn get_int(&self, index: usize) -> TonResult<&I512> { extract_tuple_val!(self, index, Int) }
     pub fn get_cell(&self, index: usize) -> TonResult<&TonCell> {
-        match self.get(index) {
-            None => Err(TonError::TVMStackEmpty),
-            Some(TVMStackValue::Cell(val)) => Ok(&val.value),
-            Some(TVMStackValue::CellSlice(val)) => Ok(&val.value),
-            Some(rest) => Err(TonError::TVMStackWrongType("Cell | CellSlice".to_string(), format!("{rest:?}"))),
+        match self.get_checked(index)? {
+            TVMStackValue::Cell(val) => Ok(&val.value),
+            TVMStackValue::CellSlice(val) => Ok(&val.value),
+            rest => Err(wrong_type(index, "Cell | CellSlice", rest)),
         }
     }
-    pub fn get_cell_slice(&self, index: usize) -> TonResult<&TonCell> { extract_tuple_val!(self.get(index), CellSlice) }
+    pub fn get_cell_slice(&self, index: usize) -> TonResult<&TonCell> { extract_tuple_val!(self, index, CellSlice) }
     pub fn get_tuple(&self, index: usize) -> TonResult<&TVMTuple> {
-        match self.get(index) {
-            None => Err(TonError::TVMStackEmpty),
-            Some(TVMStackValue::Tuple(val)) => Ok(val),
-            Some(rest) => Err(TonError::TVMStackWrongType("Tuple".to_string(), format!("{rest:?}"))),
+        match self.get_checked(index)? {
+            TVMStackValue::Tuple(val) => Ok(val),
+            rest => Err(wrong_type(index, "Tuple", rest)),
         }
     }
 }
 
+fn wrong_type(index: usize, expected: &'static str, actual: &TVMStackValue) -> TonError {
+    TonError::TVMTupleWrongType {
+        index,
+        expected,
+        actual: actual.type_name(),
+    }
+}
+
+impl TVMTuple {
+    /// TVM limits tuple size by 255, the same value is used as default nesting limit
+    pub const MAX_SIZE: usize = 255;
+    pub const DEFAULT_MAX_DEPTH: usize = 255;
+
+    /// Same as `read`, but accepts up to `max_depth` nesting levels instead of `DEFAULT_MAX_DEPTH`
+    pub fn read_with_max_depth(parser: &mut CellParser, max_depth: usize) -> TonCoreResult<Self> {
+        with_max_depth(max_depth, || Self::read(parser))
+    }
+}
+
+/// Runs `f` with tuple nesting limit set to `max_depth` in current thread
+pub(crate) fn with_max_depth<T>(max_depth: usize, f: impl FnOnce() -> T) -> T {
+    // restores previous limit even if `f` panics
+    struct MaxDepthGuard(usize);
+    impl Drop for MaxDepthGuard {
+        fn drop(&mut self) { TUPLE_MAX_DEPTH.with(|limit| limit.set(self.0)); }
+    }
+    let _guard = MaxDepthGuard(TUPLE_MAX_DEPTH.with(|limit| limit.replace(max_depth)));
+    f()
+}
+
+// keeps TUPLE_DEPTH in sync even if parsing fails
+struct TupleDepthGuard;
+
+impl TupleDepthGuard {
+    fn enter() -> TonCoreResult<Self> {
+        let depth = TUPLE_DEPTH.with(|depth| {
+            depth.set(depth.get() + 1);
+            depth.get()
+        });
+        let guard = TupleDepthGuard;
+        let max_depth = TUPLE_MAX_DEPTH.with(Cell::get);
+        if depth > max_depth {
+            bail_ton_core_data!("TVMTuple nesting depth exceeds limit {max_depth}");
+        }
+        Ok(guard)
+    }
+}
+
+impl Drop for TupleDepthGuard {
+    fn drop(&mut self) { TUPLE_DEPTH.with(|depth| depth.set(depth.get() - 1)); }
+}
+
 impl TLB for TVMTuple {
     const PREFIX: TLBPrefix = TLBPrefix::new(0x07, 8);
     fn read_definition(parser: &mut CellParser) -> TonCoreResult<Self> {
-        let _size: u16 = parser.read_num(16)?;
-        let mut data = Vec::new();
+        let size: u16 = parser.read_num(16)?;
+        if size as usize > Self::MAX_SIZE {
+            bail_ton_core_data!("TVMTuple size {size} exceeds limit {}", Self::MAX_SIZE);
+        }
+        let _guard = TupleDepthGuard::enter()?;
+        let mut data = Vec::with_capacity(size as usize);
         read_tuple(parser, &mut data)?;
         Ok(TVMTuple(data))
     }
@@ -135,3 +201,35 @@ fn write_tuple_ref(builder: &mut CellBuilder, data: &[TVMStackValue]) -> TonCore
     write_tuple(&mut rest_builder, data)?;
     builder.write_ref(rest_builder.build()?)
 }
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_tvm_tuple_getters() -> anyhow::Result<()> {
+        let mut tuple = TVMTuple::default();
+        tuple.push_tiny_int(1);
+        tuple.push_int(I512::from(2));
+        tuple.push_cell(TonCell::empty().clone());
+        tuple.push_cell_slice(TonCell::empty().clone());
+        tuple.push_tuple(TVMTuple::default());
+
+        assert_eq!(tuple.get_tiny_int(0)?, &1);
+        assert_eq!(tuple.get_int(1)?, &I512::from(2));
+        assert_eq!(tuple.get_cell(2)?, TonCell::empty());
+        assert_eq!(tuple.get_cell(3)?, TonCell::empty());
+        assert_eq!(tuple.get_cell_slice(3)?, TonCell::empty());
+        assert!(tuple.get_tuple(4)?.is_empty());
+
+        let err = tuple.get_int(5).unwrap_err();
+        assert_eq!(err.to_string(), "TVMTupleError: index 5 is out of range, tuple len: 5");
+        let err = tuple.get_int(0).unwrap_err();
+        assert_eq!(err.to_string(), "TVMTupleError: wrong type at index 0. expected: Int, got: TinyInt");
+        let err = tuple.get_cell(4).unwrap_err();
+        assert_eq!(err.to_string(), "TVMTupleError: wrong type at index 4. expected: Cell | CellSlice, got: Tuple");
+        let err = tuple.get_tuple(2).unwrap_err();
+        assert_eq!(err.to_string(), "TVMTupleError: wrong type at index 2. expected: Tuple, got: Cell");
+        Ok(())
+    }
+}
@@ -5,6 +5,7 @@ use std::collections::HashMap;
 use ton_core::cell::*;
 use ton_core::errors::TonCoreError;
 use ton_core::traits::tlb::TLB;
+use ton_core::types::Coins;
 use ton_core::types::tlb_core::TLBRef;
 use ton_core::{TLB, bail_ton_core_data};
 
@@ -27,6 +28,22 @@ pub struct Tx {
     pub descr: TLBRef<TxDescr>,
 }
 
+impl Tx {
+    pub fn description(&self) -> &TxDescr { &self.descr }
+
+    pub fn compute_phase_exit_code(&self) -> Option<i32> { self.descr.exit_code() }
+
+    pub fn action_phase_result_code(&self) -> Option<i32> { self.descr.action().map(|action| action.result_code) }
+
+    pub fn in_message(&self) -> Option<&Msg> { self.msgs.in_msg.as_ref() }
+
+    /// Out messages in the order they were created
+    pub fn out_messages(&self) -> &[Msg] { &self.msgs.out_msgs }
+
+    // extra currencies are not included
+    pub fn total_fees(&self) -> Coins { self.total_fees.coins.into() }
+}
+
 #[derive(Default, Debug, Clone, PartialEq)]
 pub struct TxMsgs {
     pub in_msg: Option<Msg>,
@@ -72,10 +89,31 @@ impl TLB for TxMsgs {
 #[cfg(test)]
 mod tests {
     use super::*;
+    use crate::test_fixtures::EXPECTED_TX_BOC_HEX;
     use std::str::FromStr;
+    use ton_core::types::TonAddress;
     use ton_core::types::tlb_core::TLBCoins;
     use ton_core::types::tlb_core::VarLen;
 
+    #[test]
+    fn test_block_tlb_tx_accessors() -> anyhow::Result<()> {
+        // expected_tx from benchmarks/tx_emulator_bench.rs
+        let tx = Tx::from_boc_hex(EXPECTED_TX_BOC_HEX)?;
+        assert!(matches!(tx.description(), TxDescr::Ord(_)));
+        assert_eq!(tx.compute_phase_exit_code(), Some(0));
+        assert_eq!(tx.action_phase_result_code(), Some(0));
+        assert_eq!(tx.total_fees(), Coins::new(2875265));
+
+        let in_msg = tx.in_message().unwrap();
+        let wallet = TonHash::from_str("92eb9106ca20295132ce6170ece2338ba10342134a3ca0d9e499f21c9b4897e4")?;
+        assert_eq!(TonAddress::from_msg_address(in_msg.dst())?, TonAddress::new(0, wallet));
+        assert_eq!(tx.out_messages().len(), 1);
+        assert_eq!(tx.out_messages().len(), tx.out_msgs_cnt as usize);
+        let out_dst = TonHash::from_str("92eb91075d523bd262855c9b8445b0078e9cbe261e6fde1a85f66a712ffa1f30")?;
+        assert_eq!(TonAddress::from_msg_address(tx.out_messages()[0].dst())?, TonAddress::new(0, out_dst));
+        Ok(())
+    }
+
     #[test]
     fn test_block_tlb_tx_tick_tock() -> anyhow::Result<()> {
         let tx = Tx::from_boc_hex(
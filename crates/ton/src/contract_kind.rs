@@ -0,0 +1,127 @@
+use crate::bail_ton;
+use crate::errors::TonResult;
+use crate::ton_wallet::{TON_WALLET_VERSION_BY_CODE, WalletVersion};
+use parking_lot::RwLock;
+use std::collections::HashMap;
+use std::str::FromStr;
+use std::sync::LazyLock;
+use ton_core::cell::{TonCell, TonHash};
+use ton_core::traits::contract_provider::TonContractState;
+use ton_core::traits::tlb::TLB;
+
+#[derive(Debug, PartialEq, Eq, Clone, Hash)]
+pub enum ContractKind {
+    Wallet(WalletVersion),
+    JettonMaster,
+    JettonWallet,
+    NftCollection,
+    NftItem,
+    Custom(String),
+}
+
+/// Registry of code hashes with known contract kinds
+///
+/// Prefilled with all supported wallet versions and USDT jetton contracts
+pub static KNOWN_CODE_HASHES: LazyLock<TonResult<KnownCodeHashes>> = LazyLock::new(KnownCodeHashes::with_defaults);
+
+#[derive(Debug, Default)]
+pub struct KnownCodeHashes {
+    kinds: RwLock<HashMap<TonHash, ContractKind>>,
+}
+
+impl KnownCodeHashes {
+    pub fn new() -> Self { Self::default() }
+
+    pub fn with_defaults() -> TonResult<Self> {
+        let known = Self::new();
+        for (code_hash, version) in TON_WALLET_VERSION_BY_CODE.iter() {
+            known.register(code_hash.clone(), ContractKind::Wallet(*version));
+        }
+        // USDT: https://tonviewer.com/EQCxE6mUtQJKFnGfaROTKOt1lZbDiiX1kCixRv7Nw2Id_sDs
+        known.register(
+            TonHash::from_str("18d5b6e780ff0bb451254c2c760d09d6e485638cd1407abb97078752c3c1c9ee")?,
+            ContractKind::JettonMaster,
+        );
+        // USDT jetton wallet code is a library cell
+        known.register(
+            TonHash::from_str("89468f02c78e570802e39979c8516fc38df07ea76a48357e0536f2ba7b3ee37b")?,
+            ContractKind::JettonWallet,
+        );
+        Ok(known)
+    }
+
+    /// Overrides previous kind of the code_hash, if any
+    pub fn register(&self, code_hash: TonHash, kind: ContractKind) { self.kinds.write().insert(code_hash, kind); }
+
+    pub fn get(&self, code_hash: &TonHash) -> Option<ContractKind> { self.kinds.read().get(code_hash).cloned() }
+
+    /// Returns None for uninit/frozen contracts and for unknown code. Fails if code BoC is broken
+    pub fn detect(&self, state: &TonContractState) -> TonResult<Option<ContractKind>> {
+        let Some(code_boc) = state.code_boc.clone() else {
+            return Ok(None);
+        };
+        let code = TonCell::from_boc(code_boc)?;
+        Ok(self.get(&code.cell_hash()?))
+    }
+}
+
+pub trait TonContractStateExt {
+    /// Matches code hash against `KNOWN_CODE_HASHES`. Returns None for uninit/frozen contracts
+    fn detect_kind(&self) -> TonResult<Option<ContractKind>>;
+}
+
+impl TonContractStateExt for TonContractState {
+    fn detect_kind(&self) -> TonResult<Option<ContractKind>> {
+        match KNOWN_CODE_HASHES.as_ref() {
+            Ok(known) => known.detect(self),
+            Err(err) => bail_ton!("Failed to init KNOWN_CODE_HASHES: {err}"),
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::test_fixtures::USDT_MASTER_CODE_BOC_HEX;
+    use std::sync::Arc;
+    use tokio_test::assert_err;
+    use ton_core::types::{TonAddress, TxLTHash};
+
+    fn make_state(code: Option<&TonCell>) -> anyhow::Result<TonContractState> {
+        Ok(TonContractState {
+            mc_seqno: None,
+            address: TonAddress::ZERO,
+            last_tx_id: TxLTHash::ZERO,
+            code_boc: code.map(|x| x.to_boc().map(Arc::new)).transpose()?,
+            data_boc: None,
+            frozen_hash: None,
+            balance: 0,
+        })
+    }
+
+    #[test]
+    fn test_contract_kind_detect() -> anyhow::Result<()> {
+        let usdt_master_code = TonCell::from_boc_hex(USDT_MASTER_CODE_BOC_HEX)?;
+        let usdt_master_state = make_state(Some(&usdt_master_code))?;
+        assert_eq!(usdt_master_state.detect_kind()?, Some(ContractKind::JettonMaster));
+
+        let wallet_code = WalletVersion::get_code(WalletVersion::V4R2)?;
+        assert_eq!(make_state(Some(wallet_code))?.detect_kind()?, Some(ContractKind::Wallet(WalletVersion::V4R2)));
+        assert_eq!(make_state(None)?.detect_kind()?, None);
+
+        let custom_code = TonCell::from_bit_str("1010")?;
+        let known = KnownCodeHashes::new();
+        assert_eq!(known.detect(&make_state(Some(&custom_code))?)?, None);
+        known.register(custom_code.cell_hash()?, ContractKind::Custom("my_contract".to_string()));
+        assert_eq!(
+            known.detect(&make_state(Some(&custom_code))?)?,
+            Some(ContractKind::Custom("my_contract".to_string()))
+        );
+
+        let mut broken_code = make_state(Some(&custom_code))?;
+        broken_code.code_boc = Some(Arc::new(vec![1, 2, 3]));
+        assert_err!(known.detect(&broken_code));
+        assert_err!(broken_code.detect_kind());
+        Ok(())
+    }
+}
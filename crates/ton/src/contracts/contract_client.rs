@@ -1,9 +1,12 @@
 mod builder;
 mod cache_stats;
 pub mod contract_client_cache;
+#[cfg(test)]
+pub(crate) mod mock_provider;
 #[cfg(feature = "tonlibjson")]
 pub mod tl_provider;
 
+use crate::block_tlb::TVMStack;
 use crate::contracts::contract_client::builder::Builder;
 use crate::contracts::contract_client::contract_client_cache::ContractClientCache;
 use crate::emulators::emul_bc_config::EmulBCConfig;
@@ -11,6 +14,7 @@ use crate::emulators::emulator_pool::{EmulatorPool, TVMGetMethodTask};
 use crate::emulators::tvm_emulator::*;
 use crate::errors::{TonError, TonResult};
 use crate::libs_dict::LibsDict;
+use std::borrow::Cow;
 use std::collections::HashMap;
 use std::sync::Arc;
 use std::time::{Duration, SystemTime, UNIX_EPOCH};
@@ -22,6 +26,8 @@ use ton_core::traits::contract_provider::{TonContractState, TonProvider};
 use ton_core::traits::tlb::TLB;
 use ton_core::types::{TonAddress, TxLTHash};
 
+pub use crate::util::{RetryBackoff, RetryStrategy};
+
 #[derive(Clone)]
 pub struct ContractClient {
     inner: Arc<Inner>,
@@ -38,6 +44,17 @@ impl ContractClient {
         self.inner.cache.get_or_load_contract(address, tx_id).await
     }
 
+    /// Loads latest states of all addresses, requesting provider only for the ones missing in cache
+    pub async fn get_or_load_contracts(&self, addresses: &[TonAddress]) -> TonResult<Vec<Arc<TonContractState>>> {
+        self.inner.cache.get_or_load_contracts(addresses).await
+    }
+
+    /// Drops cached latest state of the address, so the next call loads it from provider
+    /// Useful right after sending a message to the contract
+    pub async fn invalidate(&self, address: &TonAddress) { self.inner.cache.invalidate(address).await }
+
+    pub fn invalidate_all(&self) { self.inner.cache.invalidate_all() }
+
     /// mc_seqno can be specified to run emulation in a specific blockchain state
     /// If mc_seqno is None, head state will be used
     /// Is not used yet
@@ -122,6 +139,40 @@ impl ContractClient {
         emul_response.into_success()
     }
 
+    /// Runs get-method on the latest cached state of the address using local emulator
+    pub async fn run_get_method_local(
+        &self,
+        address: &TonAddress,
+        method: &str,
+        stack: TVMStack,
+    ) -> TonResult<TVMStack> {
+        let state = self.get_contract(address, None).await?;
+        let method_id = TVMGetMethodID::from(Cow::Borrowed(method)).to_id();
+        self.emul_get_method(&state, method_id, stack.to_boc()?, None).await?.stack_parsed()
+    }
+
+    /// Same as `run_get_method_local`, but state loading + emulation is capped by `timeout`
+    /// Returns `TonError::NetRequestTimeout` if it's exceeded
+    pub async fn run_get_method_local_with_timeout(
+        &self,
+        address: &TonAddress,
+        method: &str,
+        stack: TVMStack,
+        timeout: Option<Duration>,
+    ) -> TonResult<TVMStack> {
+        let run_future = self.run_get_method_local(address, method, stack);
+        let Some(timeout) = timeout else {
+            return run_future.await;
+        };
+        match tokio::time::timeout(timeout, run_future).await {
+            Ok(result) => result,
+            Err(_) => Err(TonError::NetRequestTimeout {
+                msg: format!("run_get_method_local: method={method}, address={address}"),
+                timeout,
+            }),
+        }
+    }
+
     pub fn cache_stats(&self) -> HashMap<String, usize> { self.inner.cache.cache_stats() }
 
     async fn get_bc_config(&self) -> TonResult<&EmulBCConfig> {
@@ -143,3 +194,151 @@ struct Inner {
     bc_config: OnceCell<EmulBCConfig>,
     max_dyn_libs_per_contract: usize,
 }
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::contracts::contract_client::mock_provider::MockProvider;
+    use std::str::FromStr;
+    use std::sync::atomic::Ordering::Relaxed;
+
+    #[tokio::test]
+    async fn test_contract_client_get_or_load_contracts() -> anyhow::Result<()> {
+        let provider = MockProvider::default();
+        let stats = provider.stats.clone();
+        let client = ContractClient::builder(provider)?
+            .with_contract_cache_capacity(100)
+            .with_contract_cache_ttl(Duration::from_secs(60))
+            .with_emulator_pool_size(1)
+            .build()?;
+
+        let addresses = [
+            TonAddress::from_str("EQCxE6mUtQJKFnGfaROTKOt1lZbDiiX1kCixRv7Nw2Id_sDs")?,
+            TonAddress::from_str("EQAFmjUoZUqKFEBGYFEMbv-m61sFStgAfUR8J6hJDwUU09iT")?,
+            TonAddress::from_str("EQB3ncyBUTjZUA5EnFKR5_EnOMI9V1tTEAAPaiU71gc4TiUt")?,
+        ];
+        // warm up cache for the first address
+        client.get_contract(&addresses[0], None).await?;
+        assert_eq!(stats.load_state_calls.load(Relaxed), 1);
+
+        let states = client.get_or_load_contracts(&addresses).await?;
+        assert_eq!(stats.load_state_calls.load(Relaxed), 3);
+        let loaded_addresses: Vec<_> = states.iter().map(|x| x.address.clone()).collect();
+        assert_eq!(loaded_addresses, addresses);
+
+        // everything is cached now
+        let reversed: Vec<_> = addresses.iter().rev().cloned().collect();
+        let states = client.get_or_load_contracts(&reversed).await?;
+        assert_eq!(stats.load_state_calls.load(Relaxed), 3);
+        assert_eq!(states.iter().map(|x| x.address.clone()).collect::<Vec<_>>(), reversed);
+        Ok(())
+    }
+
+    #[tokio::test]
+    async fn test_contract_client_invalidate() -> anyhow::Result<()> {
+        let provider = MockProvider::default();
+        let stats = provider.stats.clone();
+        let client = ContractClient::builder(provider)?
+            .with_contract_cache_capacity(100)
+            .with_contract_cache_ttl(Duration::from_secs(60))
+            .with_emulator_pool_size(1)
+            .build()?;
+
+        let address0 = TonAddress::from_str("EQCxE6mUtQJKFnGfaROTKOt1lZbDiiX1kCixRv7Nw2Id_sDs")?;
+        let address1 = TonAddress::from_str("EQAFmjUoZUqKFEBGYFEMbv-m61sFStgAfUR8J6hJDwUU09iT")?;
+        client.get_or_load_contracts(&[address0.clone(), address1.clone()]).await?;
+        client.get_contract(&address0, None).await?;
+        assert_eq!(stats.load_state_calls.load(Relaxed), 2);
+
+        client.invalidate(&address0).await;
+        client.get_contract(&address0, None).await?;
+        client.get_contract(&address1, None).await?;
+        assert_eq!(stats.load_state_calls.load(Relaxed), 3);
+
+        client.invalidate_all();
+        client.get_or_load_contracts(&[address0, address1]).await?;
+        assert_eq!(stats.load_state_calls.load(Relaxed), 5);
+        Ok(())
+    }
+
+    #[tokio::test]
+    async fn test_contract_client_run_get_method_timeout() -> anyhow::Result<()> {
+        let provider = MockProvider {
+            delay: Duration::from_secs(10),
+            ..Default::default()
+        };
+        let client = ContractClient::builder(provider)?
+            .with_emulator_pool_size(1)
+            .with_retry_strategy(RetryStrategy::NO_RETRY)
+            .build()?;
+        let address = TonAddress::from_str("EQCxE6mUtQJKFnGfaROTKOt1lZbDiiX1kCixRv7Nw2Id_sDs")?;
+
+        let timeout = Duration::from_millis(50);
+        let result =
+            client.run_get_method_local_with_timeout(&address, "get_wallet_data", TVMStack::EMPTY, Some(timeout));
+        match result.await {
+            Err(TonError::NetRequestTimeout {
+                msg,
+                timeout: err_timeout,
+            }) => {
+                assert!(msg.contains("get_wallet_data"));
+                assert_eq!(err_timeout, timeout);
+            }
+            other => panic!("expected NetRequestTimeout, got {other:?}"),
+        }
+        Ok(())
+    }
+
+    #[tokio::test]
+    async fn test_contract_client_recent_tx_loop_subscription() -> anyhow::Result<()> {
+        let provider = MockProvider {
+            mc_seqnos: vec![100, 101, 102],
+            ..Default::default()
+        };
+        let stats = provider.stats.clone();
+        let _client =
+            ContractClient::builder(provider)?.with_contract_cache_capacity(100).with_emulator_pool_size(1).build()?;
+
+        tokio::time::timeout(Duration::from_secs(5), async {
+            while stats.processed_seqnos.lock().len() < 3 {
+                tokio::time::sleep(Duration::from_millis(10)).await;
+            }
+        })
+        .await?;
+        assert_eq!(*stats.processed_seqnos.lock(), vec![100, 101, 102]);
+        Ok(())
+    }
+
+    #[tokio::test]
+    async fn test_contract_client_retry_provider_errors() -> anyhow::Result<()> {
+        let address = TonAddress::from_str("EQCxE6mUtQJKFnGfaROTKOt1lZbDiiX1kCixRv7Nw2Id_sDs")?;
+        let make_client = |failures: usize, retry_strategy: RetryStrategy| {
+            let provider = MockProvider {
+                load_state_failures: failures,
+                ..Default::default()
+            };
+            let stats = provider.stats.clone();
+            let client = ContractClient::builder(provider)?
+                .with_contract_cache_capacity(100)
+                .with_contract_cache_ttl(Duration::from_secs(60))
+                .with_emulator_pool_size(1)
+                .with_retry_strategy(retry_strategy)
+                .build()?;
+            Ok::<_, TonError>((client, stats))
+        };
+
+        let strategy = RetryStrategy::exponential(3, Duration::from_millis(1), Duration::from_millis(10));
+        let (client, stats) = make_client(2, strategy)?;
+        let state = client.get_contract(&address, None).await?;
+        assert_eq!(state.address, address);
+        assert_eq!(stats.load_state_calls.load(Relaxed), 3);
+        // cached
+        client.get_contract(&address, None).await?;
+        assert_eq!(stats.load_state_calls.load(Relaxed), 3);
+
+        let (client, stats) = make_client(2, RetryStrategy::NO_RETRY)?;
+        assert!(client.get_contract(&address, None).await.is_err());
+        assert_eq!(stats.load_state_calls.load(Relaxed), 1);
+        Ok(())
+    }
+}
@@ -1,4 +1,5 @@
 use crate::contracts::ContractClient;
+use crate::contracts::RetryStrategy;
 use crate::contracts::contract_client::Inner;
 use crate::contracts::contract_client::contract_client_cache::ContractClientCache;
 use crate::emulators::emulator_pool::EmulatorPool;
@@ -29,6 +30,12 @@ pub struct Builder {
     pub(super) code_libs_cache_idle: Duration,
     // how many times emulate_get_method will try load new missing_libraries
     pub(super) max_dyn_libs_per_contract: usize,
+    // how many levels of libs referenced by other libs are resolved before emulation (0 - no nested resolution)
+    pub(super) libs_resolution_depth: usize,
+    // cap for simultaneous provider calls while loading libs of a single contract
+    pub(super) max_concurrent_lib_loads: usize,
+    // applied to provider calls made by cache (load_state, load_libs), no retries by default
+    pub(super) retry_strategy: RetryStrategy,
 }
 
 impl Builder {
@@ -51,6 +58,9 @@ impl Builder {
             code_libs_cache_capacity: 0,
             code_libs_cache_idle: Duration::from_secs(0),
             max_dyn_libs_per_contract: 100,
+            libs_resolution_depth: 4,
+            max_concurrent_lib_loads: 8,
+            retry_strategy: RetryStrategy::NO_RETRY,
         };
         Ok(builder)
     }
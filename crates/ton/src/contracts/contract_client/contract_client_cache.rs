@@ -1,7 +1,10 @@
+use crate::contracts::RetryStrategy;
 use crate::contracts::contract_client::builder::Builder;
 use crate::contracts::contract_client::cache_stats::CacheStats;
 use crate::errors::{TonError, TonResult};
+use crate::util;
 use futures_util::future::{join_all, try_join_all};
+use futures_util::{StreamExt, TryStreamExt};
 use moka::future::Cache;
 use num_traits::Zero;
 use parking_lot::RwLock;
@@ -10,7 +13,7 @@ use std::hash::Hash;
 use std::sync::atomic::Ordering::Relaxed;
 use std::sync::{Arc, Weak};
 use std::time::Duration;
-use ton_core::cell::{TonCell, TonHash};
+use ton_core::cell::{TonCell, TonCellUtils, TonHash};
 use ton_core::traits::contract_provider::{TonContractState, TonProvider};
 use ton_core::traits::tlb::TLB;
 use ton_core::types::{TonAddress, TxLTHash};
@@ -24,6 +27,9 @@ pub(super) struct ContractClientCache {
     libs_cache_not_found: moka::sync::Cache<TonHash, ()>,
     code_extra_libs_cache: moka::sync::Cache<TonHash, Arc<RwLock<HashSet<TonHash>>>>, // code_hash -> set of lib_hashes
     cache_stats: CacheStats,
+    retry_strategy: RetryStrategy,
+    libs_resolution_depth: usize,
+    max_concurrent_lib_loads: usize,
 }
 
 impl ContractClientCache {
@@ -45,6 +51,9 @@ impl ContractClientCache {
                 .time_to_idle(builder.code_libs_cache_idle)
                 .build(),
             cache_stats: CacheStats::default(),
+            retry_strategy: builder.retry_strategy.clone(),
+            libs_resolution_depth: builder.libs_resolution_depth,
+            max_concurrent_lib_loads: builder.max_concurrent_lib_loads.max(1),
         });
         let weak = Arc::downgrade(&client_cache);
         if contract_cache_capacity.is_zero() {
@@ -77,6 +86,26 @@ impl ContractClientCache {
         Ok(state)
     }
 
+    // hits are served from cache, misses are loaded concurrently; output order matches input order
+    pub(super) async fn get_or_load_contracts(
+        &self,
+        addresses: &[TonAddress],
+    ) -> TonResult<Vec<Arc<TonContractState>>> {
+        let futs = addresses.iter().map(|address| self.get_or_load_contract(address, None));
+        try_join_all(futs).await
+    }
+
+    // states by tx_id are immutable, so only latest state entries are dropped
+    pub(super) async fn invalidate(&self, address: &TonAddress) {
+        self.latest_tx_cache.invalidate(address).await;
+        self.state_latest_cache.invalidate(address).await;
+    }
+
+    pub(super) fn invalidate_all(&self) {
+        self.latest_tx_cache.invalidate_all();
+        self.state_latest_cache.invalidate_all();
+    }
+
     pub(super) fn add_code_dyn_lib(&self, code_hash: TonHash, lib_id: TonHash) {
         self.code_extra_libs_cache.entry(code_hash).or_default().value().write().insert(lib_id);
     }
@@ -89,13 +118,31 @@ impl ContractClientCache {
         self.get_or_load_libs(lib_hashes).await
     }
 
+    /// Libs referenced by loaded libs are loaded as well, up to `libs_resolution_depth` levels
     pub(super) async fn get_or_load_libs(&self, lib_ids: HashSet<TonHash>) -> TonResult<HashMap<TonHash, TonCell>> {
+        let mut libs = self.get_or_load_libs_flat(lib_ids).await?;
+        let mut last_loaded: Vec<_> = libs.values().cloned().collect();
+        for _ in 0..self.libs_resolution_depth {
+            let mut nested_ids = TonCellUtils::extract_lib_ids(&last_loaded)?;
+            nested_ids.retain(|lib_id| !libs.contains_key(lib_id));
+            if nested_ids.is_empty() {
+                break;
+            }
+            let nested_libs = self.get_or_load_libs_flat(nested_ids).await?;
+            last_loaded = nested_libs.values().cloned().collect();
+            libs.extend(nested_libs);
+        }
+        Ok(libs)
+    }
+
+    async fn get_or_load_libs_flat(&self, lib_ids: HashSet<TonHash>) -> TonResult<HashMap<TonHash, TonCell>> {
         let futs = lib_ids.into_iter().map(|lib_id| async move {
             let lib = self.get_or_load_lib(lib_id.clone()).await?;
             Ok::<_, TonError>(lib.map(|x| (lib_id, x)))
         });
-        let libs = try_join_all(futs).await?.into_iter().flatten().collect();
-        Ok(libs)
+        let libs: Vec<_> =
+            futures_util::stream::iter(futs).buffer_unordered(self.max_concurrent_lib_loads).try_collect().await?;
+        Ok(libs.into_iter().flatten().collect())
     }
 
     pub(super) async fn get_or_load_lib(&self, lib_id: TonHash) -> TonResult<Option<TonCell>> {
@@ -125,13 +172,15 @@ impl ContractClientCache {
             Some(_) => self.cache_stats.state_by_tx_miss.fetch_add(1, Relaxed),
             None => self.cache_stats.state_latest_miss.fetch_add(1, Relaxed),
         };
-        let state = self.provider.load_state(address.clone(), tx_id).await?;
+        let load_state = || async { Ok(self.provider.load_state(address.clone(), tx_id.clone()).await?) };
+        let state = util::retry(&self.retry_strategy, load_state).await?;
         Ok(Arc::new(state))
     }
 
     // TODO think about providing mc_seqno
     async fn load_lib(&self, lib_id: TonHash) -> TonResult<Option<TonCell>> {
-        let mut response = self.provider.load_libs(vec![lib_id.clone()], None).await?;
+        let load_libs = || async { Ok(self.provider.load_libs(vec![lib_id.clone()], None).await?) };
+        let mut response = util::retry(&self.retry_strategy, load_libs).await?;
         let Some(entry) = response.pop() else {
             return Ok(None);
         };
@@ -141,55 +190,67 @@ impl ContractClientCache {
 
 async fn recent_tx_loop(weak_cache: Weak<ContractClientCache>, idle_on_error: Duration) {
     log::info!("[recent_tx_loop] initializing...");
-    let mut cur_mc_seqno = if let Some(inner) = weak_cache.upgrade() {
-        loop {
-            match inner.provider.last_mc_seqno().await {
-                Ok(seqno) => break seqno,
-                Err(err) => {
-                    log::warn!("[recent_tx_loop] fail to get last mc seqno: {err}");
-                    tokio::time::sleep(idle_on_error).await;
-                    continue;
-                }
-            }
+    let weak_provider = match weak_cache.upgrade() {
+        Some(inner) => Arc::downgrade(&inner.provider),
+        None => {
+            log::warn!("[recent_tx_loop] inner is already dropped, exiting loop");
+            return;
         }
-    } else {
-        log::warn!("[recent_tx_loop] inner is already dropped, exiting loop");
-        return;
     };
-    log::info!("[recent_tx_loop] started with last_mc_seqno: {cur_mc_seqno}");
 
     loop {
-        let client_cache = match weak_cache.upgrade() {
-            Some(inner) => inner,
+        let provider = match weak_provider.upgrade() {
+            Some(provider) => provider,
             None => {
-                log::warn!("[recent_tx_loop] inner is dropped");
-                break;
+                log::warn!("[recent_tx_loop] provider is dropped");
+                log::info!("[recent_tx_loop] completed");
+                return;
             }
         };
-        let client_cache_ref = &client_cache;
-
-        let latest_tx_per_addr = match client_cache_ref.provider.load_latest_tx_per_address(cur_mc_seqno).await {
-            Ok(latest_tx) => latest_tx,
+        let mut mc_seqno_stream = match provider.subscribe_mc_blocks().await {
+            Ok(stream) => stream,
             Err(err) => {
-                log::warn!("[recent_tx_loop] fail to loading latest txs: {err}");
+                log::warn!("[recent_tx_loop] fail to subscribe to mc blocks: {err}");
                 tokio::time::sleep(idle_on_error).await;
                 continue;
             }
         };
-        log::debug!(
-            "[recent_tx_loop] mc_seqno {}: loaded {} txs (last per address)",
-            cur_mc_seqno,
-            latest_tx_per_addr.len()
-        );
-
-        let update_cache_futs = latest_tx_per_addr.into_iter().map(|(address, tx_id)| async move {
-            client_cache_ref.latest_tx_cache.insert(address.clone(), tx_id).await;
-            client_cache_ref.state_latest_cache.invalidate(&address).await;
-        });
-        join_all(update_cache_futs).await;
-        cur_mc_seqno += 1;
+        log::info!("[recent_tx_loop] subscribed to mc blocks");
+
+        while let Some(mc_seqno) = mc_seqno_stream.next().await {
+            loop {
+                let client_cache = match weak_cache.upgrade() {
+                    Some(inner) => inner,
+                    None => {
+                        log::warn!("[recent_tx_loop] inner is dropped");
+                        log::info!("[recent_tx_loop] completed");
+                        return;
+                    }
+                };
+                match update_latest_txs(&client_cache, mc_seqno).await {
+                    Ok(()) => break,
+                    Err(err) => {
+                        log::warn!("[recent_tx_loop] fail to loading latest txs: {err}");
+                        tokio::time::sleep(idle_on_error).await;
+                    }
+                }
+            }
+        }
+        log::warn!("[recent_tx_loop] mc blocks stream is closed, resubscribing");
+        tokio::time::sleep(idle_on_error).await;
     }
-    log::info!("[recent_tx_loop] completed");
+}
+
+async fn update_latest_txs(client_cache: &ContractClientCache, mc_seqno: u32) -> TonResult<()> {
+    let latest_tx_per_addr = client_cache.provider.load_latest_tx_per_address(mc_seqno).await?;
+    log::debug!("[recent_tx_loop] mc_seqno {}: loaded {} txs (last per address)", mc_seqno, latest_tx_per_addr.len());
+
+    let update_cache_futs = latest_tx_per_addr.into_iter().map(|(address, tx_id)| async move {
+        client_cache.latest_tx_cache.insert(address.clone(), tx_id).await;
+        client_cache.state_latest_cache.invalidate(&address).await;
+    });
+    join_all(update_cache_futs).await;
+    Ok(())
 }
 
 fn init_cache<K, V>(capacity: u64, ttl: Duration) -> Cache<K, V>
@@ -207,3 +268,73 @@ where
 {
     moka::sync::Cache::builder().max_capacity(capacity).time_to_live(ttl).build()
 }
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::contracts::contract_client::mock_provider::MockProvider;
+    use ton_core::cell::CellType;
+
+    fn make_lib_ref(lib_id: &TonHash) -> anyhow::Result<TonCell> {
+        let mut builder = TonCell::builder_extra(CellType::LibraryRef, 64);
+        builder.write_num(&2, 8)?; // lib prefix
+        builder.write_bits(lib_id, TonHash::BITS_LEN)?;
+        Ok(builder.build()?)
+    }
+
+    #[tokio::test]
+    async fn test_contract_client_cache_nested_libs() -> anyhow::Result<()> {
+        // lib_1 references lib_2
+        let mut builder = TonCell::builder();
+        builder.write_num(&0xBEEFu16, 16)?;
+        let lib_2 = builder.build()?;
+        let lib_2_id = lib_2.hash()?.clone();
+
+        let mut builder = TonCell::builder();
+        builder.write_ref(make_lib_ref(&lib_2_id)?)?;
+        let lib_1 = builder.build()?;
+        let lib_1_id = lib_1.hash()?.clone();
+
+        let make_cache = |depth: usize| {
+            let provider = MockProvider {
+                libs: HashMap::from([(lib_1_id.clone(), lib_1.clone()), (lib_2_id.clone(), lib_2.clone())]),
+                ..Default::default()
+            };
+            let builder = Builder::new(provider)?.with_libs_resolution_depth(depth);
+            ContractClientCache::new(&builder)
+        };
+
+        let libs = make_cache(0)?.get_or_load_libs(HashSet::from([lib_1_id.clone()])).await?;
+        assert_eq!(libs.len(), 1);
+        assert!(!libs.contains_key(&lib_2_id));
+
+        let libs = make_cache(1)?.get_or_load_libs(HashSet::from([lib_1_id.clone()])).await?;
+        assert_eq!(libs.len(), 2);
+        assert_eq!(libs.get(&lib_1_id), Some(&lib_1));
+        assert_eq!(libs.get(&lib_2_id), Some(&lib_2));
+        Ok(())
+    }
+
+    #[tokio::test]
+    async fn test_contract_client_cache_max_concurrent_lib_loads() -> anyhow::Result<()> {
+        let mut libs = HashMap::new();
+        for i in 0..20u32 {
+            let mut builder = TonCell::builder();
+            builder.write_num(&i, 32)?;
+            let lib = builder.build()?;
+            libs.insert(lib.hash()?.clone(), lib);
+        }
+        let lib_ids: HashSet<_> = libs.keys().cloned().collect();
+        let provider = MockProvider {
+            libs,
+            delay: Duration::from_millis(10),
+            ..Default::default()
+        };
+        let stats = provider.stats.clone();
+        let builder = Builder::new(provider)?.with_max_concurrent_lib_loads(3);
+        let loaded = ContractClientCache::new(&builder)?.get_or_load_libs(lib_ids).await?;
+        assert_eq!(loaded.len(), 20);
+        assert_eq!(stats.peak_lib_loads.load(Relaxed), 3);
+        Ok(())
+    }
+}
@@ -0,0 +1,79 @@
+use async_trait::async_trait;
+use futures_util::StreamExt;
+use futures_util::stream::BoxStream;
+use parking_lot::Mutex;
+use std::collections::HashMap;
+use std::sync::Arc;
+use std::sync::atomic::AtomicUsize;
+use std::sync::atomic::Ordering::Relaxed;
+use std::time::Duration;
+use ton_core::cell::{TonCell, TonHash};
+use ton_core::errors::TonCoreError;
+use ton_core::traits::contract_provider::{TonContractState, TonProvider};
+use ton_core::traits::tlb::TLB;
+use ton_core::types::{TonAddress, TxLTHash};
+
+/// Configurable TonProvider for tests
+///
+/// load_state returns empty state of the address, load_libs serves `libs` only.
+/// subscribe_mc_blocks emits `mc_seqnos`, then hangs (so cache is never invalidated by default)
+#[derive(Default)]
+pub(crate) struct MockProvider {
+    /// applied to load_state & load_libs
+    pub(super) delay: Duration,
+    /// first `load_state_failures` load_state calls fail with retryable error
+    pub(super) load_state_failures: usize,
+    pub(super) libs: HashMap<TonHash, TonCell>,
+    pub(super) mc_seqnos: Vec<u32>,
+    pub(super) stats: Arc<MockProviderStats>,
+}
+
+#[derive(Default)]
+pub(super) struct MockProviderStats {
+    pub(super) load_state_calls: AtomicUsize,
+    pub(super) active_lib_loads: AtomicUsize,
+    pub(super) peak_lib_loads: AtomicUsize,
+    pub(super) processed_seqnos: Mutex<Vec<u32>>,
+}
+
+#[async_trait]
+impl TonProvider for MockProvider {
+    async fn last_mc_seqno(&self) -> Result<u32, TonCoreError> { Ok(1) }
+
+    async fn load_state(&self, address: TonAddress, _: Option<TxLTHash>) -> Result<TonContractState, TonCoreError> {
+        tokio::time::sleep(self.delay).await;
+        if self.stats.load_state_calls.fetch_add(1, Relaxed) < self.load_state_failures {
+            return Err(TonCoreError::Custom("connection reset".to_string()));
+        }
+        Ok(TonContractState {
+            mc_seqno: None,
+            address,
+            last_tx_id: TxLTHash::ZERO,
+            code_boc: None,
+            data_boc: None,
+            frozen_hash: None,
+            balance: 0,
+        })
+    }
+
+    async fn load_bc_config(&self, _: Option<u32>) -> Result<Vec<u8>, TonCoreError> { Ok(vec![]) }
+
+    async fn load_libs(&self, lib_ids: Vec<TonHash>, _: Option<u32>) -> Result<Vec<(TonHash, Vec<u8>)>, TonCoreError> {
+        let active = self.stats.active_lib_loads.fetch_add(1, Relaxed) + 1;
+        self.stats.peak_lib_loads.fetch_max(active, Relaxed);
+        tokio::time::sleep(self.delay).await;
+        self.stats.active_lib_loads.fetch_sub(1, Relaxed);
+        let libs = lib_ids.into_iter().filter_map(|id| self.libs.get(&id).map(|lib| (id, lib)));
+        libs.map(|(id, lib)| Ok((id, lib.to_boc()?))).collect()
+    }
+
+    async fn load_latest_tx_per_address(&self, mc_seqno: u32) -> Result<Vec<(TonAddress, TxLTHash)>, TonCoreError> {
+        self.stats.processed_seqnos.lock().push(mc_seqno);
+        Ok(vec![])
+    }
+
+    async fn subscribe_mc_blocks(self: Arc<Self>) -> Result<BoxStream<'static, u32>, TonCoreError> {
+        let seqnos = futures_util::stream::iter(self.mc_seqnos.clone());
+        Ok(seqnos.chain(futures_util::stream::pending()).boxed())
+    }
+}
@@ -1,15 +1,68 @@
 use crate::contracts::TonContract;
 use crate::errors::TonResult;
+use crate::tep::jetton::JettonTransferMsg;
 use crate::tep::tvm_result::GetWalletDataResult;
 use crate::ton_contract;
 use async_trait::async_trait;
+use ton_core::cell::TonCell;
+use ton_core::traits::tlb::TLB;
+use ton_core::types::{Coins, TonAddress};
 use ton_macros::ton_methods;
 
 // https://github.com/ton-blockchain/TEPs/blob/master/text/0074-jettons-standard.md#jetton-wallet-smart-contract
 ton_contract!(JettonWalletContract: JettonWalletMethods);
 
+impl JettonWalletContract {
+    /// Builds TEP-74 transfer body to be sent to this wallet by its owner
+    pub fn build_transfer(
+        &self,
+        query_id: u64,
+        amount: Coins,
+        destination: &TonAddress,
+        response_dest: &TonAddress,
+        forward_ton: Coins,
+        forward_payload: Option<TonCell>,
+    ) -> TonResult<TonCell> {
+        let msg =
+            JettonTransferMsg::new_transfer(query_id, amount, destination, response_dest, forward_ton, forward_payload);
+        Ok(msg.to_cell()?)
+    }
+}
+
 #[async_trait]
 #[ton_methods]
 pub trait JettonWalletMethods: TonContract {
     async fn get_wallet_data(&self) -> TonResult<GetWalletDataResult>;
 }
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::contracts::ContractClient;
+    use crate::contracts::contract_client::mock_provider::MockProvider;
+    use crate::test_fixtures::USDT_TRANSFER_MSG_BOC_HEX;
+    use std::str::FromStr;
+
+    #[tokio::test]
+    async fn test_jetton_wallet_build_transfer() -> anyhow::Result<()> {
+        let known_msg = JettonTransferMsg::<TonCell, TonCell>::from_boc_hex(USDT_TRANSFER_MSG_BOC_HEX)?;
+
+        let client = ContractClient::builder(MockProvider::default())?.with_emulator_pool_size(1).build()?;
+        let wallet_address = TonAddress::from_str("EQCxE6mUtQJKFnGfaROTKOt1lZbDiiX1kCixRv7Nw2Id_sDs")?;
+        let wallet = JettonWalletContract::new(&client, &wallet_address, None).await?;
+
+        let dst = TonAddress::from_str("0:7a92a3c8124a6d5126199765a0833f74db5d73d92253467062803eee117a580f")?;
+        let response_dst = TonAddress::from_str("0:7d767309d795453a0e1a0dbbd672aa0b810f001b5013d44aadfba24f8e3d7e36")?;
+        let body = wallet.build_transfer(
+            known_msg.query_id,
+            Coins::new(2500000000000),
+            &dst,
+            &response_dst,
+            Coins::new(600000000),
+            Some(known_msg.forward_payload.value.clone()),
+        )?;
+        assert_eq!(JettonTransferMsg::<TonCell, TonCell>::from_cell(&body)?, known_msg);
+        assert_eq!(body.hash()?, TonCell::from_boc_hex(USDT_TRANSFER_MSG_BOC_HEX)?.hash()?);
+        Ok(())
+    }
+}
@@ -1,10 +1,18 @@
 use crate::errors::TonError;
+use crate::lite_client::LiteClient;
+use crate::tlb_adapters::{DictKeyAdapterUint, DictValAdapterTLB, TLBHashMap};
 use base64::Engine;
 use base64::engine::general_purpose::STANDARD;
 use serde::{Deserialize, Deserializer, Serialize, Serializer};
+use std::collections::HashMap;
 use std::ffi::CString;
 use std::ops::Deref;
 use std::sync::Arc;
+use ton_core::bail_ton_core_data;
+use ton_core::cell::TonCell;
+use ton_core::errors::TonCoreError;
+use ton_core::traits::tlb::TLB;
+use ton_core::types::tlb_core::TLBRef;
 
 // Custom serialization for EmulBCConfig
 pub mod serde_emul_bc_config {
@@ -63,4 +71,50 @@ impl EmulBCConfig {
         let boc_bytes = self.to_boc()?;
         Ok(hex::encode(boc_bytes))
     }
+
+    /// Returns a copy of the config with `gas_limit` (and `special_gas_limit`) capped in params 20 & 21 (GasLimitsPrices)
+    pub fn with_gas_limit(&self, gas_limit: u64) -> Result<Self, TonError> {
+        let root = TonCell::from_boc(self.to_boc()?)?;
+        let dict = TLBHashMap::<DictKeyAdapterUint<_>, DictValAdapterTLB<_>>::new(32);
+        let mut params: HashMap<u32, TLBRef<TonCell>> = dict.read(&mut root.parser())?;
+        for index in [20, 21] {
+            if let Some(param) = params.get_mut(&index) {
+                *param = TLBRef::new(cap_gas_limit(param, gas_limit)?);
+            }
+        }
+        let mut builder = TonCell::builder();
+        dict.write(&mut builder, &params)?;
+        Self::from_boc(&builder.build()?.to_boc()?)
+    }
+
+    // fetches config from the specified mc block, or from the latest one if None
+    pub async fn fetch(lite_client: &LiteClient, mc_seqno: Option<u32>) -> Result<Self, TonError> {
+        let config_boc = lite_client.get_config_boc_all(mc_seqno, None).await?;
+        Self::from_boc(&config_boc)
+    }
+}
+
+// https://github.com/ton-blockchain/ton/blob/6f745c04daf8861bb1791cffce6edb1beec62204/crypto/block/block.tlb#L709
+fn cap_gas_limit(gas_prices: &TonCell, gas_limit: u64) -> Result<TonCell, TonCoreError> {
+    let mut parser = gas_prices.parser();
+    let mut builder = TonCell::builder();
+    loop {
+        let tag: u8 = parser.read_num(8)?;
+        builder.write_num(&tag, 8)?;
+        match tag {
+            // gas_flat_pfx: flat_gas_limit, flat_gas_price, other:GasLimitsPrices
+            0xd1 => builder.write_bits(parser.read_bits(128)?, 128)?,
+            // gas_prices & gas_prices_ext: gas_price, gas_limit, [special_gas_limit], ...
+            0xdd | 0xde => {
+                let limits_cnt = if tag == 0xde { 2 } else { 1 };
+                builder.write_num(&parser.read_num::<u64>(64)?, 64)?;
+                for _ in 0..limits_cnt {
+                    builder.write_num(&parser.read_num::<u64>(64)?.min(gas_limit), 64)?;
+                }
+                builder.write_cell(&parser.read_remaining()?)?;
+                return builder.build();
+            }
+            _ => bail_ton_core_data!("Unexpected GasLimitsPrices tag: {tag:#x}"),
+        }
+    }
 }
@@ -15,6 +15,8 @@ pub struct Builder {
     max_thread_queue_len: usize,
     emulator_log_level: u32,
     emulator_debug_enabled: bool,
+    /// Pins worker threads to the cores (one core per thread). Must cover all threads
+    pin_to_cores: Option<Vec<usize>>,
 }
 
 impl Builder {
@@ -26,6 +28,7 @@ impl Builder {
             max_thread_queue_len: 10,
             emulator_log_level: 0,
             emulator_debug_enabled: false,
+            pin_to_cores: None,
         };
         Ok(builder)
     }
@@ -42,10 +45,13 @@ impl Builder {
             };
             workers.push(worker)
         }
-        let thread_pool = ThreadPool::builder(workers)?
+        let mut pool_builder = ThreadPool::builder(workers)?
             .with_default_exec_timeout(self.default_exec_timeout)
-            .with_max_thread_queue_len(self.max_thread_queue_len)
-            .build()?;
+            .with_max_thread_queue_len(self.max_thread_queue_len);
+        if let Some(cores) = self.pin_to_cores {
+            pool_builder = pool_builder.with_pin_to_cores(cores);
+        }
+        let thread_pool = pool_builder.build()?;
         Ok(EmulatorPool(thread_pool))
     }
 }
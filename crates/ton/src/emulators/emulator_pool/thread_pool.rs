@@ -1,15 +1,17 @@
 mod builder;
 mod task_counter;
+mod task_queue;
 
 pub use task_counter::*;
+pub use task_queue::Priority;
 
 use crate::emulators::emulator_pool::thread_pool::builder::Builder;
+use crate::emulators::emulator_pool::thread_pool::task_queue::TaskQueue;
 use crate::errors::{TonError, TonResult};
 use std::ops::Add;
 use std::sync::Arc;
 use std::sync::atomic::AtomicUsize;
 use std::sync::atomic::Ordering;
-use std::sync::mpsc::Sender;
 use std::time::{Duration, SystemTime};
 use tokio::sync::oneshot;
 
@@ -32,9 +34,19 @@ impl<Obj: PoolObject> ThreadPool<Obj> {
     pub fn builder(objects: Vec<Obj>) -> TonResult<Builder<Obj>> { Builder::new(objects) }
 
     pub async fn exec<T: Into<Obj::Task>>(&self, task: T, timeout: Option<Duration>) -> TonResult<Obj::Retval> {
+        self.exec_with_priority(task, Priority::Normal, timeout).await
+    }
+
+    /// High priority tasks are processed before Normal ones already waiting in the thread queue
+    pub async fn exec_with_priority<T: Into<Obj::Task>>(
+        &self,
+        task: T,
+        priority: Priority,
+        timeout: Option<Duration>,
+    ) -> TonResult<Obj::Retval> {
         let exec_timeout = timeout.unwrap_or(self.0.default_exec_timeout);
 
-        match tokio::time::timeout(exec_timeout, self.0.exec_impl(task.into(), exec_timeout)).await {
+        match tokio::time::timeout(exec_timeout, self.0.exec_impl(task.into(), priority, exec_timeout)).await {
             Ok(res) => res,
             Err(_) => Err(TonError::EmulatorPoolTimeout(exec_timeout)),
         }
@@ -71,7 +83,7 @@ impl<Obj: PoolObject> ThreadPool<Obj> {
         let mut total_done = 0;
         let mut total_failed = 0;
 
-        for idx in 0..self.0.senders.len() {
+        for idx in 0..self.0.queues.len() {
             let in_progress = self.0.counters[idx].in_progress.load(Ordering::Relaxed);
             let done_tasks = self.0.counters[idx].done.load(Ordering::Relaxed);
             let failed = self.0.counters[idx].failed.load(Ordering::Relaxed);
@@ -111,7 +123,7 @@ struct PoolTask<Obj: PoolObject> {
 }
 
 struct Inner<Obj: PoolObject> {
-    senders: Vec<Sender<PoolTask<Obj>>>,
+    queues: Vec<Arc<TaskQueue<PoolTask<Obj>>>>,
     counters: Vec<TaskCounter>,
     default_exec_timeout: Duration,
     max_thread_queue_len: usize,
@@ -119,11 +131,11 @@ struct Inner<Obj: PoolObject> {
 
 impl<Obj: PoolObject> Inner<Obj> {
     async fn find_free_thread(&self) -> usize {
-        let mut chosen_thread_pos = self.senders.len(); // invalid index
+        let mut chosen_thread_pos = self.queues.len(); // invalid index
         let mut chosen_queue_len = self.max_thread_queue_len + 1; // invalid length
 
         loop {
-            for pos in 0..self.senders.len() {
+            for pos in 0..self.queues.len() {
                 let cur_queue_len = self.counters[pos].in_progress.load(Ordering::Relaxed);
                 if cur_queue_len <= MIN_QUEUE_LEN_TO_ACCEPT_TASKS {
                     return pos;
@@ -133,14 +145,14 @@ impl<Obj: PoolObject> Inner<Obj> {
                     chosen_thread_pos = pos;
                 }
             }
-            if chosen_thread_pos < self.senders.len() {
+            if chosen_thread_pos < self.queues.len() {
                 return chosen_thread_pos;
             }
             tokio::time::sleep(SLEEP_ON_FULL_QUEUE).await;
         }
     }
 
-    async fn exec_impl(&self, task: Obj::Task, timeout: Duration) -> TonResult<Obj::Retval> {
+    async fn exec_impl(&self, task: Obj::Task, priority: Priority, timeout: Duration) -> TonResult<Obj::Retval> {
         let (tx, rx) = oneshot::channel();
         let pool_task = PoolTask {
             task,
@@ -152,13 +164,19 @@ impl<Obj: PoolObject> Inner<Obj> {
         let thread_idx = self.find_free_thread().await;
         let counter_updater = self.counters[thread_idx].task_added();
 
-        self.senders[thread_idx].send(pool_task).map_err(TonError::system)?;
+        if self.queues[thread_idx].push(pool_task, priority).is_err() {
+            return Err(TonError::system(format!("ThreadPool thread {thread_idx} is not running")));
+        }
         let emul_result = rx.await.map_err(TonError::system)?;
         counter_updater.task_done();
         emul_result
     }
 }
 
+impl<Obj: PoolObject> Drop for Inner<Obj> {
+    fn drop(&mut self) { self.queues.iter().for_each(|queue| queue.close()); }
+}
+
 #[cfg(test)]
 mod tests {
     use super::*;
@@ -192,4 +210,145 @@ mod tests {
         assert_eq!(counter.failed.load(Ordering::Relaxed), 0);
         Ok(())
     }
+
+    #[tokio::test]
+    async fn test_thread_pool_pin_to_cores() -> anyhow::Result<()> {
+        let objects = vec![TestObject(1), TestObject(2)];
+        assert!(ThreadPool::builder(objects)?.with_pin_to_cores(vec![0]).build().is_err());
+
+        // cores available to the current process (respects cgroup / taskset limits)
+        let core_ids: Vec<_> =
+            core_affinity::get_core_ids().unwrap_or_default().into_iter().map(|core| core.id).collect();
+        let Some(&valid_core) = core_ids.first() else {
+            return Ok(()); // pinning is not supported on this platform
+        };
+
+        // build fails unless both threads report they are pinned to valid_core
+        let objects = vec![TestObject(1), TestObject(2)];
+        let pool = ThreadPool::builder(objects)?
+            .with_pin_to_cores(vec![valid_core, valid_core])
+            .with_default_exec_timeout(Duration::from_secs(1))
+            .build()?;
+        for task in 0..10usize {
+            let result = pool.exec(task, None).await?;
+            assert!(result == 1000 + task || result == 2000 + task);
+        }
+        let counter = pool.get_counters_aggregated();
+        assert_eq!(counter.in_progress.load(Ordering::Relaxed), 0);
+        assert_eq!(counter.done.load(Ordering::Relaxed), 10);
+
+        // stay below 1024: cpu_set_t can't hold larger ids
+        let Some(invalid_core) = (0..1024).find(|id| !core_ids.contains(id)) else {
+            return Ok(());
+        };
+        let objects = vec![TestObject(1)];
+        let err = ThreadPool::builder(objects)?.with_pin_to_cores(vec![invalid_core]).build().err().unwrap();
+        assert!(err.to_string().contains("Failed to pin"), "{err}");
+        Ok(())
+    }
+
+    struct PanicObject;
+
+    impl PoolObject for PanicObject {
+        type Task = usize;
+        type Retval = usize;
+        fn process<T: Into<Self::Task>>(&mut self, _task: T) -> Result<usize, TonError> { panic!("thread is dead") }
+    }
+
+    #[tokio::test]
+    async fn test_thread_pool_send_failure_releases_queue_slot() -> anyhow::Result<()> {
+        let pool = ThreadPool::builder(vec![PanicObject])?.with_default_exec_timeout(Duration::from_secs(1)).build()?;
+        // 1st task kills the thread, 2nd one fails to send
+        assert!(pool.exec(1usize, None).await.is_err());
+        tokio::time::sleep(Duration::from_millis(50)).await; // let the thread unwind
+        let started = std::time::Instant::now();
+        assert!(pool.exec(2usize, None).await.is_err());
+        assert!(started.elapsed() < Duration::from_millis(500));
+
+        let counter = pool.get_counters_aggregated();
+        assert_eq!(counter.in_progress.load(Ordering::Relaxed), 0);
+        assert_eq!(counter.done.load(Ordering::Relaxed), 0);
+        assert_eq!(counter.failed.load(Ordering::Relaxed), 2);
+        Ok(())
+    }
+
+    #[tokio::test]
+    async fn test_thread_pool_panic_fails_pending_tasks() -> anyhow::Result<()> {
+        let pool = ThreadPool::builder(vec![PanicObject])?.with_default_exec_timeout(Duration::from_secs(5)).build()?;
+        // 1st task kills the thread, 2nd one is still in the queue and must fail without waiting for timeout
+        let started = std::time::Instant::now();
+        let (first, second) = tokio::join!(pool.exec(1usize, None), pool.exec(2usize, None));
+        assert!(first.is_err());
+        assert!(second.is_err());
+        assert!(started.elapsed() < Duration::from_secs(1));
+        Ok(())
+    }
+
+    struct SlowObject;
+
+    impl PoolObject for SlowObject {
+        type Task = usize;
+        type Retval = usize;
+        fn process<T: Into<Self::Task>>(&mut self, task: T) -> Result<usize, TonError> {
+            std::thread::sleep(Duration::from_millis(50));
+            Ok(task.into())
+        }
+    }
+
+    #[tokio::test]
+    async fn test_thread_pool_timeout_releases_queue_slot() -> anyhow::Result<()> {
+        let pool = ThreadPool::builder(vec![SlowObject])?.build()?;
+        let result = pool.exec(1usize, Some(Duration::from_millis(5))).await;
+        assert!(matches!(result, Err(TonError::EmulatorPoolTimeout(_))));
+
+        let counter = pool.get_counters_aggregated();
+        assert_eq!(counter.in_progress.load(Ordering::Relaxed), 0);
+        assert_eq!(counter.failed.load(Ordering::Relaxed), 1);
+        Ok(())
+    }
+
+    // task is (id, sleep_ms); records ids in processing order
+    struct OrderObject(Arc<parking_lot::Mutex<Vec<usize>>>);
+
+    impl PoolObject for OrderObject {
+        type Task = (usize, u64);
+        type Retval = usize;
+        fn process<T: Into<Self::Task>>(&mut self, task: T) -> Result<usize, TonError> {
+            let (id, sleep_ms) = task.into();
+            std::thread::sleep(Duration::from_millis(sleep_ms));
+            self.0.lock().push(id);
+            Ok(id)
+        }
+    }
+
+    #[tokio::test]
+    async fn test_thread_pool_priority() -> anyhow::Result<()> {
+        let order = Arc::new(parking_lot::Mutex::new(vec![]));
+        let pool = ThreadPool::builder(vec![OrderObject(order.clone())])?
+            .with_default_exec_timeout(Duration::from_secs(5))
+            .build()?;
+
+        // occupy the only thread, so the rest tasks are queued
+        let slow_pool = pool.clone();
+        let slow = tokio::spawn(async move { slow_pool.exec((0usize, 100u64), None).await });
+        tokio::time::sleep(Duration::from_millis(20)).await;
+
+        let mut handles = vec![];
+        for (id, priority) in [
+            (1, Priority::Normal),
+            (2, Priority::Normal),
+            (3, Priority::High),
+            (4, Priority::High),
+        ] {
+            let pool = pool.clone();
+            handles.push(tokio::spawn(async move { pool.exec_with_priority((id, 1u64), priority, None).await }));
+            tokio::time::sleep(Duration::from_millis(5)).await;
+        }
+        assert_eq!(slow.await??, 0);
+        for handle in handles {
+            handle.await??;
+        }
+        assert_eq!(*order.lock(), vec![0, 3, 4, 1, 2]);
+        Ok(())
+    }
 }
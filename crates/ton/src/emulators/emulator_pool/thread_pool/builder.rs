@@ -1,9 +1,11 @@
+use crate::bail_ton;
 use crate::emulators::emulator_pool::thread_pool::task_counter::TaskCounter;
+use crate::emulators::emulator_pool::thread_pool::task_queue::TaskQueue;
 use crate::emulators::emulator_pool::thread_pool::{Inner, PoolObject, PoolTask, ThreadPool};
 use crate::errors::{TonError, TonResult};
 use derive_setters::Setters;
-use std::sync::mpsc::Receiver;
-use std::sync::{Arc, mpsc};
+use std::sync::Arc;
+use std::sync::mpsc;
 use std::thread;
 use std::time::{Duration, SystemTime};
 
@@ -14,6 +16,8 @@ pub struct Builder<Obj: PoolObject> {
     emulators: Vec<Obj>,
     default_exec_timeout: Duration,
     max_thread_queue_len: usize,
+    /// i-th thread is pinned to core pin_to_cores[i]
+    pin_to_cores: Option<Vec<usize>>,
 }
 
 impl<Obj: PoolObject> Builder<Obj> {
@@ -22,36 +26,79 @@ impl<Obj: PoolObject> Builder<Obj> {
             emulators,
             default_exec_timeout: Duration::from_millis(10),
             max_thread_queue_len: 10,
+            pin_to_cores: None,
         })
     }
     pub fn build(mut self) -> TonResult<ThreadPool<Obj>> {
         let threads_count = self.emulators.len();
+        if let Some(cores) = &self.pin_to_cores {
+            if cores.len() < threads_count {
+                bail_ton!("Can't pin {threads_count} threads to {} cores: {cores:?}", cores.len());
+            }
+        }
 
-        let mut senders = Vec::with_capacity(threads_count);
+        let mut queues = Vec::with_capacity(threads_count);
         let mut counters = Vec::with_capacity(threads_count);
+        // each pinned thread reports (core_id, pinned) before processing tasks
+        let (pin_sender, pin_receiver) = mpsc::channel();
 
         for id in 0..threads_count {
-            let (tx, rx) = mpsc::channel::<PoolTask<Obj>>();
+            let queue = Arc::new(TaskQueue::<PoolTask<Obj>>::new());
             let obj = self.emulators.pop().unwrap();
-            let _ = thread::spawn(move || thread_loop(obj, rx, id));
-            senders.push(tx);
+            let thread_queue = queue.clone();
+            let pin_to = self.pin_to_cores.as_ref().map(|cores| (cores[id], pin_sender.clone()));
+            let _ = thread::spawn(move || thread_loop(obj, thread_queue, id, pin_to));
+            queues.push(queue);
             counters.push(TaskCounter::new());
         }
+        drop(pin_sender);
+        // on error, dropping inner closes the queues, so all threads exit
         let inner = Inner {
             default_exec_timeout: self.default_exec_timeout,
             max_thread_queue_len: self.max_thread_queue_len,
-            senders,
+            queues,
             counters,
         };
+        if self.pin_to_cores.is_some() {
+            for _ in 0..threads_count {
+                match pin_receiver.recv() {
+                    Ok((_, true)) => {}
+                    Ok((core_id, false)) => bail_ton!("Failed to pin emulator thread to core {core_id}"),
+                    Err(_) => bail_ton!("Emulator thread exited before pinning to core"),
+                }
+            }
+        }
         Ok(ThreadPool(Arc::new(inner)))
     }
 }
 
-fn thread_loop<Obj: PoolObject>(mut obj: Obj, receiver: Receiver<PoolTask<Obj>>, id: usize) {
+// closes the queue even if thread panics, so new tasks are rejected immediately
+struct CloseOnDrop<'a, T>(&'a TaskQueue<T>);
+
+impl<T> Drop for CloseOnDrop<'_, T> {
+    fn drop(&mut self) { self.0.close(); }
+}
+
+fn thread_loop<Obj: PoolObject>(
+    mut obj: Obj,
+    queue: Arc<TaskQueue<PoolTask<Obj>>>,
+    id: usize,
+    pin_to: Option<(usize, mpsc::Sender<(usize, bool)>)>,
+) {
     let log_prefix = format!("EmulatorPool][{}][{}", obj.descriptor(), id);
     log::debug!("[{log_prefix}] thread started");
+    let _close_guard = CloseOnDrop(&queue);
+    if let Some((core_id, pin_sender)) = pin_to {
+        let pinned = core_affinity::set_for_current(core_affinity::CoreId { id: core_id });
+        let _ = pin_sender.send((core_id, pinned));
+        if !pinned {
+            log::warn!("[{log_prefix}] failed to pin thread to core {core_id}, exiting");
+            return;
+        }
+        log::debug!("[{log_prefix}] thread pinned to core {core_id}");
+    }
 
-    while let Ok(task) = receiver.recv() {
+    while let Some(task) = queue.pop() {
         if SystemTime::now() > task.deadline {
             let _ = task.rsp_sender.send(Err(TonError::EmulatorPoolTimeout(task.timeout)));
             continue;
@@ -0,0 +1,108 @@
+use parking_lot::{Condvar, Mutex};
+use std::collections::VecDeque;
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default)]
+pub enum Priority {
+    #[default]
+    Normal,
+    High, // processed before any Normal task in the same thread
+}
+
+/// Per-thread queue: High tasks are always taken first
+pub(super) struct TaskQueue<T> {
+    state: Mutex<QueueState<T>>,
+    cond: Condvar,
+}
+
+struct QueueState<T> {
+    high: VecDeque<T>,
+    normal: VecDeque<T>,
+    closed: bool,
+}
+
+impl<T> TaskQueue<T> {
+    pub(super) fn new() -> Self {
+        Self {
+            state: Mutex::new(QueueState {
+                high: VecDeque::new(),
+                normal: VecDeque::new(),
+                closed: false,
+            }),
+            cond: Condvar::new(),
+        }
+    }
+
+    /// Returns task back if queue is closed
+    pub(super) fn push(&self, task: T, priority: Priority) -> Result<(), T> {
+        let mut state = self.state.lock();
+        if state.closed {
+            return Err(task);
+        }
+        match priority {
+            Priority::High => state.high.push_back(task),
+            Priority::Normal => state.normal.push_back(task),
+        }
+        drop(state);
+        self.cond.notify_one();
+        Ok(())
+    }
+
+    /// Blocks until task is available. Returns None if queue is closed
+    pub(super) fn pop(&self) -> Option<T> {
+        let mut state = self.state.lock();
+        loop {
+            if state.closed {
+                return None;
+            }
+            if let Some(task) = state.high.pop_front().or_else(|| state.normal.pop_front()) {
+                return Some(task);
+            }
+            self.cond.wait(&mut state);
+        }
+    }
+
+    /// Drops pending tasks, so their senders are released and callers get an error immediately
+    pub(super) fn close(&self) {
+        let mut state = self.state.lock();
+        state.closed = true;
+        let pending = (std::mem::take(&mut state.high), std::mem::take(&mut state.normal));
+        drop(state);
+        self.cond.notify_all();
+        drop(pending);
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_task_queue_priority() -> anyhow::Result<()> {
+        let queue = TaskQueue::new();
+        queue.push(1, Priority::Normal).unwrap();
+        queue.push(2, Priority::High).unwrap();
+        queue.push(3, Priority::Normal).unwrap();
+        queue.push(4, Priority::High).unwrap();
+        let order: Vec<_> = (0..4).map(|_| queue.pop().unwrap()).collect();
+        assert_eq!(order, vec![2, 4, 1, 3]);
+
+        queue.close();
+        assert_eq!(queue.pop(), None);
+        assert_eq!(queue.push(5, Priority::High), Err(5));
+        Ok(())
+    }
+
+    #[test]
+    fn test_task_queue_close_drops_pending() -> anyhow::Result<()> {
+        let task = std::sync::Arc::new(());
+        let queue = TaskQueue::new();
+        queue.push(task.clone(), Priority::Normal).unwrap();
+        queue.push(task.clone(), Priority::High).unwrap();
+        assert_eq!(std::sync::Arc::strong_count(&task), 3);
+
+        queue.close();
+        assert_eq!(std::sync::Arc::strong_count(&task), 1);
+        assert!(queue.pop().is_none());
+        Ok(())
+    }
+}
@@ -0,0 +1,122 @@
+use crate::block_tlb::TVMStack;
+use crate::emulators::emul_bc_config::EmulBCConfig;
+use crate::emulators::tvm_emulator::{TVMEmulator, TVMEmulatorC7, TVMGetMethodID, TVMGetMethodResponse, TVMState};
+use crate::errors::{TonError, TonResult};
+use std::sync::Arc;
+use ton_core::cell::TonCell;
+use ton_core::traits::tlb::TLB;
+use ton_core::types::TonAddress;
+
+#[derive(Debug, Clone)]
+pub struct GetMethodResult {
+    pub exit_code: i32,
+    pub stack: TVMStack,
+    pub gas_used: u64,
+}
+
+impl GetMethodResult {
+    pub fn exit_success(&self) -> bool { self.exit_code == 0 || self.exit_code == 1 }
+}
+
+/// Runs get-methods against account code & data offline
+/// Unlike TVMEmulator::emul_get_method, returns result for non-zero exit codes as well
+#[derive(Debug, Clone)]
+pub struct GetMethodEmulator {
+    state: TVMState,
+}
+
+impl GetMethodEmulator {
+    pub fn new(address: TonAddress, code: &TonCell, data: &TonCell, config: EmulBCConfig) -> TonResult<Self> {
+        let state = TVMState {
+            code_boc: Arc::new(code.to_boc()?),
+            data_boc: Arc::new(data.to_boc()?),
+            c7: TVMEmulatorC7::new(address, config)?,
+            libs_boc: None,
+            debug_enabled: None,
+            gas_limit: None,
+        };
+        Ok(Self { state })
+    }
+
+    pub fn with_libs(mut self, libs_boc: Vec<u8>) -> Self {
+        self.state.libs_boc = Some(Arc::new(libs_boc));
+        self
+    }
+
+    pub fn with_gas_limit(mut self, gas_limit: u64) -> Self {
+        self.state.gas_limit = Some(gas_limit);
+        self
+    }
+
+    pub fn state(&self) -> &TVMState { &self.state }
+
+    pub fn run<M: Into<TVMGetMethodID>>(&self, method: M, stack: &TVMStack) -> TonResult<GetMethodResult> {
+        let mut emulator = TVMEmulator::from_state(&self.state)?;
+        let response = emulator.emul_get_method(method, &stack.to_boc()?)?;
+        Self::make_result(response)
+    }
+
+    fn make_result(response: TVMGetMethodResponse) -> TonResult<GetMethodResult> {
+        let (true, Some(exit_code), Some(gas_used)) = (response.success, response.vm_exit_code, &response.gas_used)
+        else {
+            return Err(TonError::EmulatorEmulationError {
+                vm_exit_code: response.vm_exit_code,
+                response_raw: response.raw_response,
+            });
+        };
+        let gas_used = gas_used.parse::<u64>()?;
+        let stack = match &response.stack {
+            Some(stack_b64) => TVMStack::from_boc_base64(stack_b64)?,
+            None => TVMStack::default(),
+        };
+        Ok(GetMethodResult {
+            exit_code,
+            stack,
+            gas_used,
+        })
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::sys_utils::sys_tonlib_set_verbosity_level;
+    use crate::test_fixtures::{USDT_MASTER_CODE_BOC_HEX, USDT_MASTER_DATA_BOC_HEX};
+    use std::str::FromStr;
+    use std::sync::LazyLock;
+
+    static BC_CONFIG: LazyLock<EmulBCConfig> = LazyLock::new(|| {
+        EmulBCConfig::from_boc_hex(include_str!(concat!(
+            env!("CARGO_MANIFEST_DIR"),
+            "/resources/tests/bc_config_key_block_42123611.hex"
+        )))
+        .unwrap()
+    });
+
+    #[test]
+    fn test_get_method_emulator_usdt_get_wallet_address() -> anyhow::Result<()> {
+        sys_tonlib_set_verbosity_level(0);
+        let owner_address = TonAddress::from_str("EQB2BtXDXaQuIcMYW7JEWhHmwHfPPwa-eoCdefiAxOhU3pQg")?;
+        // USDT
+        let master_address = TonAddress::from_str("EQCxE6mUtQJKFnGfaROTKOt1lZbDiiX1kCixRv7Nw2Id_sDs")?;
+        let master_code = TonCell::from_boc_hex(USDT_MASTER_CODE_BOC_HEX)?;
+        let master_data = TonCell::from_boc_hex(USDT_MASTER_DATA_BOC_HEX)?;
+        let emulator = GetMethodEmulator::new(master_address, &master_code, &master_data, BC_CONFIG.clone())?;
+
+        let mut stack = TVMStack::default();
+        stack.push_cell_slice(owner_address.to_cell()?);
+        let mut result = emulator.run("get_wallet_address", &stack)?;
+        assert!(result.exit_success());
+        assert!(result.gas_used > 0);
+        let wallet_address = TonAddress::from_cell(&result.stack.pop_cell()?)?;
+        assert_eq!(
+            wallet_address,
+            TonAddress::from_str("0:89c154a4225ebfa810891236ba01975f1f8860d2b090be3036fa05c36b53c77c")?
+        );
+
+        // no such method => non-zero exit code is returned as result
+        let result = emulator.run("unknown_method", &TVMStack::default())?;
+        assert_eq!(result.exit_code, 11);
+        Ok(())
+    }
+}
@@ -1,9 +1,8 @@
+use crate::tep::get_method_id::method_id;
 use crc::{CRC_32_ISO_HDLC, Crc};
 use std::borrow::Cow;
 use std::fmt::{Debug, Display, Formatter};
 
-const CRC_16_XMODEM: Crc<u16> = Crc::<u16>::new(&crc::CRC_16_XMODEM);
-
 #[derive(Clone, Eq, PartialEq, Hash)]
 pub enum TVMGetMethodID {
     Number(i32),
@@ -25,7 +24,7 @@ impl TVMGetMethodID {
 
     pub fn to_id(&self) -> i32 {
         match self {
-            TVMGetMethodID::Name(name) => CRC_16_XMODEM.checksum(name.as_bytes()) as i32 | 0x10000,
+            TVMGetMethodID::Name(name) => method_id(name),
             TVMGetMethodID::Number(id) => *id,
         }
     }
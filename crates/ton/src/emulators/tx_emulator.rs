@@ -6,13 +6,17 @@ pub use c7_prev_blocks_info::*;
 pub use tx_emul_args::*;
 pub use tx_emul_response::*;
 
+use crate::block_tlb::{CommonMsgInfo, Msg, ShardAccount};
 use crate::emulators::emul_bc_config::EmulBCConfig;
 use crate::emulators::emul_utils::{convert_emulator_response, make_base64_c_str, set_param_failed};
 use crate::errors::{TonError, TonResult};
+use std::collections::{HashMap, VecDeque};
 use std::ffi::CString;
 use std::sync::Arc;
 use ton_core::cell::TonHash;
 use ton_core::constants::TON_ZERO_CONFIG_BOC_B64;
+use ton_core::traits::tlb::TLB;
+use ton_core::types::TonAddress;
 use tonlib_sys::*;
 
 pub struct TXEmulator {
@@ -24,6 +28,8 @@ pub struct TXEmulator {
     cur_libs_hash: u64,
     cur_ignore_chksig: bool,
     cur_prev_blocks_info_hash: u64,
+    default_debug_enabled: bool,
+    cur_debug_enabled: bool,
 }
 
 impl TXEmulator {
@@ -42,8 +48,10 @@ impl TXEmulator {
             cur_libs_hash: calc_hash([]),
             cur_ignore_chksig: false,
             cur_prev_blocks_info_hash: 0,
+            default_debug_enabled: debug_enabled,
+            cur_debug_enabled: false,
         };
-        emulator.set_debug_enabled(debug_enabled)?;
+        emulator.actualize_debug_enabled(debug_enabled)?;
         Ok(emulator)
     }
 
@@ -69,8 +77,54 @@ impl TXEmulator {
         let response_str = convert_emulator_response(response_ptr)?;
         TXEmulationResponse::from_json(response_str)
     }
+
+    /// Emulates `initial_msg`, then routes produced internal messages to their destinations found in `accounts`
+    ///
+    /// `emul_args.shard_account_boc` is ignored: shard accounts are taken from `accounts` and updated after each tx.
+    /// Messages to unknown accounts are not emulated. `max_hops` limits chain depth (0 - initial_msg only).
+    /// Returns emulated txs in processing (BFS) order, fails on the first failed emulation
+    pub fn emulate_chain(
+        &mut self,
+        initial_msg: &Msg,
+        accounts: &HashMap<TonAddress, ShardAccount>,
+        emul_args: &TXEmulArgs,
+        max_hops: usize,
+    ) -> TonResult<Vec<TXEmulationSuccess>> {
+        let mut accounts = accounts.clone();
+        let mut queue = VecDeque::from([(initial_msg.clone(), 0)]);
+        let mut args = TXEmulOrdArgs {
+            in_msg_boc: Arc::default(),
+            emul_args: emul_args.clone(),
+        };
+        let mut results = vec![];
+        while let Some((msg, hop)) = queue.pop_front() {
+            if matches!(msg.info(), CommonMsgInfo::ExtOut(_)) {
+                continue;
+            }
+            let dst = TonAddress::from_msg_address(msg.dst())?;
+            let Some(shard_account) = accounts.get(&dst) else {
+                continue;
+            };
+            args.in_msg_boc = Arc::new(msg.to_boc()?);
+            args.emul_args.shard_account_boc = Arc::new(shard_account.to_boc()?);
+            let success = self.emulate_ord(&args)?.into_success()?;
+            let tx = success.tx_parsed()?;
+            accounts.insert(dst, success.shard_account_parsed()?);
+            // next tx must start after all lts allocated by current one
+            args.emul_args.lt = args.emul_args.lt.max(tx.lt + tx.out_msgs_cnt as u64 + 1);
+            if hop < max_hops {
+                queue.extend(tx.out_messages().iter().map(|out_msg| (out_msg.clone(), hop + 1)));
+            }
+            results.push(success);
+        }
+        Ok(results)
+    }
+
     fn prepare_emulator(&mut self, args: &TXEmulArgs) -> TonResult<()> {
-        self.actualize_config(&args.bc_config)?;
+        match args.gas_limit {
+            Some(gas_limit) => self.actualize_config(&args.bc_config.with_gas_limit(gas_limit)?)?,
+            None => self.actualize_config(&args.bc_config)?,
+        }
         self.actualize_rand_seed(&args.rand_seed)?;
         self.actualize_utime(args.utime)?;
         self.actualize_lt(args.lt)?;
@@ -81,6 +135,7 @@ impl TXEmulator {
         if let Some(prev_blocks) = &args.c7_prev_blocks_info_boc {
             self.actualize_prev_blocks_info(prev_blocks.as_ref())?;
         }
+        self.actualize_debug_enabled(self.default_debug_enabled || args.debug_enabled)?;
         Ok(())
     }
 
@@ -144,11 +199,15 @@ impl TXEmulator {
         Ok(())
     }
 
-    fn set_debug_enabled(&mut self, debug_enabled: bool) -> TonResult<()> {
+    fn actualize_debug_enabled(&mut self, debug_enabled: bool) -> TonResult<()> {
+        if self.cur_debug_enabled == debug_enabled {
+            return Ok(());
+        }
         match unsafe { transaction_emulator_set_debug_enabled(self.emulator, debug_enabled) } {
-            true => Ok(()),
-            false => set_param_failed("debug_enabled"),
+            true => self.cur_debug_enabled = debug_enabled,
+            false => return set_param_failed("debug_enabled"),
         }
+        Ok(())
     }
 
     fn actualize_prev_blocks_info(&mut self, prev_blocks_info: &[u8]) -> TonResult<()> {
@@ -196,19 +255,17 @@ fn calc_hash<T: AsRef<[u8]> + std::hash::Hash>(data: T) -> u64 {
 #[cfg(test)]
 mod tests {
     use super::*;
-    use crate::block_tlb::{Msg, ShardAccount, Tx};
+    use crate::block_tlb::Tx;
+    use crate::test_fixtures::{EXPECTED_TX_BOC_HEX, EXT_IN_MSG_BOC_HEX, SHARD_ACCOUNT_BOC_HEX};
 
     use crate::sys_utils::sys_tonlib_set_verbosity_level;
     use std::str::FromStr;
     use std::sync::LazyLock;
     use tokio_test::{assert_err, assert_ok};
-    use ton_core::traits::tlb::TLB;
     #[allow(dead_code)]
     const VM_CODE_NOT_ENOUGH_LIBS: i32 = 9;
-    static TEST_EXPECTED_TX: LazyLock<Tx> = LazyLock::new(|| {
-        Tx::from_boc_hex(
-            "b5ee9c7241020c010002f50003b5792eb9106ca20295132ce6170ece2338ba10342134a3ca0d9e499f21c9b4897e4000030a49dab028194fb2314023373e7b36b05b69e31508eba9ba24a60e994060fee1ca55302f8c2000030a4972bcd43679cb7df00034657bf0280102030201e00405008272fb026ad92478055ab0086833e193b9e2ad35aa0073769228fcdc27ed38ef72a4c533ffcf55fd97275de407b0068404ed61966be66ec1e82d6c49d100f01e6064020f0c51c618a18604400a0b01e1880125d7220d944052a2659cc2e1d9c4671742068426947941b3c933e43936912fc800000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000014d4d18bb3ce5c84000000088001c060101df07016862004975c883aea91de93142ae4dc222d803c74e5f130f37ef0d42fb353897fd0f982068e77800000000000000000000000000010801b1680125d7220d944052a2659cc2e1d9c4671742068426947941b3c933e43936912fc90024bae441d7548ef498a15726e1116c01e3a72f89879bf786a17d9a9c4bfe87cc103473bc000614884c000061493b560504cf396fbec00801b20f8a7ea500000000000000005012a05f20080129343398aec31cdbbf7d32d977c27a96d5cd23c38fd4bd47be019abafb9b356b0024bae441b2880a544cb3985c3b388ce2e840d084d28f283679267c8726d225f90814dc9381090099259385618012934339d11465553b2f3e428ae79b0b1e2fd250b80784d4996dd44741736528ca0259f3a0f90024bae441b2880a544cb3985c3b388ce2e840d084d28f283679267c8726d225f910009d419d8313880000000000000000110000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000020006fc987b3184c14882800000000000200000000000224cb2890dee94c80761e06b8c446b1a9835aff2fc055cee75373ceeceffa6b4240d03f644db9e7b3").unwrap()
-    });
+    const VM_CODE_OUT_OF_GAS: i32 = -14;
+    static TEST_EXPECTED_TX: LazyLock<Tx> = LazyLock::new(|| Tx::from_boc_hex(EXPECTED_TX_BOC_HEX).unwrap());
 
     static TEST_EXPECTED_SHARD_ACCOUNT: LazyLock<ShardAccount> = LazyLock::new(|| {
         ShardAccount::from_boc_hex("b5ee9c7241021701000366000150775a15d6954e05b73e0c25729e776e6be6328ed14ebaf7262014603827198d24000030a49dab028101026fc0092eb9106ca20295132ce6170ece2338ba10342134a3ca0d9e499f21c9b4897e422c858e433ce5bef80000c29276ac0a0d036dd880934002030114ff00f4a413f4bcf2c80b0400510000001229a9a317cbf377c9b73604c70bf73488ddceba14f763baef2ac70f68d1d6032a120149f4400201200506020148070804f8f28308d71820d31fd31fd31f02f823bbf264ed44d0d31fd31fd3fff404d15143baf2a15151baf2a205f901541064f910f2a3f80024a4c8cb1f5240cb1f5230cbff5210f400c9ed54f80f01d30721c0009f6c519320d74a96d307d402fb00e830e021c001e30021c002e30001c0039130e30d03a4c8cb1f12cb1fcbff1314151602e6d001d0d3032171b0925f04e022d749c120925f04e002d31f218210706c7567bd22821064737472bdb0925f05e003fa403020fa4401c8ca07cbffc9d0ed44d0810140d721f404305c810108f40a6fa131b3925f07e005d33fc8258210706c7567ba923830e30d03821064737472ba925f06e30d090a0201200b0c007801fa00f40430f8276f2230500aa121bef2e0508210706c7567831eb17080185004cb0526cf1658fa0219f400cb6917cb1f5260cb3f20c98040fb0006008a5004810108f45930ed44d0810140d720c801cf16f400c9ed540172b08e23821064737472831eb17080185005cb055003cf1623fa0213cb6acb1fcb3fc98040fb00925f03e20201200d0e0059bd242b6f6a2684080a06b90fa0218470d4080847a4937d29910ce6903e9ff9837812801b7810148987159f31840201580f100011b8c97ed44d0d70b1f8003db29dfb513420405035c87d010c00b23281f2fff274006040423d029be84c6002012011120019adce76a26840206b90eb85ffc00019af1df6a26840106b90eb858fc0006ed207fa00d4d422f90005c8ca0715cbffc9d077748018c8cb05cb0222cf165005fa0214cb6b12ccccc973fb00c84014810108f451f2a7020070810108d718fa00d33fc8542047810108f451f2a782106e6f746570748018c8cb05cb025006cf165004fa0214cb6a12cb1fcb3fc973fb0002006c810108d718fa00d33f305224810108f459f2a782106473747270748018c8cb05cb025005cf165003fa0213cb6acb1f12cb3fc973fb00000af400c9ed5494cb980d"
@@ -226,17 +283,10 @@ mod tests {
         .unwrap()
     });
 
-    pub static TEST_SHARD_ACCOUNT: LazyLock<ShardAccount> = LazyLock::new(|| {
-        ShardAccount::from_boc_hex(
-            "b5ee9c720102170100036600015094fb2314023373e7b36b05b69e31508eba9ba24a60e994060fee1ca55302f8c2000030a4972bcd4301026fc0092eb9106ca20295132ce6170ece2338ba10342134a3ca0d9e499f21c9b4897e422c858e433ce5b6500000c2925caf351106c29d2a534002030114ff00f4a413f4bcf2c80b0400510000001129a9a317cbf377c9b73604c70bf73488ddceba14f763baef2ac70f68d1d6032a120149f4400201200506020148070804f8f28308d71820d31fd31fd31f02f823bbf264ed44d0d31fd31fd3fff404d15143baf2a15151baf2a205f901541064f910f2a3f80024a4c8cb1f5240cb1f5230cbff5210f400c9ed54f80f01d30721c0009f6c519320d74a96d307d402fb00e830e021c001e30021c002e30001c0039130e30d03a4c8cb1f12cb1fcbff090a0b0c02e6d001d0d3032171b0925f04e022d749c120925f04e002d31f218210706c7567bd22821064737472bdb0925f05e003fa403020fa4401c8ca07cbffc9d0ed44d0810140d721f404305c810108f40a6fa131b3925f07e005d33fc8258210706c7567ba923830e30d03821064737472ba925f06e30d0d0e0201200f10006ed207fa00d4d422f90005c8ca0715cbffc9d077748018c8cb05cb0222cf165005fa0214cb6b12ccccc973fb00c84014810108f451f2a7020070810108d718fa00d33fc8542047810108f451f2a782106e6f746570748018c8cb05cb025006cf165004fa0214cb6a12cb1fcb3fc973fb0002006c810108d718fa00d33f305224810108f459f2a782106473747270748018c8cb05cb025005cf165003fa0213cb6acb1f12cb3fc973fb00000af400c9ed54007801fa00f40430f8276f2230500aa121bef2e0508210706c7567831eb17080185004cb0526cf1658fa0219f400cb6917cb1f5260cb3f20c98040fb0006008a5004810108f45930ed44d0810140d720c801cf16f400c9ed540172b08e23821064737472831eb17080185005cb055003cf1623fa0213cb6acb1fcb3fc98040fb00925f03e202012011120059bd242b6f6a2684080a06b90fa0218470d4080847a4937d29910ce6903e9ff9837812801b7810148987159f318402015813140011b8c97ed44d0d70b1f8003db29dfb513420405035c87d010c00b23281f2fff274006040423d029be84c6002012015160019adce76a26840206b90eb85ffc00019af1df6a26840106b90eb858fc0"
-        ).unwrap()
-    });
+    pub static TEST_SHARD_ACCOUNT: LazyLock<ShardAccount> =
+        LazyLock::new(|| ShardAccount::from_boc_hex(SHARD_ACCOUNT_BOC_HEX).unwrap());
 
-    pub(crate) static TEST_MSG_IN_EXT: LazyLock<Msg> = LazyLock::new(|| {
-        Msg::from_boc_hex(
-            "b5ee9c72010204010001560001e1880125d7220d944052a2659cc2e1d9c4671742068426947941b3c933e43936912fc800000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000014d4d18bb3ce5c84000000088001c01016862004975c883aea91de93142ae4dc222d803c74e5f130f37ef0d42fb353897fd0f982068e77800000000000000000000000000010201b20f8a7ea500000000000000005012a05f20080129343398aec31cdbbf7d32d977c27a96d5cd23c38fd4bd47be019abafb9b356b0024bae441b2880a544cb3985c3b388ce2e840d084d28f283679267c8726d225f90814dc9381030099259385618012934339d11465553b2f3e428ae79b0b1e2fd250b80784d4996dd44741736528ca0259f3a0f90024bae441b2880a544cb3985c3b388ce2e840d084d28f283679267c8726d225f910"
-        ).unwrap()
-    });
+    pub(crate) static TEST_MSG_IN_EXT: LazyLock<Msg> = LazyLock::new(|| Msg::from_boc_hex(EXT_IN_MSG_BOC_HEX).unwrap());
 
     #[test]
     fn test_tx_emulator_creation() {
@@ -270,6 +320,86 @@ mod tests {
         Ok(())
     }
 
+    #[test]
+    fn test_tx_emulator_emulate_chain() -> anyhow::Result<()> {
+        sys_tonlib_set_verbosity_level(0);
+        let mut emulator = TXEmulator::new(0, false)?;
+        let mut ord_args = create_test_tx_emul_ord_args(
+            TEST_MSG_IN_EXT.clone(),
+            &TEST_SHARD_ACCOUNT,
+            &BC_CONFIG,
+            TEST_RAND_SEED.clone(),
+            1738323935,
+            53483578000001,
+        )?;
+        ord_args.emul_args.ignore_chksig = true;
+
+        let wallet = TonAddress::from_msg_address(TEST_MSG_IN_EXT.dst())?;
+        let accounts = HashMap::from([(wallet.clone(), TEST_SHARD_ACCOUNT.clone())]);
+
+        // out message destination is unknown, so chain stops after the first tx
+        let txs = emulator.emulate_chain(&TEST_MSG_IN_EXT, &accounts, &ord_args.emul_args, 4)?;
+        assert_eq!(txs.len(), 1);
+        assert_eq!(txs[0].tx_parsed()?, TEST_EXPECTED_TX.clone());
+        assert_eq!(txs[0].shard_account_parsed()?, TEST_EXPECTED_SHARD_ACCOUNT.clone());
+
+        // wallet -> receiver -> wallet: receiver doesn't exist, so bounceable message comes back
+        let out_msg = &TEST_EXPECTED_TX.out_messages()[0];
+        let receiver = TonAddress::from_msg_address(out_msg.dst())?;
+        let mut accounts = accounts;
+        accounts.insert(receiver.clone(), ShardAccount::NON_EXIST);
+        let txs = emulator.emulate_chain(&TEST_MSG_IN_EXT, &accounts, &ord_args.emul_args, 4)?;
+        assert_eq!(txs.len(), 3);
+        assert_eq!(txs[0].tx_parsed()?, TEST_EXPECTED_TX.clone());
+        let receiver_tx = txs[1].tx_parsed()?;
+        assert_eq!(receiver_tx.in_message(), Some(out_msg));
+        assert_eq!(receiver_tx.account_addr, receiver.hash);
+        assert!(receiver_tx.lt > TEST_EXPECTED_TX.lt);
+        let bounce_tx = txs[2].tx_parsed()?;
+        assert_eq!(bounce_tx.account_addr, wallet.hash);
+        assert!(matches!(bounce_tx.in_message().map(|x| x.info()), Some(CommonMsgInfo::Int(info)) if info.bounced));
+
+        // hop limit
+        let txs = emulator.emulate_chain(&TEST_MSG_IN_EXT, &accounts, &ord_args.emul_args, 1)?;
+        assert_eq!(txs.len(), 2);
+        let txs = emulator.emulate_chain(&TEST_MSG_IN_EXT, &accounts, &ord_args.emul_args, 0)?;
+        assert_eq!(txs.len(), 1);
+
+        // initial message destination is unknown
+        let txs = emulator.emulate_chain(&TEST_MSG_IN_EXT, &HashMap::new(), &ord_args.emul_args, 4)?;
+        assert!(txs.is_empty());
+        Ok(())
+    }
+
+    #[test]
+    fn test_tx_emulator_gas_limit() -> anyhow::Result<()> {
+        sys_tonlib_set_verbosity_level(0);
+        let mut emulator = TXEmulator::new(0, false)?;
+        let mut ord_args = create_test_tx_emul_ord_args(
+            TEST_MSG_IN_EXT.clone(),
+            &TEST_SHARD_ACCOUNT,
+            &BC_CONFIG,
+            TEST_RAND_SEED.clone(),
+            1738323935,
+            53483578000001,
+        )?;
+        ord_args.emul_args.ignore_chksig = true;
+        ord_args.emul_args.gas_limit = Some(100);
+        ord_args.emul_args.debug_enabled = true;
+
+        // wallet runs out of gas before accept_message()
+        let response = emulator.emulate_ord(&ord_args)?;
+        assert!(!response.success);
+        assert_eq!(response.vm_exit_code, Some(VM_CODE_OUT_OF_GAS));
+        assert_err!(response.into_success());
+
+        // config is restored for the next emulation
+        ord_args.emul_args.gas_limit = None;
+        let response = assert_ok!(emulator.emulate_ord(&ord_args)?.into_success());
+        assert_eq!(response.tx_parsed()?, TEST_EXPECTED_TX.clone());
+        Ok(())
+    }
+
     #[test]
     fn test_tx_emulator_no_libs() -> anyhow::Result<()> {
         // no vm_code in result, remove should_panic when it will be fixed
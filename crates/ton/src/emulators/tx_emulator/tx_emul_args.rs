@@ -37,6 +37,12 @@ pub struct TXEmulArgs {
     pub c7_prev_blocks_info_boc: Option<Arc<Vec<u8>>>,
     #[serde(with = "serde_opt_arc_vec_u8_base64")]
     pub libs_boc: Option<Arc<Vec<u8>>>,
+    // caps gas_limit in config params 20 & 21 (tx emulator has no direct setting for it)
+    #[serde(default)]
+    pub gas_limit: Option<u64>,
+    // enables TVM debug output (DUMP, STRDUMP etc) in vm_log
+    #[serde(default)]
+    pub debug_enabled: bool,
 }
 
 impl Display for TXEmulArgs {
@@ -54,8 +60,8 @@ impl Display for TXEmulArgs {
         };
 
         f.write_fmt(format_args!(
-            "shard_account_boc: {}, bc_config: {}, rand_seed: {}, utime: {}, lt: {}, ignore_chksig: {}, prev_blocks_boc: {}, libs_boc: {}",
-            shard_acc_str, self.bc_config.to_string_lossy(), self.rand_seed, self.utime, self.lt, self.ignore_chksig, prev_blocks_str, libs_str
+            "shard_account_boc: {}, bc_config: {}, rand_seed: {}, utime: {}, lt: {}, ignore_chksig: {}, prev_blocks_boc: {}, libs_boc: {}, gas_limit: {:?}, debug_enabled: {}",
+            shard_acc_str, self.bc_config.to_string_lossy(), self.rand_seed, self.utime, self.lt, self.ignore_chksig, prev_blocks_str, libs_str, self.gas_limit, self.debug_enabled
         ))
     }
 }
@@ -140,6 +146,8 @@ pub fn create_test_tx_emul_ord_args(
             ignore_chksig: false,
             c7_prev_blocks_info_boc: None,
             libs_boc: None,
+            gas_limit: None,
+            debug_enabled: false,
         },
     })
 }
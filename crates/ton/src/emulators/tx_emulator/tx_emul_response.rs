@@ -17,6 +17,12 @@ pub struct TXEmulationSuccess {
     pub vm_log: String,
     pub actions: Option<String>,
     pub elapsed_time: f64,
+    // taken from compute phase of emulated tx, all are 0 if compute phase was skipped
+    pub exit_code: i32,
+    pub gas_used: u64,
+    pub vm_steps: u32,
+    // lines emitted by TVM debug primitives (requires debug_enabled)
+    pub debug_log: Vec<String>,
 }
 
 #[derive(Deserialize, Debug, Clone)]
@@ -43,6 +49,9 @@ impl TXEmulationResponse {
         Ok(value)
     }
 
+    // lines emitted by TVM debug primitives (requires debug_enabled), available for failed emulations as well
+    pub fn debug_log(&self) -> Vec<String> { extract_debug_log(self.vm_log.as_deref().unwrap_or_default()) }
+
     pub fn into_success(self) -> Result<TXEmulationSuccess, TonError> {
         if !self.success {
             return Err(TonError::EmulatorEmulationError {
@@ -53,6 +62,15 @@ impl TXEmulationResponse {
         let tx_boc_b64 = require_field(self.tx_boc_b64, "tx_boc", &self.raw_response)?;
         let shard_account_boc_b64 = require_field(self.shard_account_boc_b64, "shard_account_boc", &self.raw_response)?;
         let vm_log = self.vm_log.unwrap_or_default();
+        let debug_log = extract_debug_log(&vm_log);
+        let tx = Tx::from_boc_base64(&tx_boc_b64)?;
+        let (exit_code, gas_used, vm_steps) = match tx.descr.compute_phase() {
+            Some(phase) => {
+                let vm_info = &phase.compute_phase_vm_info;
+                (vm_info.exit_code, *vm_info.gas_used, vm_info.vm_steps)
+            }
+            None => (0, 0, 0),
+        };
         Ok(TXEmulationSuccess {
             success: self.success,
             tx_boc_b64,
@@ -60,6 +78,10 @@ impl TXEmulationResponse {
             vm_log,
             actions: self.actions,
             elapsed_time: self.elapsed_time,
+            exit_code,
+            gas_used,
+            vm_steps,
+            debug_log,
         })
     }
 }
@@ -69,4 +91,62 @@ impl TXEmulationSuccess {
         Ok(ShardAccount::from_boc_base64(&self.shard_account_boc_b64)?)
     }
     pub fn tx_parsed(&self) -> Result<Tx, TonCoreError> { Tx::from_boc_base64(&self.tx_boc_b64) }
+    pub fn exit_code(&self) -> i32 { self.exit_code }
+    pub fn gas_used(&self) -> u64 { self.gas_used }
+    pub fn vm_steps(&self) -> u32 { self.vm_steps }
+    // TVM exit code 0 or 1 (alternative success); other codes mean contract code failed, while tx is still committed
+    pub fn exit_success(&self) -> bool { self.exit_code == 0 || self.exit_code == 1 }
+}
+
+fn extract_debug_log(vm_log: &str) -> Vec<String> {
+    const DEBUG_PREFIX: &str = "#DEBUG#";
+    vm_log.lines().filter(|line| line.contains(DEBUG_PREFIX)).map(str::to_string).collect()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::block_tlb::TxDescr;
+    use crate::test_fixtures::EXPECTED_TX_BOC_HEX;
+    use std::ops::DerefMut;
+    use ton_core::cell::TonCell;
+
+    fn make_response_json(tx: &Tx) -> anyhow::Result<String> {
+        let json = serde_json::json!({
+            "success": true,
+            "transaction": tx.to_boc_base64()?,
+            "shard_account": TonCell::empty().to_boc_base64()?,
+            "vm_log": "execute SETCP 0\n#DEBUG#: s0 = 42\nexecute ACCEPT",
+            "actions": null,
+            "elapsed_time": 0.1,
+        });
+        Ok(json.to_string())
+    }
+
+    #[test]
+    fn test_tx_emul_response_compute_phase_info() -> anyhow::Result<()> {
+        let tx = Tx::from_boc_hex(EXPECTED_TX_BOC_HEX)?;
+        let vm_info = tx.descr.compute_phase().unwrap().compute_phase_vm_info.clone();
+
+        let success = TXEmulationResponse::from_json(make_response_json(&tx)?)?.into_success()?;
+        assert_eq!(success.exit_code(), 0);
+        assert!(success.exit_success());
+        assert_eq!(success.gas_used(), *vm_info.gas_used);
+        assert_eq!(success.vm_steps(), vm_info.vm_steps);
+        assert_eq!(success.debug_log, ["#DEBUG#: s0 = 42"]);
+
+        // tx is committed, but compute phase failed
+        let mut failed_tx = tx.clone();
+        let TxDescr::Ord(descr) = failed_tx.descr.deref_mut() else {
+            panic!("expected ord tx");
+        };
+        let compute_phase = descr.compute_phase.as_vm_mut().unwrap();
+        compute_phase.success = false;
+        compute_phase.compute_phase_vm_info.exit_code = 37;
+        let success = TXEmulationResponse::from_json(make_response_json(&failed_tx)?)?.into_success()?;
+        assert_eq!(success.exit_code(), 37);
+        assert!(!success.exit_success());
+        assert_eq!(success.gas_used(), *vm_info.gas_used);
+        Ok(())
+    }
 }
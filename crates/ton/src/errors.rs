@@ -1,15 +1,20 @@
+#[cfg(feature = "network")]
 use crate::tep::metadata::MetadataContent;
 use hmac::digest::crypto_common;
+#[cfg(feature = "network")]
 use reqwest::StatusCode;
 use std::io;
 use std::sync::Arc;
 use std::time::Duration;
 use thiserror::Error;
+#[cfg(feature = "network")]
 use tokio::time::error::Elapsed;
 use ton_core::cell::TonHash;
 use ton_core::errors::TonCoreError;
 use ton_core::types::{TonAddress, TxLTHash};
+#[cfg(feature = "network")]
 use ton_liteapi::tl::request::Request;
+#[cfg(feature = "network")]
 use ton_liteapi::types::LiteError;
 
 #[macro_export]
@@ -36,14 +41,17 @@ pub enum TonError {
     NetRequestTimeout { msg: String, timeout: Duration },
 
     // LiteClient
+    #[cfg(feature = "network")]
     #[error("LiteClientErrorResponse: {0:?}")]
     LiteClientErrorResponse(ton_liteapi::tl::response::Error),
     #[error("LiteClientWrongResponse: expected {0}, got {1}")]
     LiteClientWrongResponse(String, String),
+    #[cfg(feature = "network")]
     #[error("LiteClientLiteError: {0}")]
     LiteClientLiteError(#[from] LiteError),
     #[error("LiteClientConnTimeout: {0:?}")]
     LiteClientConnTimeout(Duration),
+    #[cfg(feature = "network")]
     #[error("LiteClientReqTimeout: {0:?}")]
     LiteClientReqTimeout(Box<(Request, Duration)>),
     #[error("EverscaleError: {0:?}")]
@@ -93,8 +101,16 @@ pub enum TonError {
     TVMStackWrongType(String, String),
     #[error("TVMStackError: stack is empty")]
     TVMStackEmpty,
-    #[error("TVMStackError: stack is not empty, but should be")]
-    TVMStackNotEmpty,
+    #[error("TVMStackError: stack is not empty, but should be. {} values left: [{}]", .0.len(), .0.join(", "))]
+    TVMStackNotEmpty(Vec<&'static str>), // type names of leftover values, bottom to top
+    #[error("TVMTupleError: index {index} is out of range, tuple len: {len}")]
+    TVMTupleIndexOutOfRange { index: usize, len: usize },
+    #[error("TVMTupleError: wrong type at index {index}. expected: {expected}, got: {actual}")]
+    TVMTupleWrongType {
+        index: usize,
+        expected: &'static str,
+        actual: &'static str,
+    },
 
     // Mnemonic
     #[error("MnemonicWordsCount: expected 24 words, got {0}")]
@@ -119,6 +135,7 @@ pub enum TonError {
     #[error("CustomError: {0}")]
     Custom(String),
 
+    #[cfg(feature = "network")]
     #[error("MetaLoaderError: {0}")]
     MetaLoaderError(#[from] MetaLoaderError),
 
@@ -132,8 +149,10 @@ pub enum TonError {
     UTF8Error(#[from] std::str::Utf8Error),
     #[error("{0}")]
     FromHexError(#[from] hex::FromHexError),
+    #[cfg(feature = "network")]
     #[error("{0}")]
     ElapsedError(#[from] Elapsed),
+    #[cfg(feature = "network")]
     #[error("{0}")]
     AdnlError(#[from] adnl::AdnlError),
 
@@ -143,10 +162,12 @@ pub enum TonError {
     FromUtf8(#[from] std::string::FromUtf8Error),
     #[error("{0}")]
     SerdeJson(#[from] serde_json::Error),
+    #[cfg(feature = "network")]
     #[error("Transport error ({0})")]
     TransportError(#[from] reqwest::Error),
 }
 
+#[cfg(feature = "network")]
 #[derive(Debug, Error)]
 pub enum MetaLoaderError {
     #[error("Unsupported content layout (Metadata content: {0:?})")]
@@ -163,10 +184,12 @@ pub enum MetaLoaderError {
     },
 }
 
+#[cfg(feature = "network")]
 impl From<everscale_types::error::Error> for TonError {
     fn from(err: everscale_types::error::Error) -> Self { TonError::EverscaleError(err.to_string()) }
 }
 
+#[cfg(feature = "network")]
 impl From<everscale_types::boc::de::Error> for TonError {
     fn from(err: everscale_types::boc::de::Error) -> Self { TonError::EverscaleError(err.to_string()) }
 }
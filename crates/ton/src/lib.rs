@@ -1,12 +1,19 @@
 pub use ton_core; // re-export
 pub mod block_tlb;
+pub mod contract_kind;
 pub mod errors;
 pub mod libs_dict;
+#[cfg(feature = "network")]
 pub mod lite_client;
 pub mod net_config;
+pub mod proof;
 pub mod tep;
+#[cfg(test)]
+pub(crate) mod test_fixtures;
 pub mod tlb_adapters;
 pub mod ton_wallet;
+#[cfg(feature = "network")]
+pub mod util;
 
 #[cfg(feature = "tonlibjson")]
 pub mod contracts;
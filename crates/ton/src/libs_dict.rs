@@ -22,6 +22,16 @@ impl LibsDict {
         }
         Ok(Self { data })
     }
+
+    // keys are lib hashes, so overlapping entries are the same libs
+    pub fn merge(&mut self, other: LibsDict) { self.data.extend(other.data); }
+
+    pub fn contains(&self, id: &TonHash) -> bool { self.data.contains_key(id) }
+
+    // returns requested ids which are absent in dict, keeping the requested order
+    pub fn missing(&self, requested: &[TonHash]) -> Vec<TonHash> {
+        requested.iter().filter(|id| !self.contains(id)).cloned().collect()
+    }
 }
 
 impl From<HashMap<TonHash, TonCell>> for LibsDict {
@@ -65,4 +75,32 @@ mod tests {
         assert_eq!(lib_cell_parsed.hash()?, &lib_id);
         Ok(())
     }
+
+    fn make_lib(val: u32) -> anyhow::Result<TonCell> {
+        let mut builder = TonCell::builder();
+        builder.write_num(&val, 32)?;
+        Ok(builder.build()?)
+    }
+
+    #[test]
+    fn test_libs_dict_merge_missing() -> anyhow::Result<()> {
+        let (lib1, lib2, lib3) = (make_lib(1)?, make_lib(2)?, make_lib(3)?);
+        let (id1, id2, id3) = (lib1.hash()?.clone(), lib2.hash()?.clone(), lib3.hash()?.clone());
+
+        let mut libs = LibsDict::new([lib1.clone(), lib2.clone()])?;
+        assert!(libs.contains(&id1));
+        assert!(!libs.contains(&id3));
+        assert_eq!(libs.missing(&[id3.clone(), id1.clone(), id2.clone()]), vec![id3.clone()]);
+
+        // overlapping key (id2) must not duplicate entries
+        libs.merge(LibsDict::new([lib2.clone(), lib3.clone()])?);
+        assert_eq!(libs.len(), 3);
+        assert!(libs.missing(&[id1.clone(), id2.clone(), id3.clone()]).is_empty());
+        assert_eq!(libs, LibsDict::new([lib1, lib2, lib3])?);
+
+        let unknown = TonHash::from([7u8; 32]);
+        assert_eq!(libs.missing(&[unknown.clone(), id1, unknown.clone()]), vec![unknown.clone(), unknown]);
+        assert!(LibsDict::default().missing(&[]).is_empty());
+        Ok(())
+    }
 }
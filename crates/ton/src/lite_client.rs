@@ -1,3 +1,5 @@
+#[cfg(feature = "blocking")]
+mod blocking;
 mod builder;
 mod connection;
 mod lite_types;
@@ -10,34 +12,40 @@ pub use builder::*;
 pub use lite_types::*;
 pub use req_params::*;
 
-use crate::block_tlb::{Block, BlockIdExt, MaybeAccount};
+use crate::block_tlb::{Block, BlockIdExt, MaybeAccount, TVMStack, Tx};
 use crate::errors::{TonError, TonResult};
 use crate::libs_dict::LibsDict;
 use crate::lite_client::connection::Connection;
 use crate::lite_client::metrics::{LiteClientMetrics, LiteClientMetricsSnapshot, MetricGuard};
+use crate::util::{self, RetryStrategy};
 use crate::{bail_ton, unwrap_lite_rsp};
 use auto_pool::pool::AutoPool;
 use everscale_types::boc::Boc;
 use everscale_types::cell::HashBytes;
-use everscale_types::models::ShardState;
+use everscale_types::merkle::MerkleProof;
+use everscale_types::models::{BlockchainConfigParams, ShardAccounts, ShardState};
+use futures_util::future::try_join_all;
 use futures_util::stream::{FuturesUnordered, StreamExt};
+use std::collections::HashMap;
 use std::sync::Arc;
 use std::sync::atomic::Ordering::Relaxed;
 use std::sync::atomic::{AtomicU32, AtomicU64};
 use std::time::{Duration, Instant};
-use tokio_retry::RetryIf;
-use tokio_retry::strategy::FixedInterval;
-use ton_core::cell::{TonCell, TonHash};
+use ton_core::cell::{BoC, TonCell, TonHash};
 use ton_core::constants::{TON_MASTERCHAIN, TON_SHARD_FULL};
 use ton_core::errors::TonCoreError;
 use ton_core::traits::tlb::TLB;
-use ton_core::types::TonAddress;
+use ton_core::types::{TonAddress, TxLTHash};
 use ton_liteapi::tl::common::{AccountId, Int256};
 use ton_liteapi::tl::request::*;
-use ton_liteapi::tl::response::{BlockState, Response};
+use ton_liteapi::tl::response::{BlockState, ConfigInfo, Response};
 
-const WAIT_MC_SEQNO_MS: u32 = 1000;
 const WAIT_CONNECTION_MS: u64 = 5;
+// liteserver doesn't return more than 16 txs per request
+const MAX_TXS_PER_REQUEST: usize = 16;
+const SEND_MSG_STATUS_OK: u32 = 1;
+// request result stack only, without proofs
+const RUN_SMC_METHOD_MODE_RESULT: u32 = 0b100;
 
 #[derive(Clone)]
 pub struct LiteClient(Arc<Inner>);
@@ -110,34 +118,131 @@ impl LiteClient {
         if mc_seqno == 0 {
             // zero state can't be received from lite-node directly
             // but we can extract it from zero state
-            let block_id = if self.0.mainnet {
-                BlockIdExt::ZERO_BLOCK_ID
-            } else {
-                BlockIdExt::ZERO_BLOCK_ID_TESTNET
-            };
-            let state = self.get_block_state(block_id, params).await?;
-            let cell = Boc::decode(&state.data)?;
-            let shard_state: ShardState = cell.parse()?;
-            let ShardState::Unsplit(unsplit) = shard_state else {
-                bail_ton!("zero state must be unsplit")
-            };
-            let Some((_, account)) = unsplit.load_accounts()?.get(HashBytes(*address.hash.as_slice_sized()))? else {
-                bail_ton!("Account with address {} not found in zero block", address)
-            };
-            let maybe_account = MaybeAccount::from_boc(Boc::encode(account.account.inner()))?;
-            return Ok(maybe_account);
+            let zero_state_accounts = self.load_zero_state_accounts(params).await?;
+            return zero_state_account(&zero_state_accounts, address);
         }
 
-        let req = Request::GetAccountState(GetAccountState {
-            id: self.lookup_mc_block(mc_seqno).await?.into(),
-            account: AccountId {
-                workchain: address.workchain,
-                id: Int256(*address.hash.as_slice_sized()),
-            },
+        let block_id = self.lookup_mc_block(mc_seqno).await?;
+        self.get_account_state_impl(address, &block_id, params).await
+    }
+
+    // resolves mc block once and requests all accounts concurrently; output order matches input order
+    pub async fn get_account_states(
+        &self,
+        addresses: &[TonAddress],
+        mc_seqno: u32,
+        params: Option<LiteReqParams>,
+    ) -> TonResult<Vec<MaybeAccount>> {
+        if mc_seqno == 0 {
+            let zero_state_accounts = self.load_zero_state_accounts(params).await?;
+            return addresses.iter().map(|address| zero_state_account(&zero_state_accounts, address)).collect();
+        }
+        let block_id = self.lookup_mc_block(mc_seqno).await?;
+        let futures = addresses.iter().map(|address| self.get_account_state_impl(address, &block_id, params));
+        try_join_all(futures).await
+    }
+
+    // returns up to `count` txs starting from `from` (inclusive) and going back in history
+    pub async fn get_transactions(
+        &self,
+        address: &TonAddress,
+        from: TxLTHash,
+        count: u8,
+        params: Option<LiteReqParams>,
+    ) -> TonResult<Vec<Tx>> {
+        let mut txs = Vec::with_capacity(count as usize);
+        let mut next_id = from;
+        while txs.len() < count as usize && next_id.lt != 0 {
+            let req_count = (count as usize - txs.len()).min(MAX_TXS_PER_REQUEST) as u32;
+            let req = Request::GetTransactions(GetTransactions {
+                count: req_count,
+                account: AccountId {
+                    workchain: address.workchain,
+                    id: Int256(*address.hash.as_slice_sized()),
+                },
+                lt: next_id.lt as u64,
+                hash: Int256(*next_id.hash.as_slice_sized()),
+            });
+            let rsp = self.exec(req, None, params).await?;
+            let tx_list = unwrap_lite_rsp!(rsp, TransactionList)?;
+            let boc = BoC::from_bytes(tx_list.transactions)?;
+            if boc.roots().is_empty() {
+                break;
+            }
+            for root in boc.roots() {
+                let tx = Tx::from_cell(root)?;
+                next_id = TxLTHash::new(tx.prev_tx_lt as i64, tx.prev_tx_hash.clone());
+                txs.push(tx);
+            }
+        }
+        Ok(txs)
+    }
+
+    // returns blockchain config (ConfigParams dict root) from the specified mc block, or the latest one if None
+    pub async fn get_config_boc_all(&self, mc_seqno: Option<u32>, params: Option<LiteReqParams>) -> TonResult<Vec<u8>> {
+        let block_id = match mc_seqno {
+            Some(seqno) => self.lookup_mc_block(seqno).await?,
+            None => self.get_mc_info().await?.last,
+        };
+        let req = Request::GetConfigAll(GetConfigAll {
+            mode: (),
+            id: block_id.into(),
+            with_state_root: None,
+            with_libraries: None,
+            with_state_extra_root: None,
+            with_shard_hashes: None,
+            with_validator_set: None,
+            with_special_smc: None,
+            with_accounts_root: None,
+            with_prev_blocks: None,
+            with_workchain_info: None,
+            with_capabilities: None,
+            extract_from_key_block: None,
         });
         let rsp = self.exec(req, None, params).await?;
-        let account_state_rsp = unwrap_lite_rsp!(rsp, AccountState)?;
-        Ok(MaybeAccount::from_boc(account_state_rsp.state)?)
+        let config_info = unwrap_lite_rsp!(rsp, ConfigInfo)?;
+        let config_params = parse_config_proof(&config_info)?;
+        let Some(config_root) = config_params.as_dict().root() else {
+            bail_ton!("empty config in block {}", config_info.id.seqno)
+        };
+        Ok(Boc::encode(config_root))
+    }
+
+    // returns raw cells of the requested config params; keys missing in config are omitted
+    pub async fn get_config_params(
+        &self,
+        mc_seqno: u32,
+        keys: &[i32],
+        params: Option<LiteReqParams>,
+    ) -> TonResult<HashMap<i32, TonCell>> {
+        let block_id = self.lookup_mc_block(mc_seqno).await?;
+        let req = Request::GetConfigParams(GetConfigParams {
+            mode: (),
+            id: block_id.into(),
+            param_list: keys.to_vec(),
+            with_state_root: None,
+            with_libraries: None,
+            with_state_extra_root: None,
+            with_shard_hashes: None,
+            with_validator_set: None,
+            with_special_smc: None,
+            with_accounts_root: None,
+            with_prev_blocks: None,
+            with_workchain_info: None,
+            with_capabilities: None,
+            extract_from_key_block: None,
+        });
+        let rsp = self.exec(req, None, params).await?;
+        let config_info = unwrap_lite_rsp!(rsp, ConfigInfo)?;
+        let config_params = parse_config_proof(&config_info)?;
+
+        let mut result = HashMap::with_capacity(keys.len());
+        for key in keys {
+            if let Some(param) = config_params.as_dict().get(*key as u32)? {
+                result.insert(*key, TonCell::from_boc(Boc::encode(param))?);
+            }
+        }
+        Ok(result)
     }
 
     pub async fn get_libs(&self, lib_ids: &[TonHash], params: Option<LiteReqParams>) -> TonResult<LibsDict> {
@@ -151,6 +256,38 @@ impl LiteClient {
         Ok(status.status)
     }
 
+    pub async fn send_message(&self, boc: &[u8]) -> TonResult<()> {
+        let status = self.send_msg(boc.to_vec(), None).await?;
+        if status != SEND_MSG_STATUS_OK {
+            bail_ton!("SendMessage failed with status {status}");
+        }
+        Ok(())
+    }
+
+    // runs get-method on the latest mc block
+    pub async fn run_smc_method(&self, address: &TonAddress, method_id: u64, stack: &TVMStack) -> TonResult<TVMStack> {
+        let mc_info = self.get_mc_info().await?;
+        let req = Request::RunSmcMethod(RunSmcMethod {
+            mode: RUN_SMC_METHOD_MODE_RESULT,
+            id: mc_info.last.into(),
+            account: AccountId {
+                workchain: address.workchain,
+                id: Int256(*address.hash.as_slice_sized()),
+            },
+            method_id,
+            params: stack.to_boc()?,
+        });
+        let rsp = self.exec(req, None, None).await?;
+        let method_result = unwrap_lite_rsp!(rsp, RunMethodResult)?;
+        if method_result.exit_code != 0 && method_result.exit_code != 1 {
+            bail_ton!("RunSmcMethod {method_id} on {address} failed with exit_code {}", method_result.exit_code);
+        }
+        let Some(result) = method_result.result else {
+            bail_ton!("RunSmcMethod {method_id} on {address} returned no result");
+        };
+        Ok(TVMStack::from_boc(result)?)
+    }
+
     pub async fn exec(
         &self,
         req: Request,
@@ -172,6 +309,39 @@ impl LiteClient {
     }
 
     pub fn metrics(&self) -> LiteClientMetricsSnapshot { self.0.metrics.snapshot() }
+
+    async fn load_zero_state_accounts(&self, params: Option<LiteReqParams>) -> TonResult<ShardAccounts> {
+        let block_id = if self.0.mainnet {
+            BlockIdExt::ZERO_BLOCK_ID
+        } else {
+            BlockIdExt::ZERO_BLOCK_ID_TESTNET
+        };
+        let state = self.get_block_state(block_id, params).await?;
+        let cell = Boc::decode(&state.data)?;
+        let shard_state: ShardState = cell.parse()?;
+        let ShardState::Unsplit(unsplit) = shard_state else {
+            bail_ton!("zero state must be unsplit")
+        };
+        Ok(unsplit.load_accounts()?)
+    }
+
+    async fn get_account_state_impl(
+        &self,
+        address: &TonAddress,
+        block_id: &BlockIdExt,
+        params: Option<LiteReqParams>,
+    ) -> TonResult<MaybeAccount> {
+        let req = Request::GetAccountState(GetAccountState {
+            id: block_id.clone().into(),
+            account: AccountId {
+                workchain: address.workchain,
+                id: Int256(*address.hash.as_slice_sized()),
+            },
+        });
+        let rsp = self.exec(req, None, params).await?;
+        let account_state_rsp = unwrap_lite_rsp!(rsp, AccountState)?;
+        Ok(MaybeAccount::from_boc(account_state_rsp.state)?)
+    }
 }
 
 struct Inner {
@@ -225,24 +395,17 @@ impl Inner {
         wait_seqno: Option<u32>,
         params: Option<LiteReqParams>,
     ) -> Result<Response, TonError> {
-        let wrap_req = WrappedRequest {
-            wait_masterchain_seqno: wait_seqno.map(|seqno| WaitMasterchainSeqno {
-                seqno,
-                timeout_ms: WAIT_MC_SEQNO_MS,
-            }),
-            request: req,
-        };
         let req_params = params.as_ref().unwrap_or(&self.default_req_params);
+        let wrap_req = wrap_request(req, wait_seqno, req_params);
         let req_id = self.global_req_id.fetch_add(1, Relaxed);
         let attempts = AtomicU32::new(0);
-        let fi = FixedInterval::new(req_params.retry_waiting);
-        let strategy = fi.take(req_params.retries_count as usize);
+        let strategy = RetryStrategy::new(req_params.retries_count as usize, req_params.retry_waiting);
 
         let exec_request = || async {
             let is_retry = attempts.fetch_add(1, Relaxed) > 0;
             self.exec_impl(req_id, &wrap_req, req_params.query_timeout, is_retry).await
         };
-        RetryIf::spawn(strategy, exec_request, retry_condition).await
+        util::retry_if(&strategy, exec_request, retry_condition).await
     }
 
     async fn exec_impl(
@@ -294,4 +457,58 @@ impl Inner {
     }
 }
 
+fn zero_state_account(accounts: &ShardAccounts, address: &TonAddress) -> TonResult<MaybeAccount> {
+    let Some((_, account)) = accounts.get(HashBytes(*address.hash.as_slice_sized()))? else {
+        bail_ton!("Account with address {} not found in zero block", address)
+    };
+    Ok(MaybeAccount::from_boc(Boc::encode(account.account.inner()))?)
+}
+
+fn wrap_request(req: Request, wait_seqno: Option<u32>, req_params: &LiteReqParams) -> WrappedRequest {
+    WrappedRequest {
+        wait_masterchain_seqno: wait_seqno.map(|seqno| WaitMasterchainSeqno {
+            seqno,
+            timeout_ms: req_params.wait_mc_seqno_timeout.as_millis() as u32,
+        }),
+        request: req,
+    }
+}
+
+// config_proof is a merkle proof of the mc state with the config (or requested params) kept unpruned
+fn parse_config_proof(config_info: &ConfigInfo) -> TonResult<BlockchainConfigParams> {
+    let proof: MerkleProof = Boc::decode(&config_info.config_proof)?.parse()?;
+    let ShardState::Unsplit(state) = proof.cell.virtualize().parse()? else {
+        bail_ton!("mc state must be unsplit")
+    };
+    let Some(mc_state_extra) = state.load_custom()? else {
+        bail_ton!("mc state extra not found in config proof for block {}", config_info.id.seqno)
+    };
+    Ok(mc_state_extra.config.params)
+}
+
+// lite-server may respond with an error for a while (e.g. block is not applied yet), so all but wrong responses are retried
 fn retry_condition(error: &TonError) -> bool { !matches!(error, TonError::LiteClientWrongResponse(..)) }
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_wrap_request_wait_mc_seqno_timeout() -> anyhow::Result<()> {
+        let req_params = LiteReqParams {
+            wait_mc_seqno_timeout: Duration::from_millis(12345),
+            ..Default::default()
+        };
+        let wrapped = wrap_request(Request::GetMasterchainInfo, Some(100), &req_params);
+        let wait = wrapped.wait_masterchain_seqno.unwrap();
+        assert_eq!(wait.seqno, 100);
+        assert_eq!(wait.timeout_ms, 12345);
+
+        let wrapped = wrap_request(Request::GetMasterchainInfo, None, &req_params);
+        assert!(wrapped.wait_masterchain_seqno.is_none());
+
+        let default_wrapped = wrap_request(Request::GetMasterchainInfo, Some(1), &LiteReqParams::default());
+        assert_eq!(default_wrapped.wait_masterchain_seqno.unwrap().timeout_ms, 5000);
+        Ok(())
+    }
+}
@@ -0,0 +1,76 @@
+use crate::block_tlb::{BlockIdExt, MaybeAccount, TVMStack};
+use crate::errors::{TonError, TonResult};
+use crate::libs_dict::LibsDict;
+use crate::lite_client::{BlockData, LiteClient, LiteReqParams, MasterchainInfo};
+use std::future::Future;
+use std::sync::LazyLock;
+use tokio::runtime::Runtime;
+use ton_core::cell::TonHash;
+use ton_core::types::TonAddress;
+
+// shared by all clients: connections are bound to the runtime they were opened in
+static BLOCKING_RUNTIME: LazyLock<std::io::Result<Runtime>> =
+    LazyLock::new(|| tokio::runtime::Builder::new_current_thread().enable_all().build());
+
+/// Blocking wrappers for sync code (e.g. CLI tools), driven by internal current-thread runtime
+///
+/// Must not be called from async context: it returns an error instead of blocking the executor
+impl LiteClient {
+    pub fn blocking_get_mc_info(&self) -> TonResult<MasterchainInfo> { block_on(self.get_mc_info()) }
+
+    pub fn blocking_lookup_mc_block(&self, seqno: u32) -> TonResult<BlockIdExt> {
+        block_on(self.lookup_mc_block(seqno))
+    }
+
+    pub fn blocking_get_block(&self, block_id: BlockIdExt, params: Option<LiteReqParams>) -> TonResult<BlockData> {
+        block_on(self.get_block(block_id, params))
+    }
+
+    pub fn blocking_get_account_state(
+        &self,
+        address: &TonAddress,
+        mc_seqno: u32,
+        params: Option<LiteReqParams>,
+    ) -> TonResult<MaybeAccount> {
+        block_on(self.get_account_state(address, mc_seqno, params))
+    }
+
+    pub fn blocking_get_libs(&self, lib_ids: &[TonHash], params: Option<LiteReqParams>) -> TonResult<LibsDict> {
+        block_on(self.get_libs(lib_ids, params))
+    }
+
+    pub fn blocking_run_smc_method(
+        &self,
+        address: &TonAddress,
+        method_id: u64,
+        stack: &TVMStack,
+    ) -> TonResult<TVMStack> {
+        block_on(self.run_smc_method(address, method_id, stack))
+    }
+
+    pub fn blocking_send_message(&self, boc: &[u8]) -> TonResult<()> { block_on(self.send_message(boc)) }
+}
+
+fn block_on<T, F: Future<Output = TonResult<T>>>(future: F) -> TonResult<T> {
+    if tokio::runtime::Handle::try_current().is_ok() {
+        return Err(TonError::system("LiteClient blocking API can't be used from async context"));
+    }
+    match BLOCKING_RUNTIME.as_ref() {
+        Ok(runtime) => runtime.block_on(future),
+        Err(err) => Err(TonError::system(format!("Failed to create blocking runtime: {err}"))),
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use tokio_test::assert_err;
+
+    #[test]
+    fn test_lite_client_blocking_in_async_context() -> anyhow::Result<()> {
+        let lite_client = LiteClient::builder()?.build()?;
+        let runtime = tokio::runtime::Builder::new_current_thread().build()?;
+        runtime.block_on(async { assert_err!(lite_client.blocking_get_mc_info()) });
+        Ok(())
+    }
+}
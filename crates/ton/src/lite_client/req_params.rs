@@ -1,10 +1,14 @@
 use std::time::Duration;
 
+const DEFAULT_WAIT_MC_SEQNO_MS: u64 = 5000;
+
 #[derive(Debug, Clone, Copy)]
 pub struct LiteReqParams {
     pub retries_count: u32,
     pub retry_waiting: Duration,
     pub query_timeout: Duration,
+    // how long lite-node should wait for masterchain to reach requested seqno
+    pub wait_mc_seqno_timeout: Duration,
 }
 
 impl LiteReqParams {
@@ -13,6 +17,7 @@ impl LiteReqParams {
             retries_count: retries,
             retry_waiting: Duration::from_millis(retry_waiting),
             query_timeout: Duration::from_millis(query_timeout),
+            wait_mc_seqno_timeout: Duration::from_millis(DEFAULT_WAIT_MC_SEQNO_MS),
         }
     }
 }
@@ -4,6 +4,9 @@ use crate::errors::{TonError, TonResult};
 use serde::{Deserialize, Serialize};
 use serde_json::Value;
 use std::fs::exists;
+use std::path::Path;
+use std::str::FromStr;
+use ton_core::cell::TonHash;
 
 pub const TON_NET_CONF_MAINNET_PUBLIC: &str =
     include_str!(concat!(env!("CARGO_MANIFEST_DIR"), "/resources/net_config/mainnet_public.json"));
@@ -50,6 +53,67 @@ impl TonNetConfig {
         TonNetConfig::new(&std::fs::read_to_string(path).map_err(TonError::system)?)
     }
 
+    /// Reads config from file and validates it (see [`TonNetConfig::validate`])
+    pub fn from_file<P: AsRef<Path>>(path: P) -> TonResult<Self> {
+        let path = path.as_ref();
+        let json = std::fs::read_to_string(path)
+            .map_err(|err| TonError::system(format!("failed to read net config {}: {err}", path.display())))?;
+        let config = TonNetConfig::new(&json)?;
+        config.validate()?;
+        Ok(config)
+    }
+
+    /// Downloads config (e.g. https://ton.org/global.config.json) and validates it
+    #[cfg(feature = "network")]
+    pub async fn from_url(url: &str) -> TonResult<Self> {
+        let json = reqwest::get(url).await?.error_for_status()?.text().await?;
+        let config = TonNetConfig::new(&json)?;
+        config.validate()?;
+        Ok(config)
+    }
+
+    /// Checks that config has at least one liteserver and a well-formed `validator.init_block`
+    pub fn validate(&self) -> TonResult<()> {
+        if self.lite_endpoints.is_empty() {
+            bail_ton!("invalid net config: \"liteservers\" is empty");
+        }
+        let init_block = &self.validator.init_block;
+        if !init_block.is_object() {
+            bail_ton!("invalid net config: \"validator.init_block\" must be an object, got: {init_block}");
+        }
+        for field in ["workchain", "shard"] {
+            if init_block[field].as_i64().is_none() {
+                bail_ton!("invalid net config: \"validator.init_block.{field}\" is missing or not an integer");
+            }
+        }
+        if init_block["seqno"].as_u64().is_none() {
+            bail_ton!("invalid net config: \"validator.init_block.seqno\" is missing or not an unsigned integer");
+        }
+        for field in ["root_hash", "file_hash"] {
+            match init_block[field].as_str() {
+                Some(hash) if TonHash::from_str(hash).is_ok() => {}
+                _ => bail_ton!("invalid net config: \"validator.init_block.{field}\" is missing or not a valid hash"),
+            }
+        }
+        Ok(())
+    }
+
+    /// Keeps only endpoints matching `predicate`
+    pub fn filter_endpoints(&mut self, predicate: impl Fn(&LiteEndpoint) -> bool) {
+        self.lite_endpoints.retain(|endpoint| predicate(endpoint));
+    }
+
+    /// Replaces all endpoints with the single one: `ip` is in the same i32 form as in json config,
+    /// `key` is base64-encoded ed25519 public key
+    pub fn with_single_endpoint(mut self, ip: i32, port: u16, key: impl Into<String>) -> Self {
+        let id = LiteID {
+            config_type: Value::String("pub.ed25519".to_string()),
+            key: key.into(),
+        };
+        self.lite_endpoints = vec![LiteEndpoint { ip, port, id }];
+        self
+    }
+
     pub fn from_env_path(env_var: &str) -> TonResult<Self> {
         if let Ok(path) = std::env::var(env_var) {
             return TonNetConfig::from_path(&path);
@@ -96,3 +160,73 @@ fn get_default_net_conf(mainnet: bool) -> TonResult<String> {
     }
     Ok(net_conf)
 }
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use tokio_test::{assert_err, assert_ok};
+
+    fn write_tmp_config(name: &str, config: &serde_json::Value) -> anyhow::Result<std::path::PathBuf> {
+        let path = std::env::temp_dir().join(format!("ton_net_config_{name}_{}.json", std::process::id()));
+        std::fs::write(&path, serde_json::to_string(config)?)?;
+        Ok(path)
+    }
+
+    #[test]
+    fn test_net_config_from_file() -> anyhow::Result<()> {
+        for name in ["mainnet_public", "testnet_public"] {
+            let path = format!("{}/resources/net_config/{name}.json", env!("CARGO_MANIFEST_DIR"));
+            let config = assert_ok!(TonNetConfig::from_file(path));
+            assert!(!config.lite_endpoints.is_empty());
+            assert!(config.get_init_block_seqno() > 0);
+        }
+        assert_err!(TonNetConfig::from_file("/non/existing/net_config.json"));
+        Ok(())
+    }
+
+    #[test]
+    fn test_net_config_from_file_invalid() -> anyhow::Result<()> {
+        let valid: serde_json::Value = serde_json::from_str(TON_NET_CONF_MAINNET_PUBLIC)?;
+
+        let mut no_liteservers = valid.clone();
+        no_liteservers["liteservers"] = serde_json::json!([]);
+        let path = write_tmp_config("no_liteservers", &no_liteservers)?;
+        let err = TonNetConfig::from_file(&path).unwrap_err();
+        std::fs::remove_file(&path)?;
+        assert!(err.to_string().contains("liteservers"), "unexpected error: {err}");
+
+        let mut no_root_hash = valid.clone();
+        no_root_hash["validator"]["init_block"].as_object_mut().unwrap().remove("root_hash");
+        let path = write_tmp_config("no_root_hash", &no_root_hash)?;
+        let err = TonNetConfig::from_file(&path).unwrap_err();
+        std::fs::remove_file(&path)?;
+        assert!(err.to_string().contains("validator.init_block.root_hash"), "unexpected error: {err}");
+
+        let mut bad_seqno = valid;
+        bad_seqno["validator"]["init_block"]["seqno"] = serde_json::json!("abc");
+        let err = TonNetConfig::new(&bad_seqno.to_string())?.validate().unwrap_err();
+        assert!(err.to_string().contains("validator.init_block.seqno"), "unexpected error: {err}");
+        Ok(())
+    }
+
+    #[test]
+    fn test_net_config_endpoints_selection() -> anyhow::Result<()> {
+        let mut config = TonNetConfig::new(TON_NET_CONF_MAINNET_PUBLIC)?;
+        assert!(config.lite_endpoints.len() > 1);
+        let first = config.lite_endpoints[0].clone();
+        config.filter_endpoints(|endpoint| endpoint.ip == first.ip && endpoint.port == first.port);
+        assert_eq!(config.lite_endpoints.len(), 1);
+        assert_eq!(config.lite_endpoints[0].id.key, first.id.key);
+
+        let config = TonNetConfig::new(TON_NET_CONF_MAINNET_PUBLIC)?.with_single_endpoint(
+            84478511,
+            19949,
+            "n4VDnSCUuSpjnCyUk9e3QOOd6o0ItSWYbTnW3Wnn8wk=",
+        );
+        assert_eq!(config.lite_endpoints.len(), 1);
+        assert_eq!(config.lite_endpoints[0].port, 19949);
+        assert_eq!(config.lite_endpoints[0].id.config_type, "pub.ed25519");
+        assert_ok!(config.validate());
+        Ok(())
+    }
+}
@@ -0,0 +1,354 @@
+use crate::bail_ton;
+use crate::block_tlb::{CurrencyCollection, MaybeAccount, MerkleUpdate, ShardAccount, ShardIdent};
+use crate::errors::TonResult;
+use ton_core::cell::{BoC, CellParser, CellType, TonCell, TonHash};
+use ton_core::traits::tlb::TLB;
+use ton_core::types::TonAddress;
+
+const BLOCK_TAG: u32 = 0x11ef55aa;
+const SHARD_STATE_TAG: u32 = 0x9023afe2;
+const ACCOUNT_KEY_BITS_LEN: usize = 256;
+
+/// Checks `liteServer.accountState.proof` (2 roots: block proof & shard state proof) offline
+///
+/// Block proof must be rooted to `block_root_hash`, state proof - to the new state hash of the block.
+/// Returns `MaybeAccount::None` if proof shows there is no such account in the state.
+/// Fails if the address is out of the shard the state belongs to.
+pub fn verify_account_proof(
+    block_root_hash: &TonHash,
+    proof_boc: &[u8],
+    address: &TonAddress,
+) -> TonResult<MaybeAccount> {
+    let boc = BoC::from_bytes(proof_boc.to_vec())?;
+    let [block_proof, state_proof] = boc.roots() else {
+        bail_ton!("Account proof must have 2 roots, got {}", boc.roots().len());
+    };
+
+    let block = block_proof.virtual_root()?;
+    if block.hash()? != block_root_hash {
+        bail_ton!("Block proof hash mismatch: expected {block_root_hash}, got {}", block.hash()?);
+    }
+    // block#11ef55aa global_id:int32 info:^BlockInfo value_flow:^ValueFlow state_update:^(MERKLE_UPDATE ShardState) ...
+    let mut block_parser = block.parser();
+    if block_parser.read_num::<u32>(32)? != BLOCK_TAG {
+        bail_ton!("Block proof root is not a block");
+    }
+    let Some(state_update_cell) = block.refs().get(2) else {
+        bail_ton!("Block proof has no state_update");
+    };
+    let state_update = MerkleUpdate::<TonCell>::from_cell(state_update_cell)?;
+
+    let state = state_proof.virtual_root()?;
+    if state.hash()? != &state_update.new_hash {
+        bail_ton!("State proof hash mismatch: expected {}, got {}", state_update.new_hash, state.hash()?);
+    }
+    // shard_state#9023afe2 global_id:int32 shard_id:ShardIdent ... out_msg_queue_info:^OutMsgQueueInfo before_split:(## 1) accounts:^ShardAccounts ...
+    let mut state_parser = state.parser();
+    if state_parser.read_num::<u32>(32)? != SHARD_STATE_TAG {
+        bail_ton!("State proof root is not an unsplit shard state");
+    }
+    let _global_id: i32 = state_parser.read_num(32)?;
+    let shard_ident = ShardIdent::read(&mut state_parser)?;
+    if !shard_ident.contains_addr(&address.to_msg_address_int()) {
+        bail_ton!("State proof is for {shard_ident}, but address {address} is out of this shard");
+    }
+    let Some(accounts) = state.refs().get(1) else {
+        bail_ton!("State proof has no accounts");
+    };
+    ensure_not_pruned(accounts)?;
+
+    // ShardAccounts = HashmapAugE 256 ShardAccount DepthBalanceInfo
+    let mut accounts_parser = accounts.parser();
+    if !accounts_parser.read_bit()? {
+        return Ok(MaybeAccount::default());
+    }
+    let Some(mut leaf_parser) = lookup_aug_dict_leaf(accounts_parser.read_next_ref()?, &address.hash)? else {
+        return Ok(MaybeAccount::default());
+    };
+    // depth_balance$_ split_depth:(#<= 30) balance:CurrencyCollection = DepthBalanceInfo
+    leaf_parser.read_uint_leq(30)?;
+    CurrencyCollection::read(&mut leaf_parser)?;
+    Ok(ShardAccount::read(&mut leaf_parser)?.account.into_inner())
+}
+
+fn ensure_not_pruned(cell: &TonCell) -> TonResult<()> {
+    if cell.cell_type() == CellType::PrunedBranch {
+        bail_ton!("Proof doesn't contain required data: cell {} is pruned", cell.hash()?);
+    }
+    Ok(())
+}
+
+// returns parser pointing to the leaf data (extra, then value), or None if there is no such key
+fn lookup_aug_dict_leaf<'a>(root: &'a TonCell, key: &TonHash) -> TonResult<Option<CellParser<'a>>> {
+    let key_bit = |pos: usize| key.as_slice()[pos / 8] >> (7 - pos % 8) & 1 == 1;
+    let mut node = root;
+    let mut pos = 0;
+    loop {
+        ensure_not_pruned(node)?;
+        let mut parser = node.parser();
+        let bits_left = (ACCOUNT_KEY_BITS_LEN - pos) as u64;
+        // hml_short$0 len:(Unary ~n) s:(n * Bit) | hml_long$10 n:(#<= m) s:(n * Bit) | hml_same$11 v:Bit n:(#<= m)
+        let (label_len, same_bit) = match (parser.read_bit()?, parser.read_bit()?) {
+            (false, mut unary_bit) => {
+                let mut label_len = 0;
+                while unary_bit {
+                    label_len += 1;
+                    unary_bit = parser.read_bit()?;
+                }
+                (label_len, None)
+            }
+            (true, false) => (parser.read_uint_leq(bits_left)?, None),
+            (true, true) => {
+                let bit = parser.read_bit()?;
+                (parser.read_uint_leq(bits_left)?, Some(bit))
+            }
+        };
+        if label_len > bits_left {
+            bail_ton!("Invalid dict label: length {label_len} exceeds {bits_left} bits left");
+        }
+        for _ in 0..label_len {
+            let bit = match same_bit {
+                Some(bit) => bit,
+                None => parser.read_bit()?,
+            };
+            if bit != key_bit(pos) {
+                return Ok(None);
+            }
+            pos += 1;
+        }
+        if pos == ACCOUNT_KEY_BITS_LEN {
+            return Ok(Some(parser));
+        }
+        // ahmn_fork#_ left:^(HashmapAug n X Y) right:^(HashmapAug n X Y) extra:Y
+        let Some(next) = node.refs().get(key_bit(pos) as usize) else {
+            bail_ton!("Invalid dict fork: no ref for bit {pos}");
+        };
+        node = next;
+        pos += 1;
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::block_tlb::ShardPfx;
+    use crate::test_fixtures::SHARD_ACCOUNT_BOC_HEX;
+    use std::ops::Deref;
+    use tokio_test::{assert_err, assert_ok};
+    use ton_core::cell::CellBuilder;
+    use ton_core::cell::LevelMask;
+    use ton_core::constants::TON_SHARD_FULL;
+
+    fn rebuild(cell: &TonCell, refs: Vec<TonCell>) -> anyhow::Result<TonCell> {
+        let mut builder = TonCell::builder_extra(cell.cell_type(), 128);
+        builder.write_bits(cell.parser().read_bits(cell.data_len_bits())?, cell.data_len_bits())?;
+        for cell_ref in refs {
+            builder.write_ref(cell_ref)?;
+        }
+        Ok(builder.build()?)
+    }
+
+    fn pruned(cell: &TonCell) -> anyhow::Result<TonCell> {
+        let mut builder = TonCell::builder_extra(CellType::PrunedBranch, 64);
+        builder.write_num(&1u8, 8)?; // cell type
+        builder.write_num(&1u8, 8)?; // level mask
+        cell.hash()?.write(&mut builder)?;
+        builder.write_num(&cell.depth()?, 16)?;
+        Ok(builder.build()?)
+    }
+
+    fn merkle_proof(proven: TonCell) -> anyhow::Result<TonCell> {
+        let mut builder = TonCell::builder_extra(CellType::MerkleProof, 64);
+        builder.write_num(&3u8, 8)?; // cell type
+        proven.hash_for_level(LevelMask::new(0))?.write(&mut builder)?;
+        builder.write_num(&proven.depth_for_level(LevelMask::new(0))?, 16)?;
+        builder.write_ref(proven)?;
+        Ok(builder.build()?)
+    }
+
+    fn write_depth_balance(builder: &mut CellBuilder) -> anyhow::Result<()> {
+        builder.write_uint_leq(0, 30)?;
+        CurrencyCollection::new(1u32.into()).write(builder)?;
+        Ok(())
+    }
+
+    fn flip_bits(hash: &TonHash, byte_pos: usize, mask: u8) -> TonHash {
+        let mut bytes = *hash.as_slice_sized();
+        bytes[byte_pos] ^= mask;
+        TonHash::from_slice_sized(&bytes)
+    }
+
+    fn make_leaf(key: &TonHash, shard_account: &ShardAccount) -> anyhow::Result<TonCell> {
+        let mut builder = TonCell::builder();
+        // hml_long$10 n:(#<= 255) s:(n * Bit), the first key bit is taken by the root fork
+        builder.write_bits([0b1000_0000], 2)?;
+        builder.write_uint_leq(255, 255)?;
+        for pos in 1..256 {
+            builder.write_bit(key.as_slice()[pos / 8] >> (7 - pos % 8) & 1 == 1)?;
+        }
+        write_depth_balance(&mut builder)?;
+        shard_account.write(&mut builder)?;
+        Ok(builder.build()?)
+    }
+
+    struct TestProof {
+        block_hash: TonHash,
+        address: TonAddress,
+        other_address: TonAddress,
+        block_proof: TonCell,
+        state_proof: TonCell,
+    }
+
+    // real lite-server response requires network, so proof of the same structure is built by hands:
+    // accounts dict has 2 accounts (differ in the first bit), the other one is pruned
+    fn make_test_proof() -> anyhow::Result<TestProof> { make_test_proof_in_shard(|_| TON_SHARD_FULL) }
+
+    fn make_test_proof_in_shard(shard_for: impl Fn(&TonAddress) -> u64) -> anyhow::Result<TestProof> {
+        let shard_account = ShardAccount::from_boc_hex(SHARD_ACCOUNT_BOC_HEX)?;
+        let address = TonAddress::from_msg_address(shard_account.account.as_account().unwrap().addr.clone())?;
+        let other_hash = flip_bits(&address.hash, 0, 0x80);
+        let other_address = TonAddress::new(address.workchain, other_hash.clone());
+
+        let leaf = make_leaf(&address.hash, &shard_account)?;
+        let other_leaf = make_leaf(&other_hash, &ShardAccount::NON_EXIST)?;
+        let our_bit = (address.hash.as_slice()[0] >> 7) as usize;
+        let mut children = vec![other_leaf.clone(), other_leaf.clone()];
+        children[our_bit] = leaf;
+
+        let mut dict_root = TonCell::builder();
+        dict_root.write_bits([0], 2)?; // hml_short$0, empty label
+        dict_root.write_ref(children[0].clone())?;
+        dict_root.write_ref(children[1].clone())?;
+        write_depth_balance(&mut dict_root)?;
+        let dict_root = dict_root.build()?;
+
+        let mut accounts = TonCell::builder();
+        accounts.write_bit(true)?;
+        accounts.write_ref(dict_root.clone())?;
+        write_depth_balance(&mut accounts)?;
+        let accounts = accounts.build()?;
+
+        let mut state = TonCell::builder();
+        state.write_num(&SHARD_STATE_TAG, 32)?;
+        state.write_num(&-239i32, 32)?;
+        ShardIdent::new(address.workchain, shard_for(&address)).write(&mut state)?;
+        state.write_bits([0; 29], 32 * 5 + 64 + 1)?; // seq_no .. min_ref_mc_seqno, before_split
+        state.write_ref(TonCell::empty().clone())?;
+        state.write_ref(accounts.clone())?;
+        state.write_ref(TonCell::empty().clone())?;
+        state.write_bit(false)?; // no custom
+        let state = state.build()?;
+
+        let state_update = MerkleUpdate {
+            old_hash: TonCell::empty().hash()?.clone(),
+            new_hash: state.hash()?.clone(),
+            old_depth: 0,
+            new_depth: state.depth()?,
+            old: pruned(TonCell::empty())?,
+            new: pruned(&state)?,
+        }
+        .to_cell()?;
+
+        let mut block = TonCell::builder();
+        block.write_num(&BLOCK_TAG, 32)?;
+        block.write_num(&-239i32, 32)?;
+        block.write_ref(TonCell::empty().clone())?;
+        block.write_ref(TonCell::empty().clone())?;
+        block.write_ref(state_update.clone())?;
+        block.write_ref(TonCell::empty().clone())?;
+        let block = block.build()?;
+
+        let empty_pruned = pruned(TonCell::empty())?;
+        let block_virtual = rebuild(
+            &block,
+            vec![
+                empty_pruned.clone(),
+                empty_pruned.clone(),
+                state_update,
+                empty_pruned.clone(),
+            ],
+        )?;
+        children[1 - our_bit] = pruned(&other_leaf)?;
+        let accounts_virtual = rebuild(&accounts, vec![rebuild(&dict_root, children)?])?;
+        let state_virtual = rebuild(&state, vec![empty_pruned.clone(), accounts_virtual, empty_pruned])?;
+
+        Ok(TestProof {
+            block_hash: block.hash()?.clone(),
+            address,
+            other_address,
+            block_proof: merkle_proof(block_virtual)?,
+            state_proof: merkle_proof(state_virtual)?,
+        })
+    }
+
+    #[test]
+    fn test_verify_account_proof() -> anyhow::Result<()> {
+        let proof = make_test_proof()?;
+        let proof_boc = BoC::from_roots([proof.block_proof, proof.state_proof]).to_bytes(false)?;
+        let expected = ShardAccount::from_boc_hex(SHARD_ACCOUNT_BOC_HEX)?;
+
+        let account = verify_account_proof(&proof.block_hash, &proof_boc, &proof.address)?;
+        assert_eq!(&account, expected.account.deref());
+
+        // other account is pruned, so proof says nothing about it
+        assert_err!(verify_account_proof(&proof.block_hash, &proof_boc, &proof.other_address));
+
+        // label mismatch: account doesn't exist
+        let absent_address = TonAddress::new(proof.address.workchain, flip_bits(&proof.address.hash, 31, 1));
+        let account = verify_account_proof(&proof.block_hash, &proof_boc, &absent_address)?;
+        assert_eq!(account, MaybeAccount::default());
+        Ok(())
+    }
+
+    #[test]
+    fn test_virtual_root_account() -> anyhow::Result<()> {
+        let shard_account = ShardAccount::from_boc_hex(SHARD_ACCOUNT_BOC_HEX)?;
+        let proof = merkle_proof(shard_account.to_cell()?)?;
+        let child = proof.virtual_root()?;
+        assert_eq!(child.hash()?, &shard_account.cell_hash()?);
+        assert_eq!(ShardAccount::from_cell(&child)?, shard_account);
+        assert_err!(child.virtual_root());
+
+        // state proof: accounts are reachable, the rest of the state is pruned
+        let test_proof = make_test_proof()?;
+        let state = test_proof.state_proof.virtual_root()?;
+        assert_eq!(state.refs()[0].cell_type(), CellType::PrunedBranch);
+        assert_eq!(state.refs()[1].cell_type(), CellType::Ordinary);
+        Ok(())
+    }
+
+    #[test]
+    fn test_verify_account_proof_invalid() -> anyhow::Result<()> {
+        let proof = make_test_proof()?;
+        let proof_boc = BoC::from_roots([proof.block_proof.clone(), proof.state_proof]).to_bytes(false)?;
+
+        assert_err!(verify_account_proof(&TonHash::ZERO, &proof_boc, &proof.address));
+        let wrong_wc_address = TonAddress::new(-1, proof.address.hash.clone());
+        assert_err!(verify_account_proof(&proof.block_hash, &proof_boc, &wrong_wc_address));
+
+        // 1-bit shard prefix: the address must be in the proven shard
+        let shard_of = |address: &TonAddress, flip: u64| {
+            let first_bit = (address.hash.as_slice()[0] >> 7) as u64 ^ flip;
+            ShardPfx {
+                value: first_bit << 63,
+                bits_len: 1,
+            }
+            .to_shard()
+        };
+        let in_shard = make_test_proof_in_shard(|address| shard_of(address, 0))?;
+        let in_shard_boc = BoC::from_roots([in_shard.block_proof, in_shard.state_proof]).to_bytes(false)?;
+        assert_ok!(verify_account_proof(&in_shard.block_hash, &in_shard_boc, &in_shard.address));
+        let out_of_shard = make_test_proof_in_shard(|address| shard_of(address, 1))?;
+        let out_of_shard_boc = BoC::from_roots([out_of_shard.block_proof, out_of_shard.state_proof]).to_bytes(false)?;
+        assert_err!(verify_account_proof(&out_of_shard.block_hash, &out_of_shard_boc, &out_of_shard.address));
+
+        let single_root_boc = BoC::new(proof.block_proof.clone()).to_bytes(false)?;
+        assert_err!(verify_account_proof(&proof.block_hash, &single_root_boc, &proof.address));
+
+        // state proof doesn't match the block
+        let other_state_proof = merkle_proof(TonCell::empty().clone())?;
+        let wrong_state_boc = BoC::from_roots([proof.block_proof, other_state_proof]).to_bytes(false)?;
+        assert_err!(verify_account_proof(&proof.block_hash, &wrong_state_boc, &proof.address));
+        Ok(())
+    }
+}
@@ -1,5 +1,43 @@
+/// tonlib log verbosity levels
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum TonlibVerbosity {
+    Off,
+    Error,
+    Warn,
+    Info,
+    Debug,
+}
+
+impl TonlibVerbosity {
+    pub fn level(&self) -> u32 {
+        match self {
+            TonlibVerbosity::Off => 0, // fatal errors only
+            TonlibVerbosity::Error => 1,
+            TonlibVerbosity::Warn => 2,
+            TonlibVerbosity::Info => 3,
+            TonlibVerbosity::Debug => 4,
+        }
+    }
+}
+
+pub fn set_verbosity(verbosity: TonlibVerbosity) { sys_tonlib_set_verbosity_level(verbosity.level()); }
+
 pub fn sys_tonlib_set_verbosity_level(level: u32) {
     unsafe {
         tonlib_sys::tonlib_client_set_verbosity_level(level);
     }
 }
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_tonlib_verbosity_level() {
+        assert_eq!(TonlibVerbosity::Off.level(), 0);
+        assert_eq!(TonlibVerbosity::Error.level(), 1);
+        assert_eq!(TonlibVerbosity::Warn.level(), 2);
+        assert_eq!(TonlibVerbosity::Info.level(), 3);
+        assert_eq!(TonlibVerbosity::Debug.level(), 4);
+    }
+}
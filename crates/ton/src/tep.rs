@@ -1,8 +1,10 @@
 pub mod excesses_msg;
+pub mod get_method_id;
 pub mod jetton;
 pub mod metadata;
 pub mod mintless_airdrop;
 pub mod nft;
 pub mod sbt;
 pub mod snake_data;
+pub mod text_comment;
 pub mod tvm_result;
@@ -0,0 +1,19 @@
+use crc::Crc;
+
+const CRC_16_XMODEM: Crc<u16> = Crc::<u16>::new(&crc::CRC_16_XMODEM);
+
+/// Get-method id as FunC/Tolk compilers calculate it: `crc16(name) | 0x10000`
+pub fn method_id(name: &str) -> i32 { CRC_16_XMODEM.checksum(name.as_bytes()) as i32 | 0x10000 }
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_method_id() {
+        assert_eq!(method_id("seqno"), 85143);
+        assert_eq!(method_id("get_wallet_data"), 97026);
+        assert_eq!(method_id("get_jetton_data"), 106029);
+        assert_eq!(method_id("get_wallet_address"), 103289);
+    }
+}
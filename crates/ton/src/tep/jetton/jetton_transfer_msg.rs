@@ -3,6 +3,7 @@ use ton_core::cell::TonCell;
 use ton_core::traits::tlb::TLB;
 use ton_core::types::tlb_core::TLBCoins;
 use ton_core::types::tlb_core::{MsgAddress, MsgAddressInt, TLBEitherRef, TLBRef};
+use ton_core::types::{Coins, TonAddress};
 
 ///```raw
 /// transfer#f8a7ea5 query_id:uint64 amount:(VarUInteger 16) destination:MsgAddress
@@ -37,9 +38,32 @@ impl<CustomPayload: TLB, ForwardPayloadT: TLB> JettonTransferMsg<CustomPayload,
     }
 }
 
+impl JettonTransferMsg {
+    /// custom_payload is null, forward_payload is stored inline if it fits, as a ref otherwise
+    pub fn new_transfer(
+        query_id: u64,
+        amount: Coins,
+        dst: &TonAddress,
+        response_dst: &TonAddress,
+        forward_ton_amount: Coins,
+        forward_payload: Option<TonCell>,
+    ) -> Self {
+        JettonTransferMsg {
+            query_id,
+            amount: amount.into(),
+            dst: dst.to_msg_address(),
+            response_dst: response_dst.to_msg_address(),
+            custom_payload: None,
+            forward_ton_amount: forward_ton_amount.into(),
+            forward_payload: TLBEitherRef::new(forward_payload.unwrap_or_else(|| TonCell::empty().clone())),
+        }
+    }
+}
+
 #[cfg(test)]
 mod tests {
     use super::*;
+    use crate::test_fixtures::USDT_TRANSFER_MSG_BOC_HEX;
 
     use std::str::FromStr;
     use ton_core::traits::tlb::TLB;
@@ -48,9 +72,7 @@ mod tests {
 
     #[test]
     fn test_jetton_transfer_msg() -> anyhow::Result<()> {
-        // int_msg from https://tonviewer.com/transaction/18679bed03915803746469e9fe498add0ffecd76ae3056bb9c3777c9f722becd
-        let msg_boc = "b5ee9c720101020100650001b40f8a7ea55ecf57d735066d2460246139ca800800f52547902494daa24c332ecb41067ee9b6bae7b244a68ce0c5007ddc22f4b01f001f5d9cc275e5514e8386836ef59caa82e043c006d404f512ab7ee893e38f5f8d8847868c0101000be8e8e46c0020";
-        let msg = JettonTransferMsg::<TonCell, TonCell>::from_boc_hex(msg_boc)?;
+        let msg = JettonTransferMsg::<TonCell, TonCell>::from_boc_hex(USDT_TRANSFER_MSG_BOC_HEX)?;
 
         let mut pl_builder = TonCell::builder();
         pl_builder.write_bits([232, 232, 228, 108, 0, 0], 42)?;
@@ -75,4 +97,32 @@ mod tests {
         assert_eq!(exp_msg, msg);
         Ok(())
     }
+
+    #[test]
+    fn test_jetton_transfer_msg_new_transfer() -> anyhow::Result<()> {
+        let known_msg = JettonTransferMsg::<TonCell, TonCell>::from_boc_hex(USDT_TRANSFER_MSG_BOC_HEX)?;
+
+        let dst = TonAddress::from_str("0:7a92a3c8124a6d5126199765a0833f74db5d73d92253467062803eee117a580f")?;
+        let response_dst = TonAddress::from_str("0:7d767309d795453a0e1a0dbbd672aa0b810f001b5013d44aadfba24f8e3d7e36")?;
+        let payload = known_msg.forward_payload.value.clone();
+        let msg = JettonTransferMsg::new_transfer(
+            known_msg.query_id,
+            Coins::new(2500000000000),
+            &dst,
+            &response_dst,
+            Coins::new(600000000),
+            Some(payload.clone()),
+        );
+        let parsed = JettonTransferMsg::<TonCell, TonCell>::from_cell(&msg.to_cell()?)?;
+        assert_eq!(parsed, known_msg);
+        // small payload is stored inline
+        assert_eq!(parsed.forward_payload.layout, EitherRefLayout::ToCell);
+
+        let no_payload = JettonTransferMsg::new_transfer(1, Coins::ONE, &dst, &response_dst, Coins::ZERO, None);
+        let parsed = JettonTransferMsg::<TonCell, TonCell>::from_cell(&no_payload.to_cell()?)?;
+        assert_eq!(parsed.forward_payload.value, TonCell::empty().clone());
+        assert_eq!(parsed.custom_payload, None);
+        assert_eq!(parsed.query_id, 1);
+        Ok(())
+    }
 }
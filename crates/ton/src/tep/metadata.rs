@@ -1,8 +1,10 @@
+#[cfg(feature = "network")]
 mod meta_loader;
 mod metadata_content;
 mod metadata_fields;
 mod traits;
 
+#[cfg(feature = "network")]
 pub use meta_loader::*;
 pub use metadata_content::*;
 pub use metadata_fields::*;
@@ -1,11 +1,14 @@
+use crate::bail_ton;
 use crate::block_tlb::{FromTVMStack, TVMStack};
 use crate::errors::TonResult;
+use crate::tep::metadata::MetadataField;
 use crate::tep::snake_data::SnakeData;
 use crate::tlb_adapters::DictKeyAdapterTonHash;
 use crate::tlb_adapters::DictValAdapterTLB;
 use crate::tlb_adapters::TLBHashMapE;
 use std::collections::HashMap;
 use std::fmt::Debug;
+use std::ops::Deref;
 use ton_core::TLB;
 use ton_core::cell::{CellBuilder, CellParser, TonCell, TonHash};
 use ton_core::errors::TonCoreResult;
@@ -39,6 +42,56 @@ pub struct MetadataUnsupported {
     pub cell: TonCell,
 }
 
+// value of on-chain attribute, stored as snake data with 0x00 prefix
+// Raw keeps parsed values which are encoded differently (no prefix, custom chunks), so they are written back as-is
+#[derive(PartialEq, Eq, Debug, Clone)]
+pub enum MetadataValue {
+    String(String),
+    Bytes(Vec<u8>),
+    Raw(SnakeData),
+}
+
+impl MetadataValue {
+    fn to_snake(&self) -> SnakeData {
+        let bytes = match self {
+            MetadataValue::String(value) => value.as_bytes(),
+            MetadataValue::Bytes(value) => value.as_slice(),
+            MetadataValue::Raw(snake) => return snake.clone(),
+        };
+        let mut data = Vec::with_capacity(bytes.len() + 1);
+        data.push(0);
+        data.extend_from_slice(bytes);
+        SnakeData::new(data)
+    }
+
+    fn from_snake(snake: &SnakeData) -> TonResult<Self> {
+        let data = match snake.as_slice() {
+            [0, rest @ ..] => rest,
+            _ => return Ok(MetadataValue::Raw(snake.clone())),
+        };
+        let value = match std::str::from_utf8(data) {
+            Ok(value) => MetadataValue::String(value.to_string()),
+            Err(_) => MetadataValue::Bytes(data.to_vec()),
+        };
+        if value.to_snake().to_cell()? != snake.to_cell()? {
+            return Ok(MetadataValue::Raw(snake.clone()));
+        }
+        Ok(value)
+    }
+}
+
+impl From<&str> for MetadataValue {
+    fn from(value: &str) -> Self { MetadataValue::String(value.to_string()) }
+}
+
+impl From<String> for MetadataValue {
+    fn from(value: String) -> Self { MetadataValue::String(value) }
+}
+
+impl From<Vec<u8>> for MetadataValue {
+    fn from(value: Vec<u8>) -> Self { MetadataValue::Bytes(value) }
+}
+
 impl FromTVMStack for MetadataContent {
     fn from_stack(stack: &mut TVMStack) -> TonResult<Self> { Ok(MetadataContent::from_cell(&stack.pop_cell()?)?) }
 }
@@ -83,6 +136,30 @@ impl TLB for MetadataContent {
 }
 
 impl MetadataContent {
+    // TEP-64 on-chain content: 0x00 prefix + dict sha256(attr_name) -> ^SnakeData
+    pub fn build_onchain(attrs: &[(&str, MetadataValue)]) -> TonResult<TonCell> {
+        let data = attrs
+            .iter()
+            .map(|(name, value)| (MetadataField::new(name).deref().clone(), value.to_snake().into()))
+            .collect();
+        Ok(MetadataContent::Internal(MetadataInternal { data }).to_cell()?)
+    }
+
+    // keys are stored as hashes, so caller specifies attribute names to look for
+    pub fn parse_onchain(cell: &TonCell, attr_names: &[&str]) -> TonResult<HashMap<String, MetadataValue>> {
+        let internal = match MetadataContent::from_cell(cell)? {
+            MetadataContent::Internal(internal) => internal,
+            other => bail_ton!("Expected on-chain metadata content, got {other:?}"),
+        };
+        let mut attrs = HashMap::new();
+        for name in attr_names {
+            if let Some(value) = internal.data.get(MetadataField::new(name).deref()) {
+                attrs.insert(name.to_string(), MetadataValue::from_snake(value)?);
+            }
+        }
+        Ok(attrs)
+    }
+
     pub fn as_internal(&self) -> Option<&MetadataInternal> {
         match self {
             MetadataContent::Internal(inner) => Some(inner),
@@ -151,13 +228,17 @@ impl From<MetadataUnsupported> for MetadataContent {
 #[cfg(test)]
 mod tests {
     use anyhow::Result;
+    use ton_core::cell::TonCell;
     use ton_core::traits::tlb::TLB;
 
-    use crate::tep::metadata::MetadataContent;
+    use crate::tep::jetton::JettonMetadata;
+    use crate::tep::metadata::{Metadata, MetadataContent, MetadataField, MetadataInternal, MetadataValue};
+    use crate::tep::snake_data::SnakeData;
     use log::LevelFilter;
     use log4rs::Config;
     use log4rs::append::console::{ConsoleAppender, Target};
     use log4rs::config::{Appender, Root};
+    use std::ops::Deref;
     use std::sync::Once;
 
     static LOG: Once = Once::new();
@@ -189,4 +270,62 @@ mod tests {
         dbg!(content);
         Ok(())
     }
+
+    #[test]
+    fn test_metadata_build_onchain_roundtrip() -> Result<()> {
+        let image = "https://example.com/images/".repeat(50);
+        let attrs = [
+            ("name", MetadataValue::from("Test Jetton")),
+            ("symbol", "TST".into()),
+            ("decimals", "6".into()),
+            ("image", image.clone().into()),
+        ];
+        let cell = MetadataContent::build_onchain(&attrs)?;
+        assert_eq!(cell.parser().read_num::<u8>(8)?, 0);
+
+        let parsed = MetadataContent::parse_onchain(&cell, &["name", "symbol", "decimals", "image", "description"])?;
+        assert_eq!(parsed.len(), 4);
+        for (name, value) in &attrs {
+            assert_eq!(&parsed[*name], value);
+        }
+
+        let content = MetadataContent::from_cell(&cell)?;
+        let jetton_meta = JettonMetadata::from_dict(&content.as_internal().unwrap().data)?;
+        assert_eq!(jetton_meta.name.as_deref(), Some("Test Jetton"));
+        assert_eq!(jetton_meta.symbol.as_deref(), Some("TST"));
+        assert_eq!(jetton_meta.decimals, Some(6));
+        assert_eq!(jetton_meta.image, Some(image));
+
+        let empty = MetadataContent::from_cell(&MetadataContent::build_onchain(&[])?)?;
+        assert!(empty.as_internal().unwrap().data.is_empty());
+        assert!(MetadataContent::parse_onchain(&TonCell::builder().build()?, &["name"]).is_err());
+        Ok(())
+    }
+
+    #[test]
+    fn test_metadata_parse_onchain_keeps_encoding() -> Result<()> {
+        let no_prefix = SnakeData::from("TST");
+        let custom_chunks = SnakeData {
+            data: b"\0Test Jetton".to_vec(),
+            chunks_bits_len: vec![40, 56],
+        };
+        let data = [
+            ("symbol", no_prefix.clone()),
+            ("name", custom_chunks.clone()),
+            ("decimals", SnakeData::from("\09")),
+        ]
+        .into_iter()
+        .map(|(name, value)| (MetadataField::new(name).deref().clone(), value.into()))
+        .collect();
+        let cell = MetadataContent::Internal(MetadataInternal { data }).to_cell()?;
+
+        let parsed = MetadataContent::parse_onchain(&cell, &["name", "symbol", "decimals"])?;
+        assert_eq!(parsed["symbol"], MetadataValue::Raw(SnakeData::from_cell(&no_prefix.to_cell()?)?));
+        assert_eq!(parsed["name"], MetadataValue::Raw(custom_chunks));
+        assert_eq!(parsed["decimals"], MetadataValue::from("9"));
+
+        let attrs: Vec<_> = parsed.iter().map(|(name, value)| (name.as_str(), value.clone())).collect();
+        assert_eq!(MetadataContent::build_onchain(&attrs)?, cell);
+        Ok(())
+    }
 }
@@ -24,7 +24,7 @@ impl Deref for MetadataField {
 }
 
 impl MetadataField {
-    fn new(name: &str) -> MetadataField {
+    pub fn new(name: &str) -> MetadataField {
         let mut hasher = Sha256::new();
         hasher.update(name);
         let slice = &hasher.finalize()[..];
@@ -18,6 +18,7 @@ pub struct SnakeData {
 #[rustfmt::skip]
 impl SnakeData {
     pub fn new(data: Vec<u8>) -> Self { Self { data, chunks_bits_len: vec![] } }
+    pub fn from_bytes(data: &[u8]) -> Self { Self::new(data.to_vec()) }
     pub fn as_str(&self) -> Cow<'_, str> {
         if self.data.is_empty() {
             return Cow::Borrowed("");
@@ -73,6 +74,27 @@ impl TLB for SnakeData {
     }
 }
 
+/// Shortcuts to store bytes as a snake chain in a ref without building SnakeData manually
+pub trait CellBuilderSnakeExt {
+    fn write_snake_ref(&mut self, data: &[u8]) -> Result<(), TonCoreError>;
+}
+
+pub trait CellParserSnakeExt {
+    fn read_snake_ref(&mut self) -> Result<Vec<u8>, TonCoreError>;
+}
+
+impl CellBuilderSnakeExt for CellBuilder {
+    fn write_snake_ref(&mut self, data: &[u8]) -> Result<(), TonCoreError> {
+        self.write_ref(SnakeData::from_bytes(data).to_cell()?)
+    }
+}
+
+impl CellParserSnakeExt for CellParser<'_> {
+    fn read_snake_ref(&mut self) -> Result<Vec<u8>, TonCoreError> {
+        Ok(SnakeData::from_cell(self.read_next_ref()?)?.data)
+    }
+}
+
 impl SnakeData {
     fn read_chunk(&mut self, parser: &mut CellParser, bits_read: &mut usize) -> Result<(), TonCoreError> {
         let cur_cell_bits_len = parser.data_bits_left()?;
@@ -123,7 +145,8 @@ impl SnakeData {
         let bits_to_write = if let Some(chunk_bits_len) = chunks_bits_len.first() {
             *chunk_bits_len
         } else {
-            min(data_bits_len - bits_offset, builder.data_bits_left())
+            // keep chunks byte-aligned, so every cell holds whole bytes
+            min(data_bits_len - bits_offset, builder.data_bits_left() / 8 * 8)
         };
         if bits_to_write > data_bits_len - bits_offset {
             bail_ton_core_data!(
@@ -155,7 +178,7 @@ mod tests {
     use ton_core::cell::TonCell;
     use ton_core::traits::tlb::TLB;
 
-    use crate::tep::snake_data::SnakeData;
+    use crate::tep::snake_data::{CellBuilderSnakeExt, CellParserSnakeExt, SnakeData};
 
     #[test]
     fn test_snake_data() -> anyhow::Result<()> {
@@ -182,7 +205,7 @@ mod tests {
         let serialized = parsed_no_prefix.to_cell()?;
         assert_eq!(serialized, cell);
 
-        // test serialization fill all available bits in cell by default
+        // test serialization fill all available whole bytes in cell by default
         let snake_data = SnakeData::new(vec![0b11111111; 128]); // 1024 bits
         let mut builder = TonCell::builder();
         builder.write_bits([0b00000000; 64], 512)?;
@@ -191,15 +214,14 @@ mod tests {
         let cell = builder.build()?;
         let mut parser = cell.parser();
         let _ = parser.read_bits(512); // skip
-        assert_eq!(parser.data_bits_left()?, 511);
-        let mut expected_bits = vec![0b11111111; 63];
-        expected_bits.push(0b11111110);
-        assert_eq!(parser.read_bits(511)?, expected_bits);
+        assert_eq!(parser.data_bits_left()?, 504);
+        assert_eq!(parser.read_bits(504)?, vec![0b11111111; 63]);
+        assert_eq!(cell.refs()[0].data_len_bits(), 520);
 
         // just in case - write to empty cell
         let cell = snake_data.to_cell()?;
-        assert_eq!(cell.data_len_bits(), 1023);
-        assert_eq!(cell.refs()[0].data_len_bits(), 1);
+        assert_eq!(cell.data_len_bits(), 1016);
+        assert_eq!(cell.refs()[0].data_len_bits(), 8);
 
         // from_str
 
@@ -292,6 +314,52 @@ mod tests {
         Ok(())
     }
 
+    #[test]
+    fn test_snake_data_from_bytes_multiple_cells() -> anyhow::Result<()> {
+        let data: Vec<u8> = (0..500).map(|i| (i % 251) as u8).collect();
+        let cell = SnakeData::from_bytes(&data).to_cell()?;
+
+        // 500 bytes = 127 + 127 + 127 + 119
+        let mut chunks_len = vec![];
+        let mut cur = cell.clone();
+        loop {
+            chunks_len.push(cur.data_len_bits());
+            assert_eq!(cur.data_len_bits() % 8, 0);
+            match cur.refs().first() {
+                Some(next) => cur = next.clone(),
+                None => break,
+            }
+        }
+        assert_eq!(chunks_len, vec![1016, 1016, 1016, 952]);
+
+        let parsed = SnakeData::from_cell(&cell)?;
+        assert_eq!(parsed.as_slice(), data.as_slice());
+        Ok(())
+    }
+
+    #[test]
+    fn test_snake_data_write_read_snake_ref() -> anyhow::Result<()> {
+        let payload: Vec<u8> = (0..200).map(|i| i as u8).collect();
+        let mut builder = TonCell::builder();
+        builder.write_num(&0x12345678u32, 32)?;
+        builder.write_snake_ref(&payload)?;
+        builder.write_snake_ref(&[])?;
+        let cell = builder.build()?;
+
+        // 200 bytes = 127 in the first snake cell + 73 in the second one
+        let snake_cell = &cell.refs()[0];
+        assert_eq!(snake_cell.data_len_bits(), 127 * 8);
+        assert_eq!(snake_cell.refs()[0].data_len_bits(), 73 * 8);
+        assert!(snake_cell.refs()[0].refs().is_empty());
+
+        let mut parser = cell.parser();
+        assert_eq!(parser.read_num::<u32>(32)?, 0x12345678);
+        assert_eq!(parser.read_snake_ref()?, payload);
+        assert_eq!(parser.read_snake_ref()?, Vec::<u8>::new());
+        assert!(parser.read_snake_ref().is_err());
+        Ok(())
+    }
+
     #[test]
     fn test_snake_data_from_str() -> anyhow::Result<()> {
         let s = "Hello, SnakeData!";
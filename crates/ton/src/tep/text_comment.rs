@@ -0,0 +1,93 @@
+use crate::tep::snake_data::SnakeData;
+use ton_core::cell::{CellBuilder, CellParser};
+use ton_core::errors::TonCoreError;
+use ton_core::traits::tlb::{TLB, TLBPrefix};
+
+// https://docs.ton.org/v3/guidelines/dapps/asset-processing/payments-processing#send-payments-with-comments
+/// Transfer comment: op = 0 followed by snake-encoded UTF-8 text
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct TextComment(pub String);
+
+impl TextComment {
+    pub fn new<T: Into<String>>(text: T) -> Self { Self(text.into()) }
+    pub fn as_str(&self) -> &str { &self.0 }
+}
+
+impl TLB for TextComment {
+    const PREFIX: TLBPrefix = TLBPrefix::new(0, 32);
+
+    fn read_definition(parser: &mut CellParser) -> Result<Self, TonCoreError> {
+        Ok(Self(String::from_utf8(SnakeData::read(parser)?.data)?))
+    }
+
+    fn write_definition(&self, builder: &mut CellBuilder) -> Result<(), TonCoreError> {
+        SnakeData::from(self.as_str()).write(builder)
+    }
+}
+
+pub trait CellParserCommentExt {
+    /// Returns None (and keeps the position) if data doesn't start with comment op
+    fn read_text_comment(&mut self) -> Result<Option<TextComment>, TonCoreError>;
+}
+
+impl CellParserCommentExt for CellParser<'_> {
+    fn read_text_comment(&mut self) -> Result<Option<TextComment>, TonCoreError> {
+        let prefix_bits_len = TextComment::PREFIX.bits_len;
+        if self.data_bits_left()? < prefix_bits_len || self.lookup_bits(prefix_bits_len)? != 0 {
+            return Ok(None);
+        }
+        Ok(Some(TextComment::read(self)?))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::block_tlb::{CommonMsgInfo, CommonMsgInfoExtIn, Msg};
+    use std::str::FromStr;
+    use ton_core::cell::TonCell;
+    use ton_core::types::TonAddress;
+    use ton_core::types::tlb_core::{MsgAddressExt, TLBCoins};
+
+    #[test]
+    fn test_text_comment_short() -> anyhow::Result<()> {
+        let comment = TextComment::new("hello ✋");
+        let cell = comment.to_cell()?;
+        assert_eq!(cell.data_len_bits(), 32 + comment.as_str().len() * 8);
+        assert!(cell.refs().is_empty());
+        assert_eq!(cell.parser().read_num::<u32>(32)?, 0);
+        assert_eq!(TextComment::from_cell(&cell)?, comment);
+        Ok(())
+    }
+
+    #[test]
+    fn test_text_comment_long() -> anyhow::Result<()> {
+        let comment = TextComment::new("long comment ".repeat(50));
+        let cell = comment.to_cell()?;
+        assert!(!cell.refs().is_empty());
+        assert_eq!(TextComment::from_cell(&cell)?, comment);
+        assert_eq!(cell.parser().read_text_comment()?, Some(comment));
+        Ok(())
+    }
+
+    #[test]
+    fn test_text_comment_detect() -> anyhow::Result<()> {
+        let mut builder = TonCell::builder();
+        builder.write_num(&0x0f8a7ea5u32, 32)?;
+        let not_comment = builder.build()?;
+        let mut parser = not_comment.parser();
+        assert_eq!(parser.read_text_comment()?, None);
+        assert_eq!(parser.data_bits_left()?, 32);
+        assert_eq!(TonCell::empty().parser().read_text_comment()?, None);
+
+        let msg_info = CommonMsgInfo::ExtIn(CommonMsgInfoExtIn {
+            src: MsgAddressExt::NONE,
+            dst: TonAddress::from_str("EQBiMfDMivebQb052Z6yR3jHrmwNhw1kQ5bcAUOBYsK_VPuK")?.to_msg_address_int(),
+            import_fee: TLBCoins::ZERO,
+        });
+        let msg = Msg::new(msg_info.clone(), TextComment::new("gm").to_cell()?);
+        assert_eq!(msg.text_comment()?, Some(TextComment::new("gm")));
+        assert_eq!(Msg::new(msg_info, not_comment).text_comment()?, None);
+        Ok(())
+    }
+}
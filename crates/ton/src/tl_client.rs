@@ -1,20 +1,29 @@
 mod builder;
 mod callback;
 mod connection;
+mod health_check;
+mod metrics;
 
 pub mod tl;
 mod tl_client_trait;
 
 pub use callback::*;
 pub use connection::*;
+pub use metrics::*;
 pub use tl_client_trait::*;
 
+// re-export for backward compatibility
+pub use crate::util::{RetryBackoff, RetryStrategy};
+
 use crate::errors::TonResult;
 use crate::tl_client::builder::Builder;
+use crate::tl_client::health_check::{ConnRecovery, check_and_recover};
 use async_trait::async_trait;
 use rand::prelude::{IndexedRandom, StdRng};
 use std::ops::DerefMut;
-use std::sync::{Arc, Mutex};
+use std::sync::atomic::AtomicBool;
+use std::sync::atomic::Ordering::Relaxed;
+use std::sync::{Arc, Mutex, Weak};
 use std::time::Duration;
 
 // /// Simple contract_client with many connections
@@ -25,6 +34,8 @@ pub struct TLClient {
 
 impl TLClient {
     pub fn builder() -> TonResult<Builder> { Builder::new() }
+
+    pub fn healthy_connection_count(&self) -> usize { self.inner.healthy.iter().filter(|x| x.load(Relaxed)).count() }
 }
 
 #[async_trait]
@@ -40,17 +51,28 @@ impl TLClientTrait for TLClient {
 struct Inner {
     rnd: Mutex<StdRng>,
     connections: Vec<TLConnection>,
+    healthy: Vec<AtomicBool>,
     retry_strategy: RetryStrategy,
 }
 
+// stops when client is dropped
+async fn health_check_loop<R: ConnRecovery<Conn = TLConnection>>(
+    weak_inner: Weak<Inner>,
+    recovery: R,
+    interval: Duration,
+) {
+    loop {
+        tokio::time::sleep(interval).await;
+        let Some(inner) = weak_inner.upgrade() else {
+            break;
+        };
+        let healthy_cnt = check_and_recover(&recovery, &inner.connections, &inner.healthy).await;
+        log::debug!("[TLClient] health check done: {healthy_cnt}/{} connections are healthy", inner.connections.len());
+    }
+}
+
 #[derive(Debug, PartialEq, Clone)]
 pub enum LiteNodeFilter {
     Healthy, // connect to any healthy node
     Archive, // connect to archive node only
 }
-
-#[derive(Debug, Clone)]
-pub struct RetryStrategy {
-    pub retry_count: usize,
-    pub retry_waiting: Duration,
-}
@@ -3,18 +3,22 @@ use crate::block_tlb::BlockIdExt;
 use crate::errors::{TonError, TonResult};
 use crate::lite_client::LiteClient;
 use crate::net_config::TonNetConfig;
+use crate::tl_client::connection::TLConnRecovery;
 use crate::tl_client::tl::{TLConfig, TLKeyStoreType, TLOptions};
-use crate::tl_client::{Inner, LiteNodeFilter, RetryStrategy, TLCallbacksStore, TLClient, TLConnection};
+use crate::tl_client::{
+    Inner, LiteNodeFilter, RetryStrategy, TLCallbacksStore, TLClient, TLConnection, TLMetricsStore, health_check_loop,
+};
 use derive_setters::Setters;
 use futures_util::future::{join_all, try_join_all};
 use rand::SeedableRng;
 use rand::prelude::StdRng;
 use std::fmt::Debug;
+use std::sync::atomic::AtomicBool;
 use std::sync::{Arc, Mutex};
 use std::time::Duration;
 use tokio::sync::Semaphore;
 
-#[derive(Setters, Debug)]
+#[derive(Setters, Debug, Clone)]
 #[setters(prefix = "with_", strip_option)]
 pub struct Builder {
     pub(super) mainnet: bool,
@@ -28,6 +32,8 @@ pub struct Builder {
     pub(super) sleep_on_connection_error_ms: Duration,
     pub(super) tonlib_verbosity_level: u32,
     pub(super) callbacks: TLCallbacksStore,
+    pub(super) metrics: TLMetricsStore,
+    pub(super) health_check_interval: Duration, // Duration::ZERO disables health check
 }
 
 impl Builder {
@@ -47,15 +53,14 @@ impl Builder {
             connection_check: LiteNodeFilter::Healthy,
             connections_count: 5,
             max_parallel_requests: 10,
-            retry_strategy: RetryStrategy {
-                retry_count: 5,
-                retry_waiting: Duration::from_millis(200),
-            },
+            retry_strategy: RetryStrategy::new(5, Duration::from_millis(200)),
             update_init_block: true,
             update_init_block_timeout_sec: 10,
             sleep_on_connection_error_ms: Duration::from_millis(100),
             tonlib_verbosity_level: 1,
             callbacks: Default::default(),
+            metrics: Default::default(),
+            health_check_interval: Duration::from_secs(30),
         };
         Ok(builder)
     }
@@ -88,12 +93,18 @@ impl Builder {
             Err(err) => bail_ton!("[TLClient] Failed to initialize TLConnection: {:?}", err),
         };
 
-        let inner = Inner {
+        let inner = Arc::new(Inner {
             rnd: Mutex::new(StdRng::from_rng(&mut rand::rng())),
+            healthy: connections.iter().map(|_| AtomicBool::new(true)).collect(),
             connections,
-            retry_strategy: self.retry_strategy,
-        };
-        Ok(TLClient { inner: Arc::new(inner) })
+            retry_strategy: self.retry_strategy.clone(),
+        });
+        if !self.health_check_interval.is_zero() {
+            let interval = self.health_check_interval;
+            let recovery = TLConnRecovery::new(self, semaphore);
+            tokio::spawn(health_check_loop(Arc::downgrade(&inner), recovery, interval));
+        }
+        Ok(TLClient { inner })
     }
 
     pub fn with_net_config(mut self, net_config: &TonNetConfig) -> TonResult<Self> {
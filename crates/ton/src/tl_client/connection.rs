@@ -2,23 +2,26 @@ use std::collections::HashMap;
 use std::sync::atomic::{AtomicU64, Ordering};
 use std::sync::{Arc, Weak};
 use std::thread;
-use std::time::{Duration, Instant};
+use std::time::Instant;
 
 use crate::errors::TonError;
 use crate::sys_utils::sys_tonlib_set_verbosity_level;
+use crate::tl_client::metrics::exec_measured;
 use crate::tl_client::tl::tonlibjson_wrapper::TonLibJsonWrapper;
 use crate::tl_client::tl::*;
 use crate::tl_client::*;
 use crate::unwrap_tl_rsp;
 use async_trait::async_trait;
+use parking_lot::RwLock;
 use tokio::sync::{Mutex, Semaphore, oneshot};
 use ton_core::constants::{TON_MASTERCHAIN, TON_SHARD_FULL};
 
 static CONNECTION_COUNTER: AtomicU64 = AtomicU64::new(0);
 
+// inner is swappable, so connection can be re-initialized in place by health check
 #[derive(Clone)]
 pub struct TLConnection {
-    inner: Arc<Inner>,
+    inner: Arc<RwLock<Arc<Inner>>>,
 }
 
 struct Inner {
@@ -27,19 +30,14 @@ struct Inner {
     semaphore: Arc<Semaphore>,
     next_request_id: AtomicU64,
     callbacks: TLCallbacksStore,
+    metrics: TLMetricsStore,
 }
 
 #[async_trait]
 impl TLClientTrait for TLConnection {
     fn get_connection(&self) -> &TLConnection { self }
 
-    fn get_retry_strategy(&self) -> &RetryStrategy {
-        static NO_RETRY: RetryStrategy = RetryStrategy {
-            retry_count: 0,
-            retry_waiting: Duration::from_millis(0),
-        };
-        &NO_RETRY
-    }
+    fn get_retry_strategy(&self) -> &RetryStrategy { &RetryStrategy::NO_RETRY }
 }
 
 impl TLConnection {
@@ -53,12 +51,25 @@ impl TLConnection {
                 }
             };
         };
-        log::debug!("Connection {} established", checked_connection.inner.tonlibjson_wrapper.tag());
+        log::debug!("Connection {} established", checked_connection.current().tonlibjson_wrapper.tag());
         sys_tonlib_set_verbosity_level(builder.tonlib_verbosity_level);
         Ok(checked_connection)
     }
 
-    pub async fn exec_impl(&self, req: &TLRequest) -> Result<TLResponse, TonError> { self.inner.exec_impl(req).await }
+    pub async fn exec_impl(&self, req: &TLRequest) -> Result<TLResponse, TonError> {
+        let inner = self.current();
+        exec_measured(&inner.metrics, req.into(), inner.exec_impl(req)).await
+    }
+
+    pub fn metrics(&self) -> TLMetricsStore { self.current().metrics.clone() }
+
+    fn current(&self) -> Arc<Inner> { self.inner.read().clone() }
+
+    // in-flight requests keep old inner alive until they're done
+    fn replace_with(&self, other: TLConnection) {
+        let new_inner = other.current();
+        *self.inner.write() = new_inner;
+    }
 
     async fn init(&self, options: TLOptions) -> Result<TLOptionsInfo, TonError> {
         let req = TLRequest::Init { options };
@@ -134,14 +145,7 @@ async fn new_checked_connection(builder: &Builder, semaphore: Arc<Semaphore>) ->
 
     match builder.connection_check {
         LiteNodeFilter::Healthy => {
-            let mc_info = new_conn
-                .get_mc_info()
-                .await
-                .map_err(|err| TonError::Custom(format!(".get_mc_info() failed with error: {err:?}")))?;
-            let _header = new_conn
-                .get_block_header(mc_info.last)
-                .await
-                .map_err(|err| TonError::Custom(format!(".get_block_header() failed with error: {err:?}")))?;
+            check_healthy(&new_conn).await?;
             Ok(new_conn)
         }
         LiteNodeFilter::Archive => {
@@ -161,6 +165,18 @@ async fn new_checked_connection(builder: &Builder, semaphore: Arc<Semaphore>) ->
     }
 }
 
+async fn check_healthy(conn: &TLConnection) -> Result<(), TonError> {
+    let mc_info = conn
+        .get_mc_info()
+        .await
+        .map_err(|err| TonError::Custom(format!(".get_mc_info() failed with error: {err:?}")))?;
+    let _header = conn
+        .get_block_header(mc_info.last)
+        .await
+        .map_err(|err| TonError::Custom(format!(".get_block_header() failed with error: {err:?}")))?;
+    Ok(())
+}
+
 async fn new_connection(builder: &Builder, semaphore: Arc<Semaphore>) -> Result<TLConnection, TonError> {
     let conn_id = CONNECTION_COUNTER.fetch_add(1, Ordering::Relaxed);
     let tag = format!("ton-conn-{conn_id}");
@@ -171,6 +187,7 @@ async fn new_connection(builder: &Builder, semaphore: Arc<Semaphore>) -> Result<
         semaphore,
         next_request_id: AtomicU64::new(0),
         callbacks: builder.callbacks.clone(),
+        metrics: builder.metrics.clone(),
     });
     let init_log_level = match builder.tonlib_verbosity_level {
         4 => 1,
@@ -185,7 +202,31 @@ async fn new_connection(builder: &Builder, semaphore: Arc<Semaphore>) -> Result<
         .spawn(|| run_loop(tag, inner_weak, callbacks))
         .map_err(TonError::system);
 
-    let conn = TLConnection { inner };
+    let conn = TLConnection {
+        inner: Arc::new(RwLock::new(inner)),
+    };
     let _info = conn.init(builder.init_opts.clone()).await?;
     Ok(conn)
 }
+
+pub(super) struct TLConnRecovery {
+    builder: Builder,
+    semaphore: Arc<Semaphore>,
+}
+
+impl TLConnRecovery {
+    pub(super) fn new(builder: Builder, semaphore: Arc<Semaphore>) -> Self { Self { builder, semaphore } }
+}
+
+#[async_trait]
+impl ConnRecovery for TLConnRecovery {
+    type Conn = TLConnection;
+
+    async fn check(&self, conn: &TLConnection) -> Result<(), TonError> { check_healthy(conn).await }
+
+    async fn reconnect(&self, conn: &TLConnection) -> Result<(), TonError> {
+        let new_conn = new_checked_connection(&self.builder, self.semaphore.clone()).await?;
+        conn.replace_with(new_conn);
+        Ok(())
+    }
+}
@@ -0,0 +1,108 @@
+use crate::errors::TonResult;
+use async_trait::async_trait;
+use std::sync::atomic::AtomicBool;
+use std::sync::atomic::Ordering::Relaxed;
+
+// Decouples health-check flow from tonlib, so it can be tested with mock connections
+#[async_trait]
+pub(super) trait ConnRecovery: Send + Sync {
+    type Conn: Send + Sync;
+    async fn check(&self, conn: &Self::Conn) -> TonResult<()>;
+    // re-initializes connection in place
+    async fn reconnect(&self, conn: &Self::Conn) -> TonResult<()>;
+}
+
+// returns amount of healthy connections after the check
+pub(super) async fn check_and_recover<R: ConnRecovery>(
+    recovery: &R,
+    conns: &[R::Conn],
+    healthy: &[AtomicBool],
+) -> usize {
+    for (pos, (conn, is_healthy)) in conns.iter().zip(healthy).enumerate() {
+        let Err(err) = recovery.check(conn).await else {
+            is_healthy.store(true, Relaxed);
+            continue;
+        };
+        log::warn!("[TLClient] connection #{pos} is unhealthy: {err:?}, reconnecting...");
+        is_healthy.store(false, Relaxed);
+        match recovery.reconnect(conn).await {
+            Ok(()) => {
+                log::info!("[TLClient] connection #{pos} is re-initialized");
+                is_healthy.store(true, Relaxed);
+            }
+            Err(err) => log::warn!("[TLClient] failed to re-initialize connection #{pos}: {err:?}"),
+        }
+    }
+    healthy.iter().filter(|x| x.load(Relaxed)).count()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::bail_ton;
+    use std::sync::atomic::AtomicUsize;
+
+    #[derive(Default)]
+    struct MockConn {
+        broken: AtomicBool,
+        reconnects: AtomicUsize,
+    }
+
+    struct MockRecovery {
+        reconnect_works: bool,
+    }
+
+    #[async_trait]
+    impl ConnRecovery for MockRecovery {
+        type Conn = MockConn;
+
+        async fn check(&self, conn: &MockConn) -> TonResult<()> {
+            if conn.broken.load(Relaxed) {
+                bail_ton!("connection is broken");
+            }
+            Ok(())
+        }
+
+        async fn reconnect(&self, conn: &MockConn) -> TonResult<()> {
+            if !self.reconnect_works {
+                bail_ton!("reconnect failed");
+            }
+            conn.broken.store(false, Relaxed);
+            conn.reconnects.fetch_add(1, Relaxed);
+            Ok(())
+        }
+    }
+
+    #[tokio::test]
+    async fn test_check_and_recover_replaces_unhealthy() -> anyhow::Result<()> {
+        let conns: Vec<MockConn> = (0..3).map(|_| MockConn::default()).collect();
+        let healthy: Vec<AtomicBool> = (0..3).map(|_| AtomicBool::new(true)).collect();
+        let recovery = MockRecovery { reconnect_works: true };
+
+        assert_eq!(check_and_recover(&recovery, &conns, &healthy).await, 3);
+
+        conns[1].broken.store(true, Relaxed);
+        assert_eq!(check_and_recover(&recovery, &conns, &healthy).await, 3);
+        assert_eq!(conns[0].reconnects.load(Relaxed), 0);
+        assert_eq!(conns[1].reconnects.load(Relaxed), 1);
+        assert_eq!(conns[2].reconnects.load(Relaxed), 0);
+        assert!(!conns[1].broken.load(Relaxed));
+        Ok(())
+    }
+
+    #[tokio::test]
+    async fn test_check_and_recover_reconnect_failed() -> anyhow::Result<()> {
+        let conns: Vec<MockConn> = (0..2).map(|_| MockConn::default()).collect();
+        let healthy: Vec<AtomicBool> = (0..2).map(|_| AtomicBool::new(true)).collect();
+        let recovery = MockRecovery { reconnect_works: false };
+
+        conns[0].broken.store(true, Relaxed);
+        assert_eq!(check_and_recover(&recovery, &conns, &healthy).await, 1);
+        assert!(!healthy[0].load(Relaxed));
+
+        // connection recovered by itself
+        conns[0].broken.store(false, Relaxed);
+        assert_eq!(check_and_recover(&recovery, &conns, &healthy).await, 2);
+        Ok(())
+    }
+}
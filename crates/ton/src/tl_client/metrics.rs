@@ -0,0 +1,129 @@
+use crate::errors::TonError;
+use crate::tl_client::tl::TLResponse;
+use std::fmt::Debug;
+use std::sync::Arc;
+use std::time::{Duration, Instant};
+
+/// Per-request metrics hook (e.g. to export Prometheus counters)
+///
+/// `on_request_start` / `on_request_end` are called for each attempt, `on_retry` - before each retry attempt
+pub trait TLMetrics: Send + Sync {
+    fn on_request_start(&self, method: &str);
+    fn on_request_end(&self, method: &str, latency: Duration, ok: bool);
+    fn on_retry(&self, method: &str);
+}
+
+pub struct TLMetricsNoop;
+impl TLMetrics for TLMetricsNoop {
+    fn on_request_start(&self, _method: &str) {}
+    fn on_request_end(&self, _method: &str, _latency: Duration, _ok: bool) {}
+    fn on_retry(&self, _method: &str) {}
+}
+
+#[derive(Clone)]
+pub struct TLMetricsStore {
+    pub metrics: Arc<dyn TLMetrics>,
+}
+
+impl TLMetricsStore {
+    pub fn new<M: TLMetrics + 'static>(metrics: M) -> Self {
+        Self {
+            metrics: Arc::new(metrics),
+        }
+    }
+}
+
+impl Default for TLMetricsStore {
+    fn default() -> Self { Self::new(TLMetricsNoop) }
+}
+
+impl TLMetrics for TLMetricsStore {
+    fn on_request_start(&self, method: &str) { self.metrics.on_request_start(method); }
+    fn on_request_end(&self, method: &str, latency: Duration, ok: bool) {
+        self.metrics.on_request_end(method, latency, ok);
+    }
+    fn on_retry(&self, method: &str) { self.metrics.on_retry(method); }
+}
+
+impl Debug for TLMetricsStore {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result { write!(f, "TLMetricsStore") }
+}
+
+// TLResponse::Error is delivered as Ok(..), but it's a failed request anyway
+pub(super) async fn exec_measured<M, F>(metrics: &M, method: &str, request: F) -> Result<TLResponse, TonError>
+where
+    M: TLMetrics + ?Sized,
+    F: Future<Output = Result<TLResponse, TonError>>,
+{
+    metrics.on_request_start(method);
+    let started = Instant::now();
+    let result = request.await;
+    let ok = matches!(&result, Ok(rsp) if !matches!(rsp, TLResponse::Error { .. }));
+    metrics.on_request_end(method, started.elapsed(), ok);
+    result
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use parking_lot::Mutex;
+    use std::sync::atomic::AtomicUsize;
+    use std::sync::atomic::Ordering::Relaxed;
+    use tokio_test::{assert_err, assert_ok};
+
+    #[derive(Default)]
+    struct CountingMetrics {
+        starts: AtomicUsize,
+        retries: AtomicUsize,
+        ends: Mutex<Vec<(String, Duration, bool)>>,
+    }
+
+    impl TLMetrics for CountingMetrics {
+        fn on_request_start(&self, _method: &str) { self.starts.fetch_add(1, Relaxed); }
+        fn on_request_end(&self, method: &str, latency: Duration, ok: bool) {
+            self.ends.lock().push((method.to_string(), latency, ok));
+        }
+        fn on_retry(&self, _method: &str) { self.retries.fetch_add(1, Relaxed); }
+    }
+
+    #[tokio::test]
+    async fn test_tl_metrics_exec_measured() -> anyhow::Result<()> {
+        let metrics = CountingMetrics::default();
+        let mocked_request = async {
+            tokio::time::sleep(Duration::from_millis(10)).await;
+            Ok(TLResponse::Ok(()))
+        };
+        assert_ok!(exec_measured(&metrics, "sync", mocked_request).await);
+
+        assert_eq!(metrics.starts.load(Relaxed), 1);
+        assert_eq!(metrics.retries.load(Relaxed), 0);
+        let ends = metrics.ends.lock().clone();
+        assert_eq!(ends.len(), 1);
+        let (method, latency, ok) = &ends[0];
+        assert_eq!(method, "sync");
+        assert!(*latency >= Duration::from_millis(10));
+        assert!(ok);
+        Ok(())
+    }
+
+    #[tokio::test]
+    async fn test_tl_metrics_exec_measured_failed() -> anyhow::Result<()> {
+        let metrics = Arc::new(CountingMetrics::default());
+        let store = TLMetricsStore {
+            metrics: metrics.clone(),
+        };
+        let error_rsp = async {
+            Ok(TLResponse::Error {
+                code: 500,
+                message: "error".to_string(),
+            })
+        };
+        assert_ok!(exec_measured(&store, "sync", error_rsp).await);
+        assert_err!(exec_measured(&store, "sync", async { Err(TonError::Custom("error".to_string())) }).await);
+
+        assert_eq!(metrics.starts.load(Relaxed), 2);
+        let oks: Vec<_> = metrics.ends.lock().iter().map(|(_, _, ok)| *ok).collect();
+        assert_eq!(oks, vec![false, false]);
+        Ok(())
+    }
+}
@@ -1,12 +1,13 @@
 use crate::block_tlb::BlockIdExt;
 use crate::errors::TonError;
-use crate::tl_client::RetryStrategy;
 use crate::tl_client::connection::TLConnection;
 use crate::tl_client::tl::*;
+use crate::tl_client::{RetryStrategy, TLMetrics};
 use crate::unwrap_tl_rsp;
+use crate::util;
 use async_trait::async_trait;
-use tokio_retry::RetryIf;
-use tokio_retry::strategy::FixedInterval;
+use std::sync::atomic::AtomicU32;
+use std::sync::atomic::Ordering::Relaxed;
 use ton_core::cell::TonHash;
 use ton_core::constants::{TON_MASTERCHAIN, TON_SHARD_FULL};
 use ton_core::types::{TonAddress, TxLTHash};
@@ -17,10 +18,15 @@ pub trait TLClientTrait: Send + Sync {
     fn get_retry_strategy(&self) -> &RetryStrategy;
 
     async fn exec(&self, req: &TLRequest) -> Result<TLResponse, TonError> {
-        let retry_strat = self.get_retry_strategy();
-        let fi = FixedInterval::new(retry_strat.retry_waiting);
-        let strategy = fi.take(retry_strat.retry_count);
-        RetryIf::spawn(strategy, || self.get_connection().exec_impl(req), retry_condition).await
+        let attempts = AtomicU32::new(0);
+        let exec_request = || async {
+            let conn = self.get_connection();
+            if attempts.fetch_add(1, Relaxed) > 0 {
+                conn.metrics().on_retry(req.into());
+            }
+            conn.exec_impl(req).await
+        };
+        util::retry_if(self.get_retry_strategy(), exec_request, retry_condition).await
     }
 
     async fn get_mc_info(&self) -> Result<TLBlocksMCInfo, TonError> {
@@ -176,6 +182,7 @@ pub trait TLClientTrait: Send + Sync {
     }
 }
 
+// TLClient-specific: only tonlib responses with code 500 are retried, other errors are terminal
 fn retry_condition(error: &TonError) -> bool {
     match error {
         TonError::TLClientResponseError { code, .. } => *code == 500,
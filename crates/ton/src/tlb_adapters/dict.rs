@@ -1,13 +1,25 @@
+mod cell_dict_ext;
 mod data_builder;
 mod data_parser;
+mod dict_iter;
 mod dict_key_adapters;
 mod dict_val_adapters;
 mod label_type;
 mod leading_bit_utils;
 mod tlb_hash_map;
+mod tlb_hash_map_aug;
 mod tlb_hash_map_e;
 
+pub use cell_dict_ext::*;
+pub use dict_iter::*;
 pub use dict_key_adapters::*;
 pub use dict_val_adapters::*;
 pub use tlb_hash_map::*;
+pub use tlb_hash_map_aug::*;
 pub use tlb_hash_map_e::*;
+
+#[cfg(test)]
+pub(crate) mod test_fixtures {
+    // dict root cell (no presence bit) with 24-bit signed keys: -887220, -39660, -34080, 887220
+    pub(crate) const SIGNED_KEY_DICT_BOC_HEX: &str = "b5ee9c72010207010001e600020120010200e7ae3626d0000000000000000000000000000000000000000000000000000046ec8cd22d8bffffffffffffffffffffb913732dd27800000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000002020277030400e7a69d930000000000000000000000000000000000000000000000000000046ec8cd22d880000000000000000000046ec8cd22d8800000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000002002038ddc050600e5b14500000000000000000000000000000000000000000000000000001c0c3e8aba24400000000000000000001c0c3e8aba24400000000000000000000000000000000002f23e52bc009da2a23b462da0fa694000000000000000000000000000000000000e3288b94abb3942fb8a96aeac2fe000e5b2b800000000000000000000000000000000000000000000000000001c0c3e8aba247fffffffffffffffffffe3f3c17545dbc000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000020";
+}
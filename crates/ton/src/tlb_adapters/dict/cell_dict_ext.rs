@@ -0,0 +1,113 @@
+use crate::tlb_adapters::{DictKeyAdapter, DictValAdapter, TLBHashMap, TLBHashMapE};
+use std::collections::HashMap;
+use std::hash::Hash;
+use ton_core::cell::{CellBuilder, CellParser};
+use ton_core::errors::TonCoreError;
+
+pub type DictData<KA, VA> = HashMap<<KA as DictKeyAdapter>::KeyType, <VA as DictValAdapter>::ValType>;
+
+/// Shortcuts for `HashmapE` (presence bit + root ref) reading
+pub trait CellParserDictExt {
+    /// Returns empty map if dict is absent
+    fn load_dict<KA, VA>(&mut self, key_bits: usize) -> Result<DictData<KA, VA>, TonCoreError>
+    where
+        KA: DictKeyAdapter,
+        VA: DictValAdapter,
+        KA::KeyType: Eq + Hash;
+
+    fn load_dict_opt<KA, VA>(&mut self, key_bits: usize) -> Result<Option<DictData<KA, VA>>, TonCoreError>
+    where
+        KA: DictKeyAdapter,
+        VA: DictValAdapter,
+        KA::KeyType: Eq + Hash;
+}
+
+/// Writes `HashmapE`: presence bit + root ref (nothing but 0 bit for empty map)
+pub trait CellBuilderDictExt {
+    fn store_dict<KA, VA>(&mut self, key_bits: usize, data: &DictData<KA, VA>) -> Result<(), TonCoreError>
+    where
+        KA: DictKeyAdapter,
+        VA: DictValAdapter,
+        KA::KeyType: Eq + Hash + Ord;
+}
+
+impl CellParserDictExt for CellParser<'_> {
+    fn load_dict<KA, VA>(&mut self, key_bits: usize) -> Result<DictData<KA, VA>, TonCoreError>
+    where
+        KA: DictKeyAdapter,
+        VA: DictValAdapter,
+        KA::KeyType: Eq + Hash,
+    {
+        Ok(self.load_dict_opt::<KA, VA>(key_bits)?.unwrap_or_default())
+    }
+
+    fn load_dict_opt<KA, VA>(&mut self, key_bits: usize) -> Result<Option<DictData<KA, VA>>, TonCoreError>
+    where
+        KA: DictKeyAdapter,
+        VA: DictValAdapter,
+        KA::KeyType: Eq + Hash,
+    {
+        if !self.read_bit()? {
+            return Ok(None);
+        }
+        let dict_cell = self.read_next_ref()?;
+        Ok(Some(TLBHashMap::<KA, VA>::new(key_bits as u32).read(&mut dict_cell.parser())?))
+    }
+}
+
+impl CellBuilderDictExt for CellBuilder {
+    fn store_dict<KA, VA>(&mut self, key_bits: usize, data: &DictData<KA, VA>) -> Result<(), TonCoreError>
+    where
+        KA: DictKeyAdapter,
+        VA: DictValAdapter,
+        KA::KeyType: Eq + Hash + Ord,
+    {
+        TLBHashMapE::<KA, VA>::new(key_bits as u32).write(self, data)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::tlb_adapters::dict::test_fixtures::SIGNED_KEY_DICT_BOC_HEX;
+    use crate::tlb_adapters::{DictKeyAdapterInt, DictKeyAdapterUint, DictValAdapterNum, DictValAdapterTLB};
+    use ton_core::cell::TonCell;
+    use ton_core::traits::tlb::TLB;
+
+    #[test]
+    fn test_cell_dict_ext_signed_key_dict() -> anyhow::Result<()> {
+        type KA = DictKeyAdapterInt<24, i32>;
+        type VA = DictValAdapterTLB<TonCell>;
+        let dict_cell = TonCell::from_boc_hex(SIGNED_KEY_DICT_BOC_HEX)?;
+        let expected = TLBHashMap::<KA, VA>::new(24).read(&mut dict_cell.parser())?;
+
+        let mut builder = TonCell::builder();
+        builder.write_bit(true)?;
+        builder.write_ref(dict_cell.clone())?;
+        let wrapper = builder.build()?;
+
+        let loaded = wrapper.parser().load_dict::<KA, VA>(24)?;
+        assert_eq!(loaded, expected);
+        assert_eq!(wrapper.parser().load_dict_opt::<KA, VA>(24)?, Some(expected.clone()));
+
+        let mut builder = TonCell::builder();
+        builder.store_dict::<KA, VA>(24, &loaded)?;
+        assert_eq!(builder.build()?, wrapper);
+        Ok(())
+    }
+
+    #[test]
+    fn test_cell_dict_ext_empty() -> anyhow::Result<()> {
+        type KA = DictKeyAdapterUint<u8>;
+        type VA = DictValAdapterNum<u32, 32>;
+        let mut builder = TonCell::builder();
+        builder.store_dict::<KA, VA>(8, &HashMap::new())?;
+        let cell = builder.build()?;
+        assert_eq!(cell.data_len_bits(), 1);
+        assert_eq!(cell.refs().len(), 0);
+
+        assert_eq!(cell.parser().load_dict_opt::<KA, VA>(8)?, None);
+        assert!(cell.parser().load_dict::<KA, VA>(8)?.is_empty());
+        Ok(())
+    }
+}
@@ -1,6 +1,6 @@
 use super::label_type::DictLabelType;
 use super::leading_bit_utils::{add_leading_bit, all_bits_same, common_prefix_len, remove_leading_bit};
-use crate::tlb_adapters::DictValAdapter;
+use crate::tlb_adapters::{DictAugAdapter, DictValAdapter};
 use num_bigint::BigUint;
 use num_traits::{One, Zero};
 use std::marker::PhantomData;
@@ -59,31 +59,8 @@ impl<'a, VA: DictValAdapter> DictDataBuilder<'a, VA> {
         // will restore it at the end
         let key_len_bits_left_original = self.key_bits_len_left;
 
-        let key = &keys[0].1;
-        let key_len = key.bits() as usize; // includes leading bit
-
-        let common_prefix_len = common_prefix_len(key, &keys.last().unwrap().1);
-        let label = {
-            let ignored_suffix_len = key_len - common_prefix_len - 1;
-            key >> ignored_suffix_len
-        };
-        self.store_label(builder, &label)?;
-
-        let mut left_keys = Vec::with_capacity(keys.len() / 2);
-        let mut right_keys = Vec::with_capacity(keys.len() / 2);
-
-        let new_key_len = key_len - common_prefix_len - 1;
-        let new_key_mask = (BigUint::one() << new_key_len) - 1u32;
-        for (pos, key) in keys {
-            let new_key = key & new_key_mask.clone();
-            let is_right = new_key.bits() as usize == new_key_len;
-            let new_key_internal = add_leading_bit(&new_key, new_key_len - 1);
-            if is_right {
-                right_keys.push((pos, new_key_internal));
-            } else {
-                left_keys.push((pos, new_key_internal));
-            }
-        }
+        let (label, common_prefix_len, left_keys, right_keys) = split_keys(keys);
+        store_label(builder, &label, self.key_bits_len_left)?;
 
         self.key_bits_len_left -= common_prefix_len + 1; // branch consumes 1 more bit
         let mut left_builder = TonCell::builder();
@@ -104,57 +81,166 @@ impl<'a, VA: DictValAdapter> DictDataBuilder<'a, VA> {
         orig_key_pos: usize,
         label: &BigUint,
     ) -> Result<(), TonCoreError> {
-        self.store_label(builder, label)?;
+        store_label(builder, label, self.key_bits_len_left)?;
         VA::write(builder, self.values_sorted[orig_key_pos])?;
         Ok(())
     }
+}
+
+pub struct DictAugDataBuilder<'a, VA: DictValAdapter, EA: DictAugAdapter> {
+    keys_sorted: Vec<BigUint>, // contains 1 extra leading bit set to 1
+    values_sorted: &'a [&'a (VA::ValType, EA::ValType)],
+    key_bits_len_left: usize,
+    _phantom: PhantomData<(VA, EA)>,
+}
+
+impl<'a, VA: DictValAdapter, EA: DictAugAdapter> DictAugDataBuilder<'a, VA, EA>
+where
+    EA::ValType: Clone,
+{
+    pub fn new(
+        key_bits_len: usize,
+        mut keys_sorted: Vec<BigUint>,
+        values_sorted: &'a [&'a (VA::ValType, EA::ValType)],
+    ) -> Result<Self, TonCoreError> {
+        prepare_keys(&mut keys_sorted, key_bits_len)?;
+        let builder = DictAugDataBuilder {
+            keys_sorted,
+            values_sorted,
+            key_bits_len_left: key_bits_len,
+            _phantom: PhantomData,
+        };
+        Ok(builder)
+    }
 
-    // expect label with leading one
-    fn store_label(&self, builder: &mut CellBuilder, label: &BigUint) -> Result<(), TonCoreError> {
-        assert!(label.bits() > 0);
-        if label.is_one() {
-            // it's leading bit => label_type == short, len == 0 => store [false, false]
-            builder.write_num(&0, 2)?;
-            return Ok(());
+    // returns root cell and aggregated extra of the whole dict
+    pub fn build(mut self) -> Result<(TonCell, EA::ValType), TonCoreError> {
+        if self.keys_sorted.is_empty() {
+            bail_ton_core_data!("empty HashmapAug can't be written");
         }
-        let all_bits_same = all_bits_same(label);
-
-        let label_len = label.bits() as usize - 1;
-        let label_len_len = (self.key_bits_len_left as f32 + 1.0).log2().ceil() as usize;
-        let fair_label = remove_leading_bit(label);
-        let same_label_len = if all_bits_same { 3 + label_len_len } else { usize::MAX };
-        let short_label_len = 2 + label_len * 2;
-        let long_label_len = 2 + label_len_len + label_len;
-
-        let mut label_type = DictLabelType::Short;
-        if long_label_len < short_label_len {
-            label_type = DictLabelType::Long;
+        let mut keys = vec![];
+        swap(&mut self.keys_sorted, &mut keys);
+
+        let mut builder = TonCell::builder();
+        let keys = keys.into_iter().enumerate().collect();
+        let extra = self.fill_cell(&mut builder, keys)?;
+        Ok((builder.build()?, extra))
+    }
+
+    fn fill_cell(
+        &mut self,
+        builder: &mut CellBuilder,
+        keys: Vec<(usize, BigUint)>,
+    ) -> Result<EA::ValType, TonCoreError> {
+        if keys.len() == 1 {
+            // ahmn_leaf#_ extra:Y value:X
+            let (orig_key_pos, remaining_key) = &keys[0];
+            let (value, extra) = self.values_sorted[*orig_key_pos];
+            store_label(builder, remaining_key, self.key_bits_len_left)?;
+            EA::write(builder, extra)?;
+            VA::write(builder, value)?;
+            return Ok(extra.clone());
         }
-        if same_label_len < short_label_len {
-            label_type = DictLabelType::Same;
+
+        let key_len_bits_left_original = self.key_bits_len_left;
+
+        let (label, common_prefix_len, left_keys, right_keys) = split_keys(keys);
+        store_label(builder, &label, self.key_bits_len_left)?;
+
+        // ahmn_fork#_ left:^ right:^ extra:Y
+        self.key_bits_len_left -= common_prefix_len + 1;
+        let mut left_builder = TonCell::builder();
+        let left_extra = self.fill_cell(&mut left_builder, left_keys)?;
+        builder.write_ref(left_builder.build()?)?;
+
+        let mut right_builder = TonCell::builder();
+        let right_extra = self.fill_cell(&mut right_builder, right_keys)?;
+        builder.write_ref(right_builder.build()?)?;
+
+        let extra = EA::aggregate(&left_extra, &right_extra)?;
+        EA::write(builder, &extra)?;
+
+        self.key_bits_len_left = key_len_bits_left_original;
+        Ok(extra)
+    }
+}
+
+// expect label with leading one
+fn store_label(builder: &mut CellBuilder, label: &BigUint, key_bits_len_left: usize) -> Result<(), TonCoreError> {
+    assert!(label.bits() > 0);
+    if label.is_one() {
+        // it's leading bit => label_type == short, len == 0 => store [false, false]
+        builder.write_num(&0, 2)?;
+        return Ok(());
+    }
+    let all_bits_same = all_bits_same(label);
+
+    let label_len = label.bits() as usize - 1;
+    let label_len_len = (key_bits_len_left as f32 + 1.0).log2().ceil() as usize;
+    let fair_label = remove_leading_bit(label);
+    let same_label_len = if all_bits_same { 3 + label_len_len } else { usize::MAX };
+    let short_label_len = 2 + label_len * 2;
+    let long_label_len = 2 + label_len_len + label_len;
+
+    let mut label_type = DictLabelType::Short;
+    if long_label_len < short_label_len {
+        label_type = DictLabelType::Long;
+    }
+    if same_label_len < short_label_len {
+        label_type = DictLabelType::Same;
+    }
+    match label_type {
+        DictLabelType::Same => {
+            builder.write_bit(true)?;
+            builder.write_bit(true)?;
+            builder.write_bit(!fair_label.is_zero())?;
+            builder.write_num(&label_len, label_len_len)?;
         }
-        match label_type {
-            DictLabelType::Same => {
-                builder.write_bit(true)?;
-                builder.write_bit(true)?;
-                builder.write_bit(!fair_label.is_zero())?;
-                builder.write_num(&label_len, label_len_len)?;
-            }
-            DictLabelType::Short => {
-                builder.write_bit(false)?;
-                let unary_len = UnaryLen(label_len);
-                unary_len.write(builder)?;
-                builder.write_num(&fair_label, label_len)?;
-            }
-            DictLabelType::Long => {
-                builder.write_bit(true)?;
-                builder.write_bit(false)?;
-                builder.write_num(&label_len, label_len_len)?;
-                builder.write_num(&fair_label, label_len)?;
-            }
+        DictLabelType::Short => {
+            builder.write_bit(false)?;
+            let unary_len = UnaryLen(label_len);
+            unary_len.write(builder)?;
+            builder.write_num(&fair_label, label_len)?;
+        }
+        DictLabelType::Long => {
+            builder.write_bit(true)?;
+            builder.write_bit(false)?;
+            builder.write_num(&label_len, label_len_len)?;
+            builder.write_num(&fair_label, label_len)?;
+        }
+    }
+    Ok(())
+}
+
+// keys: Vec<(original_key_position, remaining_key_part)>, at least 2 keys
+// returns (label, common_prefix_len, left_keys, right_keys)
+type KeysSplit = (BigUint, usize, Vec<(usize, BigUint)>, Vec<(usize, BigUint)>);
+fn split_keys(keys: Vec<(usize, BigUint)>) -> KeysSplit {
+    let key = &keys[0].1;
+    let key_len = key.bits() as usize; // includes leading bit
+
+    let common_prefix_len = common_prefix_len(key, &keys.last().unwrap().1);
+    let label = {
+        let ignored_suffix_len = key_len - common_prefix_len - 1;
+        key >> ignored_suffix_len
+    };
+
+    let mut left_keys = Vec::with_capacity(keys.len() / 2);
+    let mut right_keys = Vec::with_capacity(keys.len() / 2);
+
+    let new_key_len = key_len - common_prefix_len - 1;
+    let new_key_mask = (BigUint::one() << new_key_len) - 1u32;
+    for (pos, key) in keys {
+        let new_key = key & new_key_mask.clone();
+        let is_right = new_key.bits() as usize == new_key_len;
+        let new_key_internal = add_leading_bit(&new_key, new_key_len - 1);
+        if is_right {
+            right_keys.push((pos, new_key_internal));
+        } else {
+            left_keys.push((pos, new_key_internal));
         }
-        Ok(())
     }
+    (label, common_prefix_len, left_keys, right_keys)
 }
 
 fn prepare_keys(keys: &mut [BigUint], key_bits_len: usize) -> Result<(), TonCoreError> {
@@ -1,14 +1,20 @@
 use std::collections::HashMap;
 
 use super::label_type::DictLabelType;
-use crate::tlb_adapters::DictValAdapter;
+use crate::tlb_adapters::{AugDictRead, DictValAdapter};
 use num_bigint::BigUint;
 use num_traits::One;
-use ton_core::cell::CellParser;
+use ton_core::cell::{CellParser, TonCell};
 use ton_core::errors::TonCoreError;
 use ton_core::traits::tlb::TLB;
 use ton_core::types::tlb_core::adapters::UnaryLen;
 
+pub(super) enum DictNode<V> {
+    Leaf(BigUint, V),
+    // key prefix (with leading 1), left & right subtrees
+    Fork(BigUint, TonCell, TonCell),
+}
+
 pub struct DictDataParser {
     key_bits_len: usize,
     cur_key_prefix: BigUint, // store leading 1 to determinate len properly
@@ -42,6 +48,129 @@ impl DictDataParser {
         // will rollback prefix to original value at the end of the function
         let origin_key_prefix_len = self.cur_key_prefix.bits();
 
+        self.read_label(parser)?;
+        if self.cur_key_prefix.bits() as usize == (self.key_bits_len + 1) {
+            let mut key = BigUint::one() << self.key_bits_len;
+            key ^= &self.cur_key_prefix;
+            dst.insert(key, VA::read(parser)?);
+        } else {
+            let left_ref = parser.read_next_ref()?;
+            self.cur_key_prefix <<= 1;
+            self.parse_impl::<VA>(&mut left_ref.parser(), dst)?;
+
+            let right_ref = parser.read_next_ref()?;
+            self.cur_key_prefix += BigUint::one();
+            self.parse_impl::<VA>(&mut right_ref.parser(), dst)?;
+        }
+        self.cur_key_prefix >>= self.cur_key_prefix.bits() - origin_key_prefix_len;
+        Ok(())
+    }
+
+    // reads single node located at key_prefix, doesn't go deeper
+    pub(super) fn read_node<VA: DictValAdapter>(
+        &mut self,
+        parser: &mut CellParser,
+        key_prefix: BigUint,
+    ) -> Result<DictNode<VA::ValType>, TonCoreError> {
+        self.cur_key_prefix = key_prefix;
+        self.read_label(parser)?;
+        if self.cur_key_prefix.bits() as usize == (self.key_bits_len + 1) {
+            let mut key = BigUint::one() << self.key_bits_len;
+            key ^= &self.cur_key_prefix;
+            return Ok(DictNode::Leaf(key, VA::read(parser)?));
+        }
+        let left = parser.read_next_ref()?.clone();
+        let right = parser.read_next_ref()?.clone();
+        Ok(DictNode::Fork(self.cur_key_prefix.clone(), left, right))
+    }
+
+    // descends to the key only, skipping other branches
+    pub fn read_by_key<VA: DictValAdapter>(
+        &mut self,
+        parser: &mut CellParser,
+        key: &BigUint,
+    ) -> Result<Option<VA::ValType>, TonCoreError> {
+        self.cur_key_prefix = BigUint::one();
+        let target = (BigUint::one() << self.key_bits_len) | key;
+        self.find_impl::<VA>(parser, &target)
+    }
+
+    fn find_impl<VA: DictValAdapter>(
+        &mut self,
+        parser: &mut CellParser,
+        target: &BigUint,
+    ) -> Result<Option<VA::ValType>, TonCoreError> {
+        self.read_label(parser)?;
+        let prefix_len = self.cur_key_prefix.bits();
+        let full_len = self.key_bits_len as u64 + 1;
+        if prefix_len > full_len || (target >> (full_len - prefix_len)) != self.cur_key_prefix {
+            return Ok(None);
+        }
+        if prefix_len == full_len {
+            return Ok(Some(VA::read(parser)?));
+        }
+        let go_right = target.bit(full_len - prefix_len - 1);
+        if go_right {
+            parser.read_next_ref()?;
+        }
+        let next_ref = parser.read_next_ref()?;
+        self.cur_key_prefix <<= 1;
+        if go_right {
+            self.cur_key_prefix += BigUint::one();
+        }
+        self.find_impl::<VA>(&mut next_ref.parser(), target)
+    }
+
+    pub fn read_aug<VA: DictValAdapter, EA: DictValAdapter>(
+        &mut self,
+        parser: &mut CellParser,
+    ) -> Result<AugDictRead<BigUint, VA::ValType, EA::ValType>, TonCoreError>
+    where
+        EA::ValType: Clone,
+    {
+        self.cur_key_prefix = BigUint::one();
+
+        let mut result = HashMap::new();
+        let root_extra = self.parse_aug_impl::<VA, EA>(parser, &mut result)?;
+        Ok((result, root_extra))
+    }
+
+    // returns extra of the node
+    fn parse_aug_impl<VA: DictValAdapter, EA: DictValAdapter>(
+        &mut self,
+        parser: &mut CellParser,
+        dst: &mut HashMap<BigUint, (VA::ValType, EA::ValType)>,
+    ) -> Result<EA::ValType, TonCoreError>
+    where
+        EA::ValType: Clone,
+    {
+        let origin_key_prefix_len = self.cur_key_prefix.bits();
+
+        self.read_label(parser)?;
+        let node_extra = if self.cur_key_prefix.bits() as usize == (self.key_bits_len + 1) {
+            let mut key = BigUint::one() << self.key_bits_len;
+            key ^= &self.cur_key_prefix;
+            // ahmn_leaf#_ extra:Y value:X
+            let extra = EA::read(parser)?;
+            let value = VA::read(parser)?;
+            dst.insert(key, (value, extra.clone()));
+            extra
+        } else {
+            // ahmn_fork#_ left:^ right:^ extra:Y
+            let left_ref = parser.read_next_ref()?;
+            self.cur_key_prefix <<= 1;
+            self.parse_aug_impl::<VA, EA>(&mut left_ref.parser(), dst)?;
+
+            let right_ref = parser.read_next_ref()?;
+            self.cur_key_prefix += BigUint::one();
+            self.parse_aug_impl::<VA, EA>(&mut right_ref.parser(), dst)?;
+            EA::read(parser)?
+        };
+        self.cur_key_prefix >>= self.cur_key_prefix.bits() - origin_key_prefix_len;
+        Ok(node_extra)
+    }
+
+    fn read_label(&mut self, parser: &mut CellParser) -> Result<(), TonCoreError> {
         let label_type = self.detect_label_type(parser)?;
         match label_type {
             DictLabelType::Same => {
@@ -74,20 +203,6 @@ impl DictDataParser {
                 }
             }
         }
-        if self.cur_key_prefix.bits() as usize == (self.key_bits_len + 1) {
-            let mut key = BigUint::one() << self.key_bits_len;
-            key ^= &self.cur_key_prefix;
-            dst.insert(key, VA::read(parser)?);
-        } else {
-            let left_ref = parser.read_next_ref()?;
-            self.cur_key_prefix <<= 1;
-            self.parse_impl::<VA>(&mut left_ref.parser(), dst)?;
-
-            let right_ref = parser.read_next_ref()?;
-            self.cur_key_prefix += BigUint::one();
-            self.parse_impl::<VA>(&mut right_ref.parser(), dst)?;
-        }
-        self.cur_key_prefix >>= self.cur_key_prefix.bits() - origin_key_prefix_len;
         Ok(())
     }
 
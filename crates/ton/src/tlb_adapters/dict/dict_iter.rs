@@ -0,0 +1,59 @@
+use crate::tlb_adapters::dict::data_parser::{DictDataParser, DictNode};
+use crate::tlb_adapters::{DictKeyAdapter, DictValAdapter};
+use num_bigint::BigUint;
+use num_traits::One;
+use std::marker::PhantomData;
+use ton_core::cell::{CellParser, TonCell};
+use ton_core::errors::TonCoreError;
+
+/// Lazy dict traversal, yields entries in ascending order of dict key (unsigned bits)
+///
+/// Use `TLBHashMap::iter` for creation. Stops after the first error
+pub struct DictIter<'a, 'b, KA: DictKeyAdapter, VA: DictValAdapter> {
+    root: Option<&'a mut CellParser<'b>>,
+    forks: Vec<(BigUint, TonCell)>, // pending subtrees with their key prefixes
+    data_parser: DictDataParser,
+    _phantom: PhantomData<(KA, VA)>,
+}
+
+impl<'a, 'b, KA: DictKeyAdapter, VA: DictValAdapter> DictIter<'a, 'b, KA, VA> {
+    pub(super) fn new(parser: &'a mut CellParser<'b>, key_bits_len: usize) -> Self {
+        Self {
+            root: Some(parser),
+            forks: vec![],
+            data_parser: DictDataParser::new(key_bits_len),
+            _phantom: PhantomData,
+        }
+    }
+}
+
+impl<KA: DictKeyAdapter, VA: DictValAdapter> Iterator for DictIter<'_, '_, KA, VA> {
+    type Item = Result<(KA::KeyType, VA::ValType), TonCoreError>;
+
+    fn next(&mut self) -> Option<Self::Item> {
+        loop {
+            let node = match self.root.take() {
+                Some(parser) => self.data_parser.read_node::<VA>(parser, BigUint::one()),
+                None => {
+                    let (key_prefix, cell) = self.forks.pop()?;
+                    self.data_parser.read_node::<VA>(&mut cell.parser(), key_prefix)
+                }
+            };
+            match node {
+                Ok(DictNode::Leaf(key, value)) => {
+                    return Some(KA::extract_key(&key).map(|key| (key, value)).map_err(Into::into));
+                }
+                Ok(DictNode::Fork(key_prefix, left, right)) => {
+                    let left_prefix: BigUint = key_prefix << 1;
+                    let right_prefix = left_prefix.clone() + BigUint::one();
+                    self.forks.push((right_prefix, right));
+                    self.forks.push((left_prefix, left));
+                }
+                Err(err) => {
+                    self.forks.clear();
+                    return Some(Err(err));
+                }
+            }
+        }
+    }
+}
@@ -20,7 +20,9 @@ pub struct DictKeyAdapterUint<T>(PhantomData<T>);
 pub struct DictKeyAdapterInt<const KEY_BITS_LEN: usize, T>(PhantomData<T>);
 pub struct DictKeyAdapterMsgAddress;
 pub struct DictKeyAdapterTonAddress;
-pub struct DictKeyAdapterString; // TODO is not covered by tests
+pub struct DictKeyAdapterString;
+
+const STRING_KEY_MARKER: u8 = 0x01;
 
 impl DictKeyAdapter for DictKeyAdapterTonHash {
     type KeyType = TonHash;
@@ -122,15 +124,23 @@ where
     }
 }
 
+// Key is big-endian string bytes with leading 0x01 marker byte,
+// so leading/trailing zero bytes survive round-trip. Requires key_bits_len >= 8 * (len + 1)
 impl DictKeyAdapter for DictKeyAdapterString {
     type KeyType = String;
     fn make_key(src_key: &String) -> Result<BigUint, TonError> {
-        let bytes = src_key.as_bytes();
-        Ok(BigUint::from_bytes_le(bytes))
+        let mut bytes = Vec::with_capacity(src_key.len() + 1);
+        bytes.push(STRING_KEY_MARKER);
+        bytes.extend_from_slice(src_key.as_bytes());
+        Ok(BigUint::from_bytes_be(&bytes))
     }
 
     fn extract_key(dict_key: &BigUint) -> Result<String, TonError> {
-        let bytes = dict_key.to_bytes_le();
+        let mut bytes = dict_key.to_bytes_be();
+        if bytes.first() != Some(&STRING_KEY_MARKER) {
+            bail_ton!("fail to extract string dict key: no marker byte in {dict_key:x}");
+        }
+        bytes.remove(0);
         Ok(String::from_utf8(bytes)?)
     }
 }
@@ -138,7 +148,9 @@ impl DictKeyAdapter for DictKeyAdapterString {
 #[cfg(test)]
 mod tests {
     use super::*;
-    use crate::tlb_adapters::{DictValAdapterTLB, TLBHashMap};
+    use crate::tlb_adapters::dict::test_fixtures::SIGNED_KEY_DICT_BOC_HEX;
+    use crate::tlb_adapters::{DictValAdapterNum, DictValAdapterTLB, TLBHashMap};
+    use std::collections::HashMap;
     use std::str::FromStr;
 
     #[test]
@@ -176,10 +188,31 @@ mod tests {
         Ok(())
     }
 
+    #[test]
+    fn test_dict_key_adapter_string() -> anyhow::Result<()> {
+        for val in ["", "a", "a\0", "\0a", "\0", "привет, 世界 🦀"] {
+            let dict_key = DictKeyAdapterString::make_key(&val.to_string())?;
+            assert_eq!(DictKeyAdapterString::extract_key(&dict_key)?, val);
+        }
+        assert!(DictKeyAdapterString::extract_key(&BigUint::from(0x61u32)).is_err());
+
+        let data = HashMap::from([
+            ("".to_string(), 1u8),
+            ("a\0".to_string(), 2),
+            ("a".to_string(), 3),
+            ("ключ".to_string(), 4),
+        ]);
+        let adapter = TLBHashMap::<DictKeyAdapterString, DictValAdapterNum<u8, 8>>::new(128);
+        let mut builder = TonCell::builder();
+        adapter.write(&mut builder, &data)?;
+        let parsed = adapter.read(&mut builder.build()?.parser())?;
+        assert_eq!(parsed, data);
+        Ok(())
+    }
+
     #[test]
     fn test_dict_key_adapter_signed_key_parse_tlb() -> anyhow::Result<()> {
-        let dict_hex = "b5ee9c72010207010001e600020120010200e7ae3626d0000000000000000000000000000000000000000000000000000046ec8cd22d8bffffffffffffffffffffb913732dd27800000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000002020277030400e7a69d930000000000000000000000000000000000000000000000000000046ec8cd22d880000000000000000000046ec8cd22d8800000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000002002038ddc050600e5b14500000000000000000000000000000000000000000000000000001c0c3e8aba24400000000000000000001c0c3e8aba24400000000000000000000000000000000002f23e52bc009da2a23b462da0fa694000000000000000000000000000000000000e3288b94abb3942fb8a96aeac2fe000e5b2b800000000000000000000000000000000000000000000000000001c0c3e8aba247fffffffffffffffffffe3f3c17545dbc000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000020";
-        let dict_cell = TonCell::from_boc_hex(dict_hex)?;
+        let dict_cell = TonCell::from_boc_hex(SIGNED_KEY_DICT_BOC_HEX)?;
         let dict = TLBHashMap::<DictKeyAdapterInt<24, i32>, DictValAdapterTLB<TonCell>>::new(24)
             .read(&mut dict_cell.parser())?;
         assert_eq!(dict.len(), 4);
@@ -190,7 +223,20 @@ mod tests {
         let mut builder = TonCell::builder();
         TLBHashMap::<DictKeyAdapterInt<24, i32>, DictValAdapterTLB<TonCell>>::new(24).write(&mut builder, &dict)?;
         let serialized = builder.build()?.to_boc_hex()?;
-        assert_eq!(dict_hex, serialized);
+        assert_eq!(SIGNED_KEY_DICT_BOC_HEX, serialized);
+        Ok(())
+    }
+
+    #[test]
+    fn test_dict_key_adapter_signed_key_sorted_roundtrip() -> anyhow::Result<()> {
+        let dict_cell = TonCell::from_boc_hex(SIGNED_KEY_DICT_BOC_HEX)?;
+        let adapter = TLBHashMap::<DictKeyAdapterInt<24, i32>, DictValAdapterTLB<TonCell>>::new(24);
+        let dict = adapter.read_sorted(&mut dict_cell.parser())?;
+        assert_eq!(dict.keys().copied().collect::<Vec<_>>(), vec![-887220, -39660, -34080, 887220]);
+
+        let mut builder = TonCell::builder();
+        adapter.write_sorted(&mut builder, &dict)?;
+        assert_eq!(SIGNED_KEY_DICT_BOC_HEX, builder.build()?.to_boc_hex()?);
         Ok(())
     }
 }
@@ -1,3 +1,5 @@
+use num_traits::CheckedAdd;
+use ton_core::bail_ton_core_data;
 use ton_core::cell::CellBuilder;
 use ton_core::cell::CellParser;
 use ton_core::cell::TonCellNum;
@@ -10,6 +12,13 @@ pub trait DictValAdapter {
     fn read(parser: &mut CellParser) -> Result<Self::ValType, TonCoreError>;
 }
 
+// extra value of HashmapAug: fork nodes store aggregation of children extras
+pub trait DictAugAdapter: DictValAdapter {
+    // extra of empty dict
+    fn empty() -> Self::ValType;
+    fn aggregate(left: &Self::ValType, right: &Self::ValType) -> Result<Self::ValType, TonCoreError>;
+}
+
 pub struct DictValAdapterTLB<T: TLB>(std::marker::PhantomData<T>);
 pub struct DictValAdapterNum<T, const BITS_LEN: usize>(std::marker::PhantomData<T>);
 
@@ -24,3 +33,14 @@ impl<T: TonCellNum, const BITS_LEN: usize> DictValAdapter for DictValAdapterNum<
     fn write(builder: &mut CellBuilder, val: &T) -> Result<(), TonCoreError> { builder.write_num(val, BITS_LEN) }
     fn read(parser: &mut CellParser) -> Result<T, TonCoreError> { parser.read_num(BITS_LEN) }
 }
+
+// numeric extras are aggregated as sum
+impl<T: TonCellNum + CheckedAdd, const BITS_LEN: usize> DictAugAdapter for DictValAdapterNum<T, BITS_LEN> {
+    fn empty() -> T { T::zero() }
+    fn aggregate(left: &T, right: &T) -> Result<T, TonCoreError> {
+        match left.checked_add(right) {
+            Some(sum) => Ok(sum),
+            None => bail_ton_core_data!("HashmapAug extra overflow on aggregation"),
+        }
+    }
+}
@@ -1,8 +1,8 @@
 use crate::errors::TonError;
 use crate::tlb_adapters::dict::data_builder::DictDataBuilder;
 use crate::tlb_adapters::dict::data_parser::DictDataParser;
-use crate::tlb_adapters::{DictKeyAdapter, DictValAdapter};
-use std::collections::HashMap;
+use crate::tlb_adapters::{DictIter, DictKeyAdapter, DictValAdapter};
+use std::collections::{BTreeMap, HashMap};
 use std::hash::Hash;
 use std::marker::PhantomData;
 use ton_core::bail_ton_core_data;
@@ -42,16 +42,56 @@ where
         Ok(data)
     }
 
+    /// Walks the dict lazily, without building the whole map
+    pub fn iter<'a, 'b>(&self, parser: &'a mut CellParser<'b>) -> DictIter<'a, 'b, KA, VA> {
+        DictIter::new(parser, self.key_bits_len as usize)
+    }
+
+    /// Reads only the branch leading to `key`. Returns None if the key is absent
+    pub fn get_by_key(&self, parser: &mut CellParser, key: &KA::KeyType) -> Result<Option<VA::ValType>, TonCoreError> {
+        let dict_key = KA::make_key(key)?;
+        DictDataParser::new(self.key_bits_len as usize).read_by_key::<VA>(parser, &dict_key)
+    }
+
+    // same as read, but keeps keys ordered
+    pub fn read_sorted(&self, parser: &mut CellParser) -> Result<BTreeMap<KA::KeyType, VA::ValType>, TonCoreError>
+    where
+        KA::KeyType: Ord,
+    {
+        Ok(self.read(parser)?.into_iter().collect())
+    }
+
     pub fn write(
         &self,
         builder: &mut CellBuilder,
         data: &HashMap<KA::KeyType, VA::ValType>,
     ) -> Result<(), TonCoreError> {
-        if data.is_empty() {
+        self.write_iter(builder, data.iter())
+    }
+
+    // output is the same as for write with HashMap: entries are sorted by dict key anyway
+    pub fn write_sorted(
+        &self,
+        builder: &mut CellBuilder,
+        data: &BTreeMap<KA::KeyType, VA::ValType>,
+    ) -> Result<(), TonCoreError> {
+        self.write_iter(builder, data.iter())
+    }
+
+    fn write_iter<'a>(
+        &self,
+        builder: &mut CellBuilder,
+        data: impl ExactSizeIterator<Item = (&'a KA::KeyType, &'a VA::ValType)>,
+    ) -> Result<(), TonCoreError>
+    where
+        KA::KeyType: 'a,
+        VA::ValType: 'a,
+    {
+        if data.len() == 0 {
             bail_ton_core_data!("empty HashMap can't be written");
         }
         let mut key_value_pairs =
-            data.iter().map(|(k, v)| Ok::<_, TonError>((KA::make_key(k)?, v))).collect::<Result<Vec<_>, _>>()?;
+            data.map(|(k, v)| Ok::<_, TonError>((KA::make_key(k)?, v))).collect::<Result<Vec<_>, _>>()?;
         key_value_pairs.sort_by_key(|(x, _)| x.clone());
 
         let mut keys_sorted = Vec::with_capacity(key_value_pairs.len());
@@ -65,3 +105,55 @@ where
         builder.write_cell(&dict_data_cell)
     }
 }
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::tlb_adapters::dict::test_fixtures::SIGNED_KEY_DICT_BOC_HEX;
+    use crate::tlb_adapters::{DictKeyAdapterInt, DictKeyAdapterUint, DictValAdapterNum, DictValAdapterTLB};
+    use ton_core::cell::TonCell;
+    use ton_core::traits::tlb::TLB;
+
+    #[test]
+    fn test_tlb_hash_map_lazy_signed_key() -> anyhow::Result<()> {
+        let dict_cell = TonCell::from_boc_hex(SIGNED_KEY_DICT_BOC_HEX)?;
+        let adapter = TLBHashMap::<DictKeyAdapterInt<24, i32>, DictValAdapterTLB<TonCell>>::new(24);
+        let expected = adapter.read(&mut dict_cell.parser())?;
+
+        let value = adapter.get_by_key(&mut dict_cell.parser(), &-39660)?;
+        assert_eq!(value.as_ref(), expected.get(&-39660));
+        assert!(value.is_some());
+        assert_eq!(adapter.get_by_key(&mut dict_cell.parser(), &-39661)?, None);
+        assert_eq!(adapter.get_by_key(&mut dict_cell.parser(), &0)?, None);
+
+        let mut parser = dict_cell.parser();
+        let found = adapter.iter(&mut parser).find(|entry| matches!(entry, Ok((key, _)) if *key == -34080));
+        let (_, value) = found.transpose()?.unwrap();
+        assert_eq!(Some(&value), expected.get(&-34080));
+
+        // ordered by unsigned dict key: positive keys go first
+        let entries = adapter.iter(&mut dict_cell.parser()).collect::<Result<Vec<_>, _>>()?;
+        let keys = entries.iter().map(|(key, _)| *key).collect::<Vec<_>>();
+        assert_eq!(keys, vec![887220, -887220, -39660, -34080]);
+        assert_eq!(entries.into_iter().collect::<HashMap<_, _>>(), expected);
+        Ok(())
+    }
+
+    #[test]
+    fn test_tlb_hash_map_lazy_single_entry() -> anyhow::Result<()> {
+        let adapter = TLBHashMap::<DictKeyAdapterUint<u32>, DictValAdapterNum<u16, 16>>::new(32);
+        let mut builder = TonCell::builder();
+        adapter.write(&mut builder, &HashMap::from([(7u32, 42u16)]))?;
+        let cell = builder.build()?;
+        assert_eq!(adapter.get_by_key(&mut cell.parser(), &7)?, Some(42));
+        assert_eq!(adapter.get_by_key(&mut cell.parser(), &8)?, None);
+        assert_eq!(adapter.iter(&mut cell.parser()).collect::<Result<Vec<_>, _>>()?, vec![(7, 42)]);
+
+        let not_dict = TonCell::empty();
+        let mut parser = not_dict.parser();
+        let mut iter = adapter.iter(&mut parser);
+        assert!(iter.next().unwrap().is_err());
+        assert!(iter.next().is_none());
+        Ok(())
+    }
+}
@@ -0,0 +1,181 @@
+use crate::tlb_adapters::dict::data_builder::DictAugDataBuilder;
+use crate::tlb_adapters::dict::data_parser::DictDataParser;
+use crate::tlb_adapters::{DictAugAdapter, DictKeyAdapter, DictValAdapter};
+use std::collections::HashMap;
+use std::hash::Hash;
+use std::marker::PhantomData;
+use ton_core::cell::CellBuilder;
+use ton_core::cell::CellParser;
+use ton_core::cell::TonCell;
+use ton_core::errors::TonCoreError;
+
+pub type AugDictData<K, V, E> = HashMap<K, (V, E)>;
+// leaves data and root extra
+pub type AugDictRead<K, V, E> = (AugDictData<K, V, E>, E);
+type AugReadResult<KA, VA, EA> = Result<
+    AugDictRead<<KA as DictKeyAdapter>::KeyType, <VA as DictValAdapter>::ValType, <EA as DictValAdapter>::ValType>,
+    TonCoreError,
+>;
+
+// https://github.com/ton-blockchain/ton/blob/050a984163a53df16fb03f66cc445c34bfed48ed/crypto/block/block.tlb (HashmapAug)
+/// Adapter for HashmapAug: each leaf carries (value, extra), each fork carries aggregated extra of its children
+/// `read` returns leaves data and root extra, `write` returns calculated root extra
+/// Doesn't write 'present' marker to root cell, use TLBHashMapAugE for it
+pub struct TLBHashMapAug<KA: DictKeyAdapter, VA: DictValAdapter, EA: DictAugAdapter> {
+    key_bits_len: u32,
+    _phantom: PhantomData<(KA, VA, EA)>,
+}
+
+impl<KA, VA, EA> TLBHashMapAug<KA, VA, EA>
+where
+    KA: DictKeyAdapter,
+    VA: DictValAdapter,
+    EA: DictAugAdapter,
+    KA::KeyType: Eq + Hash,
+    EA::ValType: Clone,
+{
+    pub fn new(key_bits_len: u32) -> Self {
+        Self {
+            key_bits_len,
+            _phantom: PhantomData,
+        }
+    }
+
+    pub fn read(&self, parser: &mut CellParser) -> AugReadResult<KA, VA, EA> {
+        let mut data_parser = DictDataParser::new(self.key_bits_len as usize);
+        let (data_raw, root_extra) = data_parser.read_aug::<VA, EA>(parser)?;
+        let data = data_raw
+            .into_iter()
+            .map(|(k, v)| Ok::<_, TonCoreError>((KA::extract_key(&k)?, v)))
+            .collect::<Result<HashMap<_, _>, _>>()?;
+        Ok((data, root_extra))
+    }
+
+    pub fn write(
+        &self,
+        builder: &mut CellBuilder,
+        data: &AugDictData<KA::KeyType, VA::ValType, EA::ValType>,
+    ) -> Result<EA::ValType, TonCoreError> {
+        let mut key_value_pairs =
+            data.iter().map(|(k, v)| Ok::<_, TonCoreError>((KA::make_key(k)?, v))).collect::<Result<Vec<_>, _>>()?;
+        key_value_pairs.sort_by_key(|(x, _)| x.clone());
+
+        let (keys_sorted, values_sorted): (Vec<_>, Vec<_>) = key_value_pairs.into_iter().unzip();
+        let data_builder = DictAugDataBuilder::<VA, EA>::new(self.key_bits_len as usize, keys_sorted, &values_sorted)?;
+        let (dict_data_cell, root_extra) = data_builder.build()?;
+        builder.write_cell(&dict_data_cell)?;
+        Ok(root_extra)
+    }
+}
+
+// https://github.com/ton-blockchain/ton/blob/050a984163a53df16fb03f66cc445c34bfed48ed/crypto/block/block.tlb (HashmapAugE)
+/// HashmapAugE: present marker (0|1 bit), dict data in first ref (if present), then root extra
+pub struct TLBHashMapAugE<KA: DictKeyAdapter, VA: DictValAdapter, EA: DictAugAdapter>(TLBHashMapAug<KA, VA, EA>);
+
+impl<KA, VA, EA> TLBHashMapAugE<KA, VA, EA>
+where
+    KA: DictKeyAdapter,
+    VA: DictValAdapter,
+    EA: DictAugAdapter,
+    KA::KeyType: Eq + Hash,
+    EA::ValType: Clone,
+{
+    pub fn new(key_bits_len: u32) -> Self { Self(TLBHashMapAug::new(key_bits_len)) }
+
+    pub fn read(&self, parser: &mut CellParser) -> AugReadResult<KA, VA, EA> {
+        if !parser.read_bit()? {
+            return Ok((HashMap::new(), EA::read(parser)?));
+        }
+        let (data, _) = self.0.read(&mut parser.read_next_ref()?.parser())?;
+        Ok((data, EA::read(parser)?))
+    }
+
+    pub fn write(
+        &self,
+        builder: &mut CellBuilder,
+        data: &AugDictData<KA::KeyType, VA::ValType, EA::ValType>,
+    ) -> Result<EA::ValType, TonCoreError> {
+        if data.is_empty() {
+            let extra = EA::empty();
+            builder.write_bit(false)?;
+            EA::write(builder, &extra)?;
+            return Ok(extra);
+        }
+        builder.write_bit(true)?;
+        let mut dict_data_builder = TonCell::builder();
+        let extra = self.0.write(&mut dict_data_builder, data)?;
+        builder.write_ref(dict_data_builder.build()?)?;
+        EA::write(builder, &extra)?;
+        Ok(extra)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::tlb_adapters::{DictKeyAdapterUint, DictValAdapterNum};
+    use ton_core::traits::tlb::TLB;
+
+    type TestAugDict = TLBHashMapAug<DictKeyAdapterUint<u8>, DictValAdapterNum<u32, 32>, DictValAdapterNum<u64, 64>>;
+    type TestAugDictE = TLBHashMapAugE<DictKeyAdapterUint<u8>, DictValAdapterNum<u32, 32>, DictValAdapterNum<u64, 64>>;
+
+    // keys 1 (0b00000001) and 2 (0b00000010) share 6-bit prefix
+    fn make_aug_dict_cell() -> anyhow::Result<TonCell> {
+        let mut left = TonCell::builder();
+        left.write_bits([0b0101_0000], 4)?; // hml_short$0 len=1 (unary 10) label=1
+        left.write_num(&7u64, 64)?;
+        left.write_num(&100u32, 32)?;
+
+        let mut right = TonCell::builder();
+        right.write_bits([0b0100_0000], 4)?; // hml_short$0 len=1 (unary 10) label=0
+        right.write_num(&11u64, 64)?;
+        right.write_num(&200u32, 32)?;
+
+        let mut root = TonCell::builder();
+        root.write_bits([0b1100_1100], 7)?; // hml_same$11 v=0 n=6 (4 bits)
+        root.write_ref(left.build()?)?;
+        root.write_ref(right.build()?)?;
+        root.write_num(&18u64, 64)?;
+        Ok(root.build()?)
+    }
+
+    #[test]
+    fn test_tlb_hash_map_aug_read() -> anyhow::Result<()> {
+        let cell = TonCell::from_boc(make_aug_dict_cell()?.to_boc()?)?;
+        let (data, root_extra) = TestAugDict::new(8).read(&mut cell.parser())?;
+        assert_eq!(data, HashMap::from([(1u8, (100u32, 7u64)), (2, (200, 11))]));
+        assert_eq!(root_extra, 18);
+        Ok(())
+    }
+
+    #[test]
+    fn test_tlb_hash_map_aug_write() -> anyhow::Result<()> {
+        let data = HashMap::from([(1u8, (100u32, 7u64)), (2, (200, 11))]);
+        let mut builder = TonCell::builder();
+        let root_extra = TestAugDict::new(8).write(&mut builder, &data)?;
+        assert_eq!(root_extra, 18);
+        assert_eq!(builder.build()?, make_aug_dict_cell()?);
+
+        let data = HashMap::from([
+            (0u8, (1u32, 1u64)),
+            (3, (2, 2)),
+            (10, (3, 3)),
+            (127, (4, 4)),
+            (255, (5, 5)),
+        ]);
+        let mut builder = TonCell::builder();
+        TestAugDictE::new(8).write(&mut builder, &data)?;
+        let cell = builder.build()?;
+        let (parsed, root_extra) = TestAugDictE::new(8).read(&mut cell.parser())?;
+        assert_eq!(parsed, data);
+        assert_eq!(root_extra, 15);
+
+        let mut builder = TonCell::builder();
+        TestAugDictE::new(8).write(&mut builder, &HashMap::new())?;
+        let cell = builder.build()?;
+        let (parsed, root_extra) = TestAugDictE::new(8).read(&mut cell.parser())?;
+        assert!(parsed.is_empty());
+        assert_eq!(root_extra, 0);
+        Ok(())
+    }
+}
@@ -1,5 +1,5 @@
 use crate::tlb_adapters::{DictKeyAdapter, DictValAdapter, TLBHashMap};
-use std::collections::HashMap;
+use std::collections::{BTreeMap, HashMap};
 use std::hash::Hash;
 use ton_core::cell::CellBuilder;
 use ton_core::cell::CellParser;
@@ -26,6 +26,13 @@ where
         self.0.read(&mut parser.read_next_ref()?.parser())
     }
 
+    pub fn read_sorted(&self, parser: &mut CellParser) -> Result<BTreeMap<KA::KeyType, VA::ValType>, TonCoreError> {
+        if !parser.read_bit()? {
+            return Ok(BTreeMap::new());
+        }
+        self.0.read_sorted(&mut parser.read_next_ref()?.parser())
+    }
+
     pub fn write(
         &self,
         builder: &mut CellBuilder,
@@ -40,6 +47,21 @@ where
         self.0.write(&mut dict_data_builder, data)?;
         builder.write_ref(dict_data_builder.build()?)
     }
+
+    pub fn write_sorted(
+        &self,
+        builder: &mut CellBuilder,
+        data: &BTreeMap<KA::KeyType, VA::ValType>,
+    ) -> Result<(), TonCoreError> {
+        if data.is_empty() {
+            builder.write_bit(false)?;
+            return Ok(());
+        }
+        builder.write_bit(true)?;
+        let mut dict_data_builder = TonCell::builder();
+        self.0.write_sorted(&mut dict_data_builder, data)?;
+        builder.write_ref(dict_data_builder.build()?)
+    }
 }
 
 #[cfg(test)]
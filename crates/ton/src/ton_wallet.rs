@@ -1,14 +1,18 @@
 mod mnemonic;
+mod send_mode;
 mod wallet_code;
 mod wallet_constants;
 mod wallet_tlb;
+mod wallet_transfer;
 mod wallet_version;
 
 use ed25519_dalek::{Signer, SigningKey};
 pub use mnemonic::*;
+pub use send_mode::*;
 pub use wallet_code::*;
 pub use wallet_constants::*;
 pub use wallet_tlb::*;
+pub use wallet_transfer::*;
 pub use wallet_version::*;
 
 use super::*;
@@ -58,6 +62,10 @@ impl TonWallet {
         })
     }
 
+    pub fn create_v5(key_pair: KeyPair, workchain: i32, wallet_id: i32) -> Result<Self, TonError> {
+        Self::new_with_params(WalletVersion::V5R1, key_pair, workchain, wallet_id)
+    }
+
     pub fn create_ext_in_msg(
         &self,
         int_msgs: Vec<TonCell>,
@@ -71,21 +79,64 @@ impl TonWallet {
         Ok(external)
     }
 
+    // state_init is attached when seqno == 0, so the first external deploys the wallet
+    pub fn build_external(
+        &self,
+        messages: &[WalletTransfer],
+        seqno: u32,
+        valid_until: u32,
+    ) -> Result<TonCell, TonError> {
+        let signing_key = self.signing_key()?;
+        self.build_external_with_signer(messages, seqno, valid_until, |hash| signing_key.sign(hash).to_bytes())
+    }
+
+    /// Same as `build_external`, but signs with external signer (e.g. HSM): it gets body hash, returns signature
+    /// Secret key of the wallet is not used, so the wallet can be created with `KeyPair::from_public_key`
+    pub fn build_external_with_signer<F>(
+        &self,
+        messages: &[WalletTransfer],
+        seqno: u32,
+        valid_until: u32,
+        signer: F,
+    ) -> Result<TonCell, TonError>
+    where
+        F: Fn(&[u8; 32]) -> [u8; 64],
+    {
+        if messages.is_empty() || messages.len() > WALLET_MAX_TRANSFERS {
+            return Err(TonError::Custom(format!(
+                "wallet external must carry 1..={WALLET_MAX_TRANSFERS} messages, got {}",
+                messages.len()
+            )));
+        }
+        let msgs = messages.iter().map(WalletTransfer::to_int_msg).collect::<Result<Vec<_>, _>>()?;
+        let msgs_modes = messages.iter().map(|x| x.mode.as_u8()).collect();
+        let body = WalletVersion::build_ext_in_body_with_modes(
+            self.version,
+            valid_until,
+            seqno,
+            self.wallet_id,
+            msgs,
+            msgs_modes,
+        )?;
+        let signed = self.sign_ext_in_body_with_signer(&body, signer)?;
+        self.create_ext_in_msg_from_body(signed, seqno == 0)
+    }
+
     pub fn create_ext_in_body(&self, expire_at: u32, seqno: u32, int_msgs: Vec<TonCell>) -> Result<TonCell, TonError> {
         WalletVersion::build_ext_in_body(self.version, expire_at, seqno, self.wallet_id, int_msgs)
     }
 
     pub fn sign_ext_in_body(&self, ext_in_body: &TonCell) -> Result<TonCell, TonError> {
-        let msg_hash = ext_in_body.cell_hash()?;
-
-        let signing_key = SigningKey::from_keypair_bytes(&self.key_pair.secret_key)
-            .map_err(|err| TonError::Custom(format!("Failed to parse Ed25519 keypair: {err}")))?;
-
-        if signing_key.verifying_key().to_bytes() != self.key_pair.public_key {
-            return Err(TonError::Custom("Failed to parse Ed25519 keypair: mismatched public key".to_string()));
-        }
+        let signing_key = self.signing_key()?;
+        self.sign_ext_in_body_with_signer(ext_in_body, |hash| signing_key.sign(hash).to_bytes())
+    }
 
-        let sign = signing_key.sign(msg_hash.as_slice()).to_bytes().to_vec();
+    pub fn sign_ext_in_body_with_signer<F>(&self, ext_in_body: &TonCell, signer: F) -> Result<TonCell, TonError>
+    where
+        F: Fn(&[u8; 32]) -> [u8; 64],
+    {
+        let msg_hash = ext_in_body.cell_hash()?;
+        let sign = signer(msg_hash.as_slice_sized());
         WalletVersion::sign_msg(self.version, ext_in_body, &sign)
     }
 
@@ -105,12 +156,23 @@ impl TonWallet {
         }
         Ok(msg.to_cell()?)
     }
+
+    fn signing_key(&self) -> Result<SigningKey, TonError> {
+        let signing_key = SigningKey::from_keypair_bytes(&self.key_pair.secret_key)
+            .map_err(|err| TonError::Custom(format!("Failed to parse Ed25519 keypair: {err}")))?;
+
+        if signing_key.verifying_key().to_bytes() != self.key_pair.public_key {
+            return Err(TonError::Custom("Failed to parse Ed25519 keypair: mismatched public key".to_string()));
+        }
+        Ok(signing_key)
+    }
 }
 
 #[cfg(test)]
 mod tests {
     use super::*;
     use std::str::FromStr;
+    use ton_core::types::Coins;
 
     const MNEMONIC_STR: &str = "fancy carpet hello mandate penalty trial consider property top vicious exit rebuild tragic profit urban major total month holiday sudden rib gather media vicious";
     const MNEMONIC_STR_V5: &str = "section garden tomato dinner season dice renew length useful spin trade intact use universe what post spike keen mandate behind concert egg doll rug";
@@ -242,6 +304,95 @@ mod tests {
         Ok(())
     }
 
+    #[test]
+    fn test_ton_wallet_create_v5_empty_transfer() -> anyhow::Result<()> {
+        let key_pair = make_keypair(MNEMONIC_STR_V5);
+        let wallet = TonWallet::create_v5(key_pair.clone(), 0, WALLET_V5R1_ID_DEFAULT)?;
+        assert_eq!(wallet, TonWallet::new(WalletVersion::V5R1, key_pair)?);
+        assert_eq!(wallet.address, TonAddress::from_str("UQDv2YSmlrlLH3hLNOVxC8FcQf4F9eGNs4vb2zKma4txo6i3")?);
+
+        // signed_request$_ tag:# wallet_id:# valid_until:# msg_seqno:# inner:InnerRequest
+        // inner: out_actions:(Maybe ^OutList) has_other_actions:(Maybe ...)
+        let body = wallet.create_ext_in_body(1700000000, 5, vec![])?;
+        let mut builder = TonCell::builder();
+        builder.write_num(&0x7369676eu32, 32)?;
+        builder.write_num(&WALLET_V5R1_ID_DEFAULT, 32)?;
+        builder.write_num(&1700000000u32, 32)?;
+        builder.write_num(&5u32, 32)?;
+        builder.write_bit(false)?;
+        builder.write_bit(false)?;
+        let expected = builder.build()?;
+        assert_eq!(body.cell_hash()?, expected.cell_hash()?);
+
+        let parsed = WalletV5ExtMsgBody::from_cell(&body)?;
+        assert!(parsed.msgs.is_empty());
+        Ok(())
+    }
+
+    #[test]
+    fn test_ton_wallet_build_external_batch() -> anyhow::Result<()> {
+        let key_pair = make_keypair(MNEMONIC_STR);
+        let wallet = TonWallet::new(WalletVersion::V4R2, key_pair)?;
+
+        let dest = TonAddress::from_str("EQBiMfDMivebQb052Z6yR3jHrmwNhw1kQ5bcAUOBYsK_VPuK")?;
+        let mut builder = TonCell::builder();
+        42u32.write(&mut builder)?;
+        let transfers = vec![
+            WalletTransfer::new(dest.clone(), Coins::new(1_000)),
+            WalletTransfer::new(dest.clone(), Coins::new(2_000)).with_mode(SendMode::PAY_GAS_SEPARATELY),
+            WalletTransfer::new(wallet.address.clone(), Coins::new(3_000))
+                .with_mode(SendMode::CARRY_ALL_REMAINING_BALANCE | SendMode::IGNORE_ERRORS)
+                .with_body(builder.build()?),
+        ];
+
+        let ext_msg = Msg::<TonCell>::from_cell(&wallet.build_external(&transfers, 5, 100)?)?;
+        assert_eq!(ext_msg.dst(), wallet.address.to_msg_address());
+        assert!(ext_msg.init.is_none());
+
+        let mut parser = ext_msg.body.value.parser();
+        parser.read_bits(512)?; // signature
+        let body = WalletV4ExtMsgBody::read(&mut parser)?;
+        assert_eq!(body.msg_seqno, 5);
+        assert_eq!(body.valid_until, 100);
+        assert_eq!(body.msgs_modes, vec![3, 1, 130]);
+        for (msg_cell, transfer) in body.msgs.iter().zip(&transfers) {
+            let msg = Msg::<TonCell>::from_cell(msg_cell)?;
+            let info = msg.info.as_int().unwrap();
+            assert_eq!(msg.dst(), transfer.dest.to_msg_address());
+            assert_eq!(info.value.coins.to_u128(), transfer.amount.inner());
+            let expected_body = transfer.body.clone().unwrap_or_else(|| TonCell::empty().clone());
+            assert_eq!(msg.body.value, expected_body);
+        }
+
+        let too_many = vec![transfers[0].clone(); WALLET_MAX_TRANSFERS + 1];
+        assert!(wallet.build_external(&too_many, 5, 100).is_err());
+        assert!(wallet.build_external(&[], 5, 100).is_err());
+        assert!(Msg::<TonCell>::from_cell(&wallet.build_external(&transfers[..1], 0, 100)?)?.init.is_some());
+        Ok(())
+    }
+
+    #[test]
+    fn test_ton_wallet_build_external_with_signer() -> anyhow::Result<()> {
+        let key_pair = make_keypair(MNEMONIC_STR_V5);
+        let wallet = TonWallet::new(WalletVersion::V5R1, key_pair.clone())?;
+        let dest = TonAddress::from_str("EQBiMfDMivebQb052Z6yR3jHrmwNhw1kQ5bcAUOBYsK_VPuK")?;
+        let transfers = vec![WalletTransfer::new(dest, Coins::new(1_000))];
+
+        // software key behind the closure, the wallet itself knows only public key
+        let signing_key = SigningKey::from_keypair_bytes(&key_pair.secret_key)?;
+        let signer = |hash: &[u8; 32]| signing_key.sign(hash).to_bytes();
+        let external_wallet = TonWallet::new(WalletVersion::V5R1, KeyPair::from_public_key(key_pair.public_key))?;
+        assert_eq!(external_wallet.address, wallet.address);
+
+        for seqno in [0, 5] {
+            let expected = wallet.build_external(&transfers, seqno, 100)?;
+            let signed = external_wallet.build_external_with_signer(&transfers, seqno, 100, signer)?;
+            assert_eq!(signed, expected);
+        }
+        assert!(external_wallet.build_external(&transfers, 5, 100).is_err());
+        Ok(())
+    }
+
     #[test]
     fn test_ton_wallet_create_external_msg_signed() -> anyhow::Result<()> {
         let key_pair_v3 = make_keypair(MNEMONIC_STR);
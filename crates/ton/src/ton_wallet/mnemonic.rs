@@ -25,6 +25,21 @@ pub struct KeyPair {
     pub secret_key: [u8; KEYPAIR_LENGTH],
 }
 
+impl KeyPair {
+    /// Derives key pair from standard TON 24-words mnemonic, see `Mnemonic` for validation details
+    pub fn from_mnemonic(words: &[&str], password: Option<&str>) -> Result<Self, TonError> {
+        Mnemonic::new(words.to_vec(), password.map(str::to_string))?.to_key_pair()
+    }
+
+    /// Key pair without secret key, for wallets signing with external signer (e.g. HSM)
+    pub fn from_public_key(public_key: [u8; PUBLIC_KEY_LENGTH]) -> Self {
+        Self {
+            public_key,
+            secret_key: [0; KEYPAIR_LENGTH],
+        }
+    }
+}
+
 impl fmt::Debug for KeyPair {
     fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
         f.debug_struct("KeyPair")
@@ -182,4 +197,20 @@ mod tests {
 
         Ok(())
     }
+
+    #[test]
+    fn key_pair_from_mnemonic_works() -> anyhow::Result<()> {
+        let words = [
+            "dose", "ice", "enrich", "trigger", "test", "dove", "century", "still", "betray", "gas", "diet", "dune",
+            "use", "other", "base", "gym", "mad", "law", "immense", "village", "world", "example", "praise", "game",
+        ];
+        let kp = KeyPair::from_mnemonic(&words, None)?;
+        assert_eq!(hex::encode(kp.public_key), "c04ad1885c127fe863abb00752fa844e6439bb04f264d70de7cea580b32637ab");
+        assert_eq!(kp, Mnemonic::new(words.to_vec(), None)?.to_key_pair()?);
+
+        // passwordless mnemonic can't be used with password
+        assert!(KeyPair::from_mnemonic(&words, Some("password")).is_err());
+        assert!(KeyPair::from_mnemonic(&words[..12], None).is_err());
+        Ok(())
+    }
 }
@@ -0,0 +1,51 @@
+use bitflags::bitflags;
+
+// https://docs.ton.org/v3/documentation/smart-contracts/message-management/sending-messages#message-modes
+bitflags! {
+    /// Send mode of out message (`mode` byte of `action_send_msg`)
+    #[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+    pub struct SendMode: u8 {
+        const PAY_GAS_SEPARATELY = 1;
+        const IGNORE_ERRORS = 2;
+        const BOUNCE_IF_FAILURE = 16;
+        const DESTROY_IF_ZERO = 32;
+        const CARRY_ALL_REMAINING_INCOMING_VALUE = 64;
+        const CARRY_ALL_REMAINING_BALANCE = 128;
+    }
+}
+
+impl SendMode {
+    pub const fn as_u8(&self) -> u8 { self.bits() }
+    /// Unknown bits are kept as is
+    pub const fn from_u8(mode: u8) -> Self { Self::from_bits_retain(mode) }
+}
+
+impl Default for SendMode {
+    fn default() -> Self { Self::PAY_GAS_SEPARATELY | Self::IGNORE_ERRORS }
+}
+
+impl From<u8> for SendMode {
+    fn from(mode: u8) -> Self { Self::from_u8(mode) }
+}
+
+impl From<SendMode> for u8 {
+    fn from(mode: SendMode) -> Self { mode.as_u8() }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_send_mode() {
+        assert_eq!(SendMode::default().as_u8(), 3);
+        assert_eq!((SendMode::PAY_GAS_SEPARATELY | SendMode::IGNORE_ERRORS).as_u8(), 3);
+        assert_eq!(SendMode::CARRY_ALL_REMAINING_INCOMING_VALUE.as_u8(), 64);
+        assert_eq!((SendMode::CARRY_ALL_REMAINING_BALANCE | SendMode::IGNORE_ERRORS).as_u8(), 130);
+        assert_eq!((SendMode::CARRY_ALL_REMAINING_BALANCE | SendMode::DESTROY_IF_ZERO).as_u8(), 160);
+        assert_eq!(SendMode::from_u8(3), SendMode::default());
+        assert_eq!(SendMode::from_u8(0), SendMode::empty());
+        assert_eq!(SendMode::from_u8(0xFF).as_u8(), 0xFF);
+        assert!(SendMode::from_u8(130).contains(SendMode::CARRY_ALL_REMAINING_BALANCE));
+    }
+}
@@ -0,0 +1,44 @@
+use crate::block_tlb::{CommonMsgInfoInt, Msg};
+use crate::errors::TonResult;
+use crate::ton_wallet::SendMode;
+use ton_core::cell::TonCell;
+use ton_core::traits::tlb::TLB;
+use ton_core::types::{Coins, TonAddress};
+
+// wallet contracts accept at most 4 out messages per external (v5 accepts more, but we keep the common limit)
+pub const WALLET_MAX_TRANSFERS: usize = 4;
+
+#[derive(Debug, Clone, PartialEq)]
+pub struct WalletTransfer {
+    pub dest: TonAddress,
+    pub amount: Coins,
+    pub mode: SendMode,
+    pub body: Option<TonCell>,
+}
+
+impl WalletTransfer {
+    pub fn new(dest: TonAddress, amount: Coins) -> Self {
+        Self {
+            dest,
+            amount,
+            mode: SendMode::default(),
+            body: None,
+        }
+    }
+
+    pub fn with_mode(mut self, mode: SendMode) -> Self {
+        self.mode = mode;
+        self
+    }
+
+    pub fn with_body(mut self, body: TonCell) -> Self {
+        self.body = Some(body);
+        self
+    }
+
+    pub fn to_int_msg(&self) -> TonResult<TonCell> {
+        let info = CommonMsgInfoInt::new(self.dest.to_msg_address(), self.amount.into());
+        let body = self.body.clone().unwrap_or_else(|| TonCell::empty().clone());
+        Ok(Msg::new(info, body).to_cell()?)
+    }
+}
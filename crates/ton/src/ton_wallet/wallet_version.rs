@@ -63,12 +63,24 @@ impl WalletVersion {
         msg_seqno: u32,
         wallet_id: i32,
         msgs: Vec<TonCell>,
+    ) -> Result<TonCell, TonError> {
+        let msgs_modes = vec![3u8; msgs.len()];
+        Self::build_ext_in_body_with_modes(version, valid_until, msg_seqno, wallet_id, msgs, msgs_modes)
+    }
+
+    pub fn build_ext_in_body_with_modes(
+        version: WalletVersion,
+        valid_until: u32,
+        msg_seqno: u32,
+        wallet_id: i32,
+        msgs: Vec<TonCell>,
+        msgs_modes: Vec<u8>,
     ) -> Result<TonCell, TonError> {
         let res = match version {
             V2R1 | V2R2 => WalletV2ExtMsgBody {
                 msg_seqno,
                 valid_until,
-                msgs_modes: vec![3u8; msgs.len()],
+                msgs_modes,
                 msgs,
             }
             .to_cell(),
@@ -76,7 +88,7 @@ impl WalletVersion {
                 subwallet_id: wallet_id,
                 msg_seqno,
                 valid_until,
-                msgs_modes: vec![3u8; msgs.len()],
+                msgs_modes,
                 msgs,
             }
             .to_cell(),
@@ -85,7 +97,7 @@ impl WalletVersion {
                 valid_until,
                 msg_seqno,
                 opcode: 0,
-                msgs_modes: vec![3u8; msgs.len()],
+                msgs_modes,
                 msgs,
             }
             .to_cell(),
@@ -93,7 +105,7 @@ impl WalletVersion {
                 wallet_id,
                 valid_until,
                 msg_seqno,
-                msgs_modes: vec![3u8; msgs.len()],
+                msgs_modes,
                 msgs,
             }
             .to_cell(),
@@ -0,0 +1,155 @@
+use crate::errors::{TonError, TonResult};
+use std::future::Future;
+use std::time::Duration;
+use tokio_retry::RetryIf;
+use ton_core::errors::TonCoreError;
+
+/// Up to `retry_count` retries, pause between attempts starts from `retry_waiting` and changes according to `backoff`
+///
+/// Use constructors (`new`, `exponential`) and `with_backoff` to create it
+#[derive(Debug, Clone)]
+#[non_exhaustive]
+pub struct RetryStrategy {
+    pub retry_count: usize,
+    pub retry_waiting: Duration,
+    pub backoff: RetryBackoff,
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+#[non_exhaustive]
+pub enum RetryBackoff {
+    /// Same `retry_waiting` pause before each retry
+    Fixed,
+    /// `retry_waiting`, 2 * `retry_waiting`, 4 * `retry_waiting`, ... up to `max_waiting`
+    Exponential { max_waiting: Duration },
+}
+
+impl RetryStrategy {
+    pub const NO_RETRY: RetryStrategy = RetryStrategy::new(0, Duration::ZERO);
+
+    pub const fn new(retry_count: usize, retry_waiting: Duration) -> Self {
+        Self {
+            retry_count,
+            retry_waiting,
+            backoff: RetryBackoff::Fixed,
+        }
+    }
+
+    pub const fn exponential(retry_count: usize, retry_waiting: Duration, max_waiting: Duration) -> Self {
+        Self {
+            retry_count,
+            retry_waiting,
+            backoff: RetryBackoff::Exponential { max_waiting },
+        }
+    }
+
+    pub const fn with_backoff(mut self, backoff: RetryBackoff) -> Self {
+        self.backoff = backoff;
+        self
+    }
+
+    /// Pauses before each retry
+    pub fn delays(&self) -> impl Iterator<Item = Duration> {
+        let (retry_waiting, backoff) = (self.retry_waiting, self.backoff);
+        (0..self.retry_count).map(move |attempt| match backoff {
+            RetryBackoff::Fixed => retry_waiting,
+            RetryBackoff::Exponential { max_waiting } => {
+                let factor = 1u32.checked_shl(attempt as u32).unwrap_or(u32::MAX);
+                retry_waiting.saturating_mul(factor).min(max_waiting)
+            }
+        })
+    }
+}
+
+/// Errors caused by wrong data or wrong response won't change on retry
+///
+/// Client-specific rules (e.g. TLClient retries only code 500) go to the condition passed to [`retry_if`]
+pub fn is_retryable(error: &TonError) -> bool {
+    !matches!(
+        error,
+        TonError::LiteClientWrongResponse(..)
+            | TonError::TLCoreError(
+                TonCoreError::DataError { .. }
+                    | TonCoreError::TLBWrongPrefix { .. }
+                    | TonCoreError::TLBEnumOutOfOptions { .. }
+                    | TonCoreError::NumOverflow { .. },
+            )
+    )
+}
+
+/// Runs `op` until it succeeds, returns a terminal error (see [`is_retryable`]) or `strategy` is exhausted
+pub async fn retry<T, F, Fut>(strategy: &RetryStrategy, op: F) -> TonResult<T>
+where
+    F: FnMut() -> Fut,
+    Fut: Future<Output = TonResult<T>>,
+{
+    retry_if(strategy, op, is_retryable).await
+}
+
+/// Same as [`retry`], but with custom retry condition
+pub async fn retry_if<T, F, Fut, C>(strategy: &RetryStrategy, op: F, condition: C) -> TonResult<T>
+where
+    F: FnMut() -> Fut,
+    Fut: Future<Output = TonResult<T>>,
+    C: FnMut(&TonError) -> bool,
+{
+    #[allow(deprecated)]
+    RetryIf::spawn(strategy.delays(), op, condition).await
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use std::sync::atomic::AtomicUsize;
+    use std::sync::atomic::Ordering::Relaxed;
+    use tokio_test::{assert_err, assert_ok};
+
+    #[tokio::test]
+    async fn test_retry() -> anyhow::Result<()> {
+        let strategy = RetryStrategy::new(3, Duration::from_millis(1));
+        let attempts = AtomicUsize::new(0);
+        let op = || async {
+            match attempts.fetch_add(1, Relaxed) {
+                0 | 1 => Err(TonError::Custom("not yet".to_string())),
+                x => Ok(x),
+            }
+        };
+        assert_eq!(assert_ok!(retry(&strategy, op).await), 2);
+        assert_eq!(attempts.load(Relaxed), 3);
+
+        attempts.store(0, Relaxed);
+        let op = || async {
+            attempts.fetch_add(1, Relaxed);
+            TonResult::<()>::Err(TonError::LiteClientWrongResponse("MasterchainInfo".to_string(), "Error".to_string()))
+        };
+        assert_err!(retry(&strategy, op).await);
+        assert_eq!(attempts.load(Relaxed), 1);
+
+        attempts.store(0, Relaxed);
+        let op = || async {
+            attempts.fetch_add(1, Relaxed);
+            TonResult::<()>::Err(TonError::Custom("always".to_string()))
+        };
+        assert_err!(retry(&strategy, op).await);
+        assert_eq!(attempts.load(Relaxed), 4);
+        Ok(())
+    }
+
+    #[test]
+    fn test_retry_strategy_delays() {
+        let strategy = RetryStrategy::new(3, Duration::from_millis(100));
+        let delays: Vec<_> = strategy.delays().map(|x| x.as_millis()).collect();
+        assert_eq!(delays, vec![100, 100, 100]);
+
+        let strategy = RetryStrategy::exponential(5, Duration::from_millis(100), Duration::from_millis(500));
+        let delays: Vec<_> = strategy.delays().map(|x| x.as_millis()).collect();
+        assert_eq!(delays, vec![100, 200, 400, 500, 500]);
+        let backoff = RetryBackoff::Exponential {
+            max_waiting: Duration::from_millis(500),
+        };
+        let strategy = RetryStrategy::new(5, Duration::from_millis(100)).with_backoff(backoff);
+        let delays: Vec<_> = strategy.delays().map(|x| x.as_millis()).collect();
+        assert_eq!(delays, vec![100, 200, 400, 500, 500]);
+        assert_eq!(RetryStrategy::NO_RETRY.delays().count(), 0);
+    }
+}
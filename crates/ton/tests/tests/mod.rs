@@ -2,6 +2,8 @@
 mod test_contract_client;
 #[cfg(feature = "tonlibjson")]
 mod test_contracts;
+#[cfg(feature = "tonlibjson")]
+mod test_emulators;
 mod test_lite_client;
 mod test_metadata;
 #[cfg(feature = "tonlibjson")]
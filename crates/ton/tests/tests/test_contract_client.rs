@@ -1,11 +1,14 @@
-use crate::tests::utils::make_tl_client;
+use crate::tests::utils::{make_lite_client, make_tl_client};
 use futures_util::try_join;
 use std::str::FromStr;
 use tokio_test::assert_ok;
+use ton::block_tlb::{FromTVMStack, TVMStack};
 use ton::contracts::tl_provider::TLProvider;
 use ton::contracts::{
     ContractClient, JettonMasterContract, JettonMasterMethods, JettonWalletContract, JettonWalletMethods, TonContract,
 };
+use ton::tep::get_method_id::method_id;
+use ton::tep::tvm_result::GetWalletDataResult;
 use ton::tl_client::TLClient;
 use ton_core::cell::TonHash;
 use ton_core::traits::contract_provider::TonProvider;
@@ -19,6 +22,7 @@ async fn test_contract_client() -> anyhow::Result<()> {
     let res = try_join!(
         assert_tl_provider_works(tl_client.clone()),
         assert_contract_client_tl_provider(tl_client.clone()),
+        assert_run_get_method_local(tl_client.clone()),
     );
     assert_ok!(res);
     Ok(())
@@ -111,6 +115,24 @@ async fn assert_contract_client_tl_provider(tl_client: TLClient) -> anyhow::Resu
     Ok(())
 }
 
+async fn assert_run_get_method_local(tl_client: TLClient) -> anyhow::Result<()> {
+    let ctr_cli = ContractClient::builder(TLProvider::new(tl_client))?.with_default_caches().build()?;
+    let lite_client = make_lite_client(true).await?;
+
+    let usdt_wallet = TonAddress::from_str("EQAmJs8wtwK93thF78iD76RQKf9Z3v2sxM57iwpZZtdQAiVM")?;
+    let method_id = method_id("get_wallet_data") as u64;
+    let local_stack = ctr_cli.run_get_method_local(&usdt_wallet, "get_wallet_data", TVMStack::EMPTY).await?;
+    let network_stack = lite_client.run_smc_method(&usdt_wallet, method_id, &TVMStack::EMPTY).await?;
+
+    // balance may change between calls
+    let local = GetWalletDataResult::from_stack(&mut local_stack.clone())?;
+    let network = GetWalletDataResult::from_stack(&mut network_stack.clone())?;
+    assert_eq!(local.owner, network.owner);
+    assert_eq!(local.master, network.master);
+    assert_eq!(local.wallet_code, network.wallet_code);
+    Ok(())
+}
+
 #[tokio::test]
 #[ignore = "testnet"]
 async fn test_contract_client_tl_provider_dynamic_libs_testnet() -> anyhow::Result<()> {
@@ -0,0 +1,51 @@
+use crate::tests::utils::make_lite_client;
+use std::str::FromStr;
+use std::time::{SystemTime, UNIX_EPOCH};
+use ton::block_tlb::{CommonMsgInfoInt, Msg, ShardAccount};
+use ton::emulators::emul_bc_config::EmulBCConfig;
+use ton::emulators::tx_emulator::{TXEmulArgs, TXEmulOrdArgs, TXEmulator};
+use ton_core::cell::{TonCell, TonHash};
+use ton_core::traits::tlb::TLB;
+use ton_core::types::tlb_core::TLBRef;
+use ton_core::types::{Coins, TonAddress};
+
+#[tokio::test]
+async fn test_emul_bc_config_fetch() -> anyhow::Result<()> {
+    let lite_client = make_lite_client(true).await?;
+    let mc_seqno = lite_client.get_mc_info().await?.last.seqno;
+    let bc_config = EmulBCConfig::fetch(&lite_client, Some(mc_seqno)).await?;
+    assert!(!bc_config.to_boc()?.is_empty());
+    assert_eq!(EmulBCConfig::fetch(&lite_client, Some(mc_seqno)).await?, bc_config);
+
+    // emulate simple transfer to wallet using fetched config
+    let wallet = TonAddress::from_str("UQAj-peZGPH-cC25EAv4Q-h8cBXszTmkch6ba6wXC8BM40qt")?;
+    let sender = TonAddress::from_str("EQCxE6mUtQJKFnGfaROTKOt1lZbDiiX1kCixRv7Nw2Id_sDs")?;
+    let account = lite_client.get_account_state(&wallet, mc_seqno, None).await?;
+    let shard_account = ShardAccount {
+        account: TLBRef::new(account),
+        ..Default::default()
+    };
+
+    let mut msg_info = CommonMsgInfoInt::new(wallet.to_msg_address(), Coins::new(1_000_000_000).into());
+    msg_info.src = sender.to_msg_address();
+    let in_msg = Msg::new(msg_info, TonCell::empty().clone());
+
+    let args = TXEmulOrdArgs {
+        in_msg_boc: in_msg.to_boc()?.into(),
+        emul_args: TXEmulArgs {
+            shard_account_boc: shard_account.to_boc()?.into(),
+            bc_config,
+            rand_seed: TonHash::ZERO,
+            utime: SystemTime::now().duration_since(UNIX_EPOCH)?.as_secs() as u32,
+            lt: 1_000_000,
+            ignore_chksig: false,
+            c7_prev_blocks_info_boc: None,
+            libs_boc: None,
+            gas_limit: None,
+            debug_enabled: false,
+        },
+    };
+    let response = TXEmulator::new(0, false)?.emulate_ord(&args)?.into_success()?;
+    assert!(response.exit_success());
+    Ok(())
+}
@@ -1,12 +1,14 @@
 use crate::tests::utils::make_lite_client;
+use fastnum::I512;
 use std::str::FromStr;
 use tokio_test::{assert_err, assert_ok};
-use ton::block_tlb::BlockIdExt;
+use ton::block_tlb::{BlockIdExt, TVMStack};
 use ton::errors::TonError;
+use ton::tep::get_method_id::method_id;
 use ton::unwrap_lite_rsp;
-use ton_core::cell::TonCell;
+use ton_core::cell::{TonCell, TonHash};
 use ton_core::traits::tlb::TLB;
-use ton_core::types::TonAddress;
+use ton_core::types::{TonAddress, TxLTHash};
 use ton_liteapi::tl::request::Request;
 use ton_liteapi::tl::response::Response;
 
@@ -38,6 +40,73 @@ async fn test_lite_client() -> anyhow::Result<()> {
     Ok(())
 }
 
+#[tokio::test]
+async fn test_lite_client_get_account_states() -> anyhow::Result<()> {
+    let lite_client = make_lite_client(true).await?;
+    let mc_seqno = lite_client.get_mc_info().await?.last.seqno;
+    let addresses = [
+        TonAddress::from_str("EQCxE6mUtQJKFnGfaROTKOt1lZbDiiX1kCixRv7Nw2Id_sDs")?,
+        TonAddress::from_str("EQAFmjUoZUqKFEBGYFEMbv-m61sFStgAfUR8J6hJDwUU09iT")?,
+        TonAddress::from_str("EQB3ncyBUTjZUA5EnFKR5_EnOMI9V1tTEAAPaiU71gc4TiUt")?,
+    ];
+    let accounts = lite_client.get_account_states(&addresses, mc_seqno, None).await?;
+    assert_eq!(accounts.len(), addresses.len());
+    for (address, account) in addresses.iter().zip(accounts.iter()) {
+        let expected = lite_client.get_account_state(address, mc_seqno, None).await?;
+        assert_eq!(account, &expected);
+    }
+    assert!(lite_client.get_account_states(&[], mc_seqno, None).await?.is_empty());
+    Ok(())
+}
+
+#[tokio::test]
+async fn test_lite_client_get_config_params() -> anyhow::Result<()> {
+    let lite_client = make_lite_client(true).await?;
+    let mc_seqno = lite_client.get_mc_info().await?.last.seqno;
+    let config_params = lite_client.get_config_params(mc_seqno, &[0, 34], None).await?;
+    assert_eq!(config_params.len(), 2);
+
+    // param 0: config smart-contract address
+    let config_addr = TonAddress::from_str("Ef9VVVVVVVVVVVVVVVVVVVVVVVVVVVVVVVVVVVVVVVVVVVVVVbxn")?;
+    let param_0 = config_params.get(&0).unwrap();
+    assert_eq!(param_0.parser().read_bits(256)?, config_addr.hash.as_slice());
+    // param 34: current validators set
+    assert!(!config_params.get(&34).unwrap().refs().is_empty());
+    Ok(())
+}
+
+#[tokio::test]
+async fn test_lite_client_get_transactions() -> anyhow::Result<()> {
+    let lite_client = make_lite_client(true).await?;
+    let address = TonAddress::from_str("EQCGScrZe1xbyWqWDvdI6mzP-GAcAWFv6ZXuaJOuSqemxku4")?;
+    let from_hash = TonHash::from_str("16befdc4512ca3ffaa2919e1f0d7635588edcb9fa7d3990fe83e89275c291cc7")?;
+    let from = TxLTHash::new(64954068000009, from_hash.clone());
+
+    let txs = lite_client.get_transactions(&address, from, 3, None).await?;
+    assert_eq!(txs.len(), 3);
+    assert_eq!(txs[0].lt, 64954068000009);
+    assert_eq!(txs[0].cell_hash()?, from_hash);
+    for pair in txs.windows(2) {
+        assert_eq!(pair[1].lt, pair[0].prev_tx_lt);
+        assert_eq!(pair[1].cell_hash()?, pair[0].prev_tx_hash);
+    }
+    Ok(())
+}
+
+#[tokio::test]
+async fn test_lite_client_run_smc_method() -> anyhow::Result<()> {
+    let lite_client = make_lite_client(true).await?;
+    let wallet = TonAddress::from_str("UQAj-peZGPH-cC25EAv4Q-h8cBXszTmkch6ba6wXC8BM40qt")?;
+    let seqno_method_id = method_id("seqno") as u64;
+    let mut stack = lite_client.run_smc_method(&wallet, seqno_method_id, &TVMStack::default()).await?;
+    assert!(stack.pop_num()? > I512::ZERO);
+    assert_ok!(stack.ensure_empty());
+
+    // garbage message must be rejected
+    assert_err!(lite_client.send_message(&[0u8; 8]).await);
+    Ok(())
+}
+
 #[ignore = "requires full (archive) testnet node"]
 #[tokio::test]
 async fn test_lite_client_testnet() -> anyhow::Result<()> {
@@ -52,3 +121,21 @@ async fn test_lite_client_testnet() -> anyhow::Result<()> {
     assert_ok!(TonCell::from_boc(state.data));
     Ok(())
 }
+
+// sync code, no runtime around
+#[cfg(feature = "blocking")]
+#[test]
+fn test_lite_client_blocking() -> anyhow::Result<()> {
+    let net_config = ton::net_config::TonNetConfig::new_default(true)?;
+    let lite_client = ton::lite_client::LiteClient::builder()?.with_net_config(net_config).build()?;
+    let mc_info = lite_client.blocking_get_mc_info()?;
+    assert_ne!(mc_info.last.seqno, 0);
+
+    let block_id = lite_client.blocking_lookup_mc_block(mc_info.last.seqno)?;
+    assert_eq!(block_id, mc_info.last);
+
+    let usdt_addr = TonAddress::from_str("EQCxE6mUtQJKFnGfaROTKOt1lZbDiiX1kCixRv7Nw2Id_sDs")?;
+    let account = lite_client.blocking_get_account_state(&usdt_addr, mc_info.last.seqno, None)?;
+    assert!(account.as_account().is_some());
+    Ok(())
+}
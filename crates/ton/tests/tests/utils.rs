@@ -42,10 +42,7 @@ pub(crate) async fn make_tl_client(mainnet: bool, archive_only: bool) -> anyhow:
         .with_net_config(&TonNetConfig::new_default(mainnet)?)?
         .with_connection_check(node_filter)
         .with_connections_count(2)
-        .with_retry_strategy(ton::tl_client::RetryStrategy {
-            retry_count: 10,
-            retry_waiting: Duration::from_millis(100),
-        })
+        .with_retry_strategy(ton::tl_client::RetryStrategy::new(10, Duration::from_millis(100)))
         .build()
         .await?;
     ton::sys_utils::sys_tonlib_set_verbosity_level(0);
@@ -80,6 +80,24 @@ impl BitsUtils {
         dst_bits.copy_from_bitslice(src_bits);
         true
     }
+
+    // panics if pos is out of data bounds
+    pub fn bit_at(data: &[u8], pos: usize) -> bool { data[pos / 8] & (0x80 >> (pos % 8)) != 0 }
+
+    // compares at most max_bits, stops at the end of the shortest input
+    pub fn common_prefix_len(a: &[u8], a_off: usize, b: &[u8], b_off: usize, max_bits: usize) -> usize {
+        let a_bits = a.view_bits::<Msb0>().get(a_off..).unwrap_or_default();
+        let b_bits = b.view_bits::<Msb0>().get(b_off..).unwrap_or_default();
+        let len = max_bits.min(a_bits.len()).min(b_bits.len());
+        a_bits[..len].iter().zip(b_bits[..len].iter()).take_while(|(x, y)| x == y).count()
+    }
+
+    // counts zero bits starting from offset, stops after max_bits or at the end of data
+    pub fn count_leading_zeros(data: &[u8], offset: usize, max_bits: usize) -> usize {
+        let bits = data.view_bits::<Msb0>().get(offset..).unwrap_or_default();
+        let len = max_bits.min(bits.len());
+        bits[..len].leading_zeros()
+    }
 }
 
 #[cfg(test)]
@@ -143,4 +161,45 @@ mod tests {
 
         assert!(!BitsUtils::read_with_offset(&src, &mut dst, 14, 10));
     }
+
+    #[test]
+    fn test_bit_at() {
+        let data = [0b10100000, 0b00000001];
+        let bits: Vec<_> = (0..16).map(|pos| BitsUtils::bit_at(&data, pos)).collect();
+        let expected: Vec<_> = (0..16).map(|pos| pos == 0 || pos == 2 || pos == 15).collect();
+        assert_eq!(bits, expected);
+    }
+
+    #[test]
+    fn test_common_prefix_len() {
+        // aligned
+        let a = [0b11001100, 0b10101010];
+        let b = [0b11001100, 0b10111010];
+        assert_eq!(BitsUtils::common_prefix_len(&a, 0, &b, 0, 16), 11);
+        assert_eq!(BitsUtils::common_prefix_len(&a, 0, &b, 0, 5), 5);
+        assert_eq!(BitsUtils::common_prefix_len(&a, 0, &a, 0, 16), 16);
+        assert_eq!(BitsUtils::common_prefix_len(&a, 0, &a, 0, 100), 16);
+
+        // unaligned: a[3..] == 0b01100_10101010, c[1..] == 0b0110010101010
+        let c = [0b00110010, 0b10101010];
+        assert_eq!(BitsUtils::common_prefix_len(&a, 3, &c, 1, 100), 13);
+        assert_eq!(BitsUtils::common_prefix_len(&a, 3, &c, 1, 7), 7);
+        assert_eq!(BitsUtils::common_prefix_len(&a, 3, &c, 0, 100), 1);
+
+        // out of bounds offsets
+        assert_eq!(BitsUtils::common_prefix_len(&a, 16, &a, 0, 8), 0);
+        assert_eq!(BitsUtils::common_prefix_len(&a, 20, &a, 20, 8), 0);
+        assert_eq!(BitsUtils::common_prefix_len(&[], 0, &[], 0, 8), 0);
+    }
+
+    #[test]
+    fn test_count_leading_zeros() {
+        let data = [0b00000000, 0b00010000];
+        assert_eq!(BitsUtils::count_leading_zeros(&data, 0, 16), 11);
+        assert_eq!(BitsUtils::count_leading_zeros(&data, 3, 16), 8);
+        assert_eq!(BitsUtils::count_leading_zeros(&data, 0, 4), 4);
+        assert_eq!(BitsUtils::count_leading_zeros(&data, 12, 16), 4);
+        assert_eq!(BitsUtils::count_leading_zeros(&data, 11, 16), 0);
+        assert_eq!(BitsUtils::count_leading_zeros(&data, 20, 16), 0);
+    }
 }
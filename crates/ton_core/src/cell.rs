@@ -9,6 +9,7 @@ mod cell_builder;
 mod cell_meta;
 mod cell_parser;
 mod ton_cell;
+mod ton_cell_diff;
 mod ton_cell_num;
 mod ton_cell_utils;
 mod ton_hash;
@@ -18,6 +19,7 @@ pub use cell_builder::*;
 pub use cell_meta::*;
 pub use cell_parser::*;
 pub use ton_cell::*;
+pub use ton_cell_diff::*;
 pub use ton_cell_num::*;
 pub use ton_cell_utils::*;
 pub use ton_hash::*;
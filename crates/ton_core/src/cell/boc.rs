@@ -1,3 +1,4 @@
+mod boc_writer;
 pub(crate) mod raw_boc;
 pub(crate) mod raw_cell;
 mod read_var_size;
@@ -10,6 +11,8 @@ use base64::Engine;
 use base64::prelude::BASE64_STANDARD;
 use std::sync::Arc;
 
+pub use boc_writer::BocWriter;
+
 pub struct BoC {
     roots: Vec<TonCell>,
 }
@@ -25,14 +28,19 @@ impl BoC {
         }
     }
 
+    /// Doesn't check crc32c, use `from_bytes_verified` for it
     pub fn from_bytes<T: Into<Arc<Vec<u8>>>>(bytes: T) -> Result<Self, TonCoreError> {
-        let bytes_ptr = bytes.into();
-        if bytes_ptr.is_empty() {
-            bail_ton_core_data!("Can't read BOC from empty slice");
-        }
-        Ok(Self {
-            roots: RawBoC::from_bytes(bytes_ptr)?.into_ton_cells()?,
-        })
+        Self::read(bytes.into(), false, TonCell::DEFAULT_MAX_DEPTH)
+    }
+
+    /// Same as `from_bytes`, but fails if BoC has crc32c and it doesn't match the data
+    pub fn from_bytes_verified<T: Into<Arc<Vec<u8>>>>(bytes: T) -> Result<Self, TonCoreError> {
+        Self::read(bytes.into(), true, TonCell::DEFAULT_MAX_DEPTH)
+    }
+
+    /// Same as `from_bytes`, but fails if any cell is deeper than `max_depth` (instead of `TonCell::DEFAULT_MAX_DEPTH`)
+    pub fn from_bytes_with_max_depth<T: Into<Arc<Vec<u8>>>>(bytes: T, max_depth: usize) -> Result<Self, TonCoreError> {
+        Self::read(bytes.into(), false, max_depth)
     }
 
     pub fn from_hex<T: AsRef<[u8]>>(hex: T) -> Result<Self, TonCoreError> {
@@ -50,8 +58,18 @@ impl BoC {
         Ok(BASE64_STANDARD.encode(self.to_bytes(add_crc32)?))
     }
 
+    fn read(bytes: Arc<Vec<u8>>, verify_crc32c: bool, max_depth: usize) -> Result<Self, TonCoreError> {
+        if bytes.is_empty() {
+            bail_ton_core_data!("Can't read BOC from empty slice");
+        }
+        Ok(Self {
+            roots: RawBoC::from_bytes(bytes, verify_crc32c)?.into_ton_cells(max_depth)?,
+        })
+    }
+
     // zero-based index
     pub fn get_root(&self, index: usize) -> Option<&TonCell> { self.roots.get(index) }
+    pub fn roots(&self) -> &[TonCell] { &self.roots }
 
     pub fn single_root(mut self) -> Result<TonCell, TonCoreError> {
         if self.roots.len() != 1 {
@@ -103,4 +121,50 @@ mod tests {
         assert_eq!(boc_hex, serial_hex);
         Ok(())
     }
+
+    #[test]
+    fn test_boc_from_bytes_with_cache_bits() -> anyhow::Result<()> {
+        // the same cells, serialized with has_idx + has_cache_bits
+        let boc_hex = "b5ee9c72010204010001560001e1880125d7220d944052a2659cc2e1d9c4671742068426947941b3c933e43936912fc800000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000014d4d18bb3ce5c84000000088001c01016862004975c883aea91de93142ae4dc222d803c74e5f130f37ef0d42fb353897fd0f982068e77800000000000000000000000000010201b20f8a7ea500000000000000005012a05f20080129343398aec31cdbbf7d32d977c27a96d5cd23c38fd4bd47be019abafb9b356b0024bae441b2880a544cb3985c3b388ce2e840d084d28f283679267c8726d225f90814dc9381030099259385618012934339d11465553b2f3e428ae79b0b1e2fd250b80784d4996dd44741736528ca0259f3a0f90024bae441b2880a544cb3985c3b388ce2e840d084d28f283679267c8726d225f910";
+        let boc_with_cache_bits_hex = "b5ee9c72a10204010001560000e90156020f02ac01e1880125d7220d944052a2659cc2e1d9c4671742068426947941b3c933e43936912fc800000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000014d4d18bb3ce5c84000000088001c01016862004975c883aea91de93142ae4dc222d803c74e5f130f37ef0d42fb353897fd0f982068e77800000000000000000000000000010201b20f8a7ea500000000000000005012a05f20080129343398aec31cdbbf7d32d977c27a96d5cd23c38fd4bd47be019abafb9b356b0024bae441b2880a544cb3985c3b388ce2e840d084d28f283679267c8726d225f90814dc9381030099259385618012934339d11465553b2f3e428ae79b0b1e2fd250b80784d4996dd44741736528ca0259f3a0f90024bae441b2880a544cb3985c3b388ce2e840d084d28f283679267c8726d225f910";
+        let expected = BoC::from_hex(boc_hex)?.single_root()?;
+        let cell = BoC::from_hex(boc_with_cache_bits_hex)?.single_root()?;
+        assert_eq!(cell.hash()?, expected.hash()?);
+        assert_eq!(BoC::new(cell).to_hex(false)?, boc_hex);
+
+        // cache bits without index are not allowed
+        let mut no_idx = hex::decode(boc_with_cache_bits_hex)?;
+        no_idx[4] &= 0b0111_1111;
+        assert!(BoC::from_bytes(no_idx).is_err());
+
+        // index must match cells layout
+        let mut broken_idx = hex::decode(boc_with_cache_bits_hex)?;
+        broken_idx[14] ^= 0b0000_0010;
+        assert!(BoC::from_bytes(broken_idx).is_err());
+        Ok(())
+    }
+
+    #[test]
+    fn test_boc_from_bytes_verified() -> anyhow::Result<()> {
+        let mut builder = TonCell::builder();
+        builder.write_num(&0xdeadbeefu32, 32)?;
+        builder.write_ref(TonCell::empty().clone())?;
+        let cell = builder.build()?;
+        let boc = BoC::new(cell.clone()).to_bytes(true)?;
+        assert_eq!(TonCell::from_boc_verified(boc.clone())?, cell);
+
+        // flip first byte of root data
+        let mut corrupted = boc.clone();
+        let data_pos = boc.windows(4).position(|w| w == [0xde, 0xad, 0xbe, 0xef]).unwrap();
+        corrupted[data_pos] ^= 0xff;
+        assert!(TonCell::from_boc_verified(corrupted.clone()).is_err());
+        assert!(BoC::from_bytes_verified(corrupted.clone()).is_err());
+        let lenient = BoC::from_bytes(corrupted)?.single_root()?;
+        assert_eq!(lenient.parser().read_num::<u32>(32)?, 0x21adbeef);
+
+        // no crc - nothing to verify
+        let no_crc = BoC::new(cell.clone()).to_bytes(false)?;
+        assert_eq!(TonCell::from_boc_verified(no_crc)?, cell);
+        Ok(())
+    }
 }
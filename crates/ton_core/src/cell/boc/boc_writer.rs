@@ -0,0 +1,73 @@
+use crate::cell::TonCell;
+use crate::cell::boc::raw_boc::RawBoC;
+use crate::errors::TonCoreError;
+use std::io::Write;
+
+/// Streams serialized BoC to `std::io::Write` instead of building the output in memory
+///
+/// Output is byte-identical to `BoC::to_bytes` for the same roots.
+/// Cells are indexed (first pass) before writing, so all roots must be added beforehand
+#[derive(Default, Debug, Clone)]
+pub struct BocWriter {
+    roots: Vec<TonCell>,
+}
+
+impl BocWriter {
+    pub fn new() -> Self { Self::default() }
+
+    pub fn add_root(&mut self, root: TonCell) -> &mut Self {
+        self.roots.push(root);
+        self
+    }
+
+    pub fn roots(&self) -> &[TonCell] { &self.roots }
+
+    /// Use `BufWriter` for files & sockets: data is written in small chunks
+    pub fn write_to<W: Write>(&self, writer: W, add_crc32: bool) -> Result<(), TonCoreError> {
+        RawBoC::from_ton_cells(&self.roots, false)?.write_to(writer, add_crc32)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::cell::BoC;
+    use crate::traits::tlb::TLB;
+
+    #[test]
+    fn test_boc_writer() -> anyhow::Result<()> {
+        let make_cell = |value: u32, refs: &[&TonCell]| -> anyhow::Result<TonCell> {
+            let mut builder = TonCell::builder();
+            builder.write_num(&value, 32)?;
+            builder.write_bits([0b1010_0000], 3)?;
+            for cell_ref in refs {
+                builder.write_ref((*cell_ref).clone())?;
+            }
+            Ok(builder.build()?)
+        };
+        let shared = make_cell(1, &[])?;
+        let mut level = vec![shared.clone()];
+        for i in 0..300 {
+            let prev = &level[level.len() - 1];
+            level.push(make_cell(i, &[prev, &shared])?);
+        }
+        let root1 = level.pop().unwrap();
+        let root2 = make_cell(42, &[&level[100], &shared])?;
+
+        let mut boc_writer = BocWriter::new();
+        boc_writer.add_root(root1.clone());
+        let mut bytes = vec![];
+        boc_writer.write_to(&mut bytes, false)?;
+        assert_eq!(bytes, root1.to_boc()?);
+
+        boc_writer.add_root(root2.clone());
+        for add_crc32 in [false, true] {
+            let mut bytes = vec![];
+            boc_writer.write_to(&mut bytes, add_crc32)?;
+            assert_eq!(bytes, BoC::from_roots([root1.clone(), root2.clone()]).to_bytes(add_crc32)?);
+            let parsed = BoC::from_bytes_verified(bytes)?;
+            assert_eq!(parsed.roots(), boc_writer.roots());
+        }
+        Ok(())
+    }
+}
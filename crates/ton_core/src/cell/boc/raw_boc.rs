@@ -6,16 +6,16 @@ use crate::cell::{TonCell, TonHash};
 use crate::errors::TonCoreError;
 use bitstream_io::BigEndian;
 use bitstream_io::{BitWrite, BitWriter, ByteRead};
-use crc::Crc;
+use crc::{Crc, Digest};
 use smallvec::SmallVec;
 use std::cell::RefCell;
 use std::collections::HashMap;
-use std::io::Cursor;
+use std::io::{Cursor, Write};
 use std::ops::Deref;
 use std::sync::Arc;
 
 const GENERIC_BOC_MAGIC: u32 = 0xb5ee9c72;
-const CRC_32_ISCSI: Crc<u32> = Crc::<u32>::new(&crc::CRC_32_ISCSI);
+static CRC_32_ISCSI: Crc<u32> = Crc::<u32>::new(&crc::CRC_32_ISCSI);
 
 /// `cells` must be topologically sorted.
 #[derive(PartialEq, Debug, Clone)]
@@ -26,7 +26,8 @@ pub(crate) struct RawBoC {
 
 impl RawBoC {
     // https://github.com/ton-blockchain/ton/blob/24dc184a2ea67f9c47042b4104bbb4d82289fac1/crypto/tl/boc.tlb#L25
-    pub(crate) fn from_bytes(data_storage: Arc<Vec<u8>>) -> Result<RawBoC, TonCoreError> {
+    // verify_crc32c: check crc32c if it's present in BoC
+    pub(crate) fn from_bytes(data_storage: Arc<Vec<u8>>, verify_crc32c: bool) -> Result<RawBoC, TonCoreError> {
         let cursor = Cursor::new(data_storage.as_slice());
         let mut reader = CellBytesReader::new(cursor);
         let magic = reader.read::<u32>()?;
@@ -35,7 +36,7 @@ impl RawBoC {
             bail_ton_core_data!("Unexpected magic: {magic}");
         };
 
-        let (has_idx, has_crc32c, _has_cache_bits, ref_pos_size_bytes) = {
+        let (has_idx, has_crc32c, has_cache_bits, ref_pos_size_bytes) = {
             // has_idx:(## 1) has_crc32c:(## 1) has_cache_bits:(## 1) flags:(## 2) { flags = 0 }
             let header = reader.read::<u8>()?;
             let has_idx = (header & 0b1000_0000) != 0;
@@ -48,6 +49,9 @@ impl RawBoC {
                 bail_ton_core_data!("Invalid BoC header: ref_pos_size={ref_pos_size} (must be <= 4)");
             }
 
+            if has_cache_bits && !has_idx {
+                bail_ton_core_data!("Invalid BoC header: has_cache_bits is set, but has_idx is not");
+            }
             (has_idx, has_crc32c, has_cache_bits, ref_pos_size)
         };
 
@@ -76,18 +80,42 @@ impl RawBoC {
             roots_pos.push(read_var_size(&mut reader, ref_pos_size_bytes)?)
         }
         //   index:has_idx?(cells * ##(off_bytes * 8))
+        // each entry is end offset of the cell; with has_cache_bits it's shifted left, and the lowest bit is cache flag
+        let mut cells_end_offsets = Vec::with_capacity(if has_idx { cells_cnt } else { 0 });
         if has_idx {
-            reader.skip(cells_cnt as u32 * off_bytes as u32)?;
+            for _ in 0..cells_cnt {
+                let entry = read_var_size(&mut reader, off_bytes)?;
+                cells_end_offsets.push(if has_cache_bits { entry >> 1 } else { entry });
+            }
         }
         //   cell_data:(tot_cells_size * [ uint8 ])
         let mut cells = Vec::with_capacity(cells_cnt);
 
-        for _ in 0..cells_cnt {
+        let cells_start = reader.reader().position() as usize;
+        for cell_pos in 0..cells_cnt {
             let cell = RawCell::read(&mut reader, ref_pos_size_bytes, data_storage.clone())?;
             cells.push(cell);
+            if let Some(&expected_end) = cells_end_offsets.get(cell_pos) {
+                let cell_end = reader.reader().position() as usize - cells_start;
+                if cell_end != expected_end {
+                    bail_ton_core_data!(
+                        "Invalid BoC index: cell {cell_pos} ends at {cell_end}, but index says {expected_end}"
+                    );
+                }
+            }
         }
         //   crc32c:has_crc32c?uint32
-        let _crc32c = if has_crc32c { reader.read::<u32>()? } else { 0 };
+        if has_crc32c {
+            let crc_pos = reader.reader().position() as usize;
+            reader.skip(4)?;
+            if verify_crc32c {
+                let expected = u32::from_le_bytes(data_storage[crc_pos..crc_pos + 4].try_into().unwrap());
+                let actual = CRC_32_ISCSI.checksum(&data_storage[..crc_pos]);
+                if expected != actual {
+                    bail_ton_core_data!("Invalid BoC crc32c: expected {expected:08x}, calculated {actual:08x}");
+                }
+            }
+        }
 
         Ok(RawBoC {
             raw_cells: cells,
@@ -95,44 +123,46 @@ impl RawBoC {
         })
     }
 
-    //Based on https://github.com/toncenter/tonweb/blob/c2d5d0fc23d2aec55a0412940ce6e580344a288c/src/boc/Cell.js#L198
     pub(crate) fn to_bytes(&self, add_crc32: bool) -> Result<Vec<u8>, TonCoreError> {
-        let root_count = self.roots_pos.len();
-        let ref_size_bits = 32 - (self.raw_cells.len() as u32).leading_zeros();
-        let ref_pos_size_bytes = ref_size_bits.div_ceil(8);
-        let has_idx = false;
-
-        let mut full_size = 0u32;
+        let layout = self.layout();
+        let total_size = layout.header_size(self.roots_pos.len() as u32) + layout.full_size + 4 * add_crc32 as u32;
+        let mut bytes = Vec::with_capacity(total_size as usize);
+        self.write_to(&mut bytes, add_crc32)?;
+        Ok(bytes)
+    }
 
-        for cell in &self.raw_cells {
-            full_size += cell.size_in_boc_bytes(ref_pos_size_bytes);
+    pub(crate) fn write_to<W: Write>(&self, dst: W, add_crc32: bool) -> Result<(), TonCoreError> {
+        if !add_crc32 {
+            self.write_body(dst, false)?.flush()?;
+            return Ok(());
         }
+        let crc_writer = self.write_body(Crc32cWriter::new(dst), true)?;
+        let crc32 = crc_writer.digest.finalize();
+        let mut dst = crc_writer.inner;
+        dst.write_all(&crc32.to_le_bytes())?;
+        dst.flush()?;
+        Ok(())
+    }
 
-        let num_offset_bits = 32 - full_size.leading_zeros();
-        let num_offset_bytes = num_offset_bits.div_ceil(8);
-
-        let total_size = 4 + // magic
-            1 + // flags and s_bytes
-            1 + // offset_bytes
-            3 * ref_pos_size_bytes + // cells_num, roots, complete
-            num_offset_bytes + // full_size
-            ref_pos_size_bytes + // root_idx
-            (if has_idx { self.raw_cells.len() as u32 * num_offset_bytes } else { 0 }) +
-            full_size +
-            (if add_crc32 { 4 } else { 0 });
-
-        let mut writer = BitWriter::endian(Vec::with_capacity(total_size as usize), BigEndian);
+    //Based on https://github.com/toncenter/tonweb/blob/c2d5d0fc23d2aec55a0412940ce6e580344a288c/src/boc/Cell.js#L198
+    fn write_body<W: Write>(&self, dst: W, has_crc32: bool) -> Result<W, TonCoreError> {
+        let layout = self.layout();
+        let ref_pos_size_bytes = layout.ref_pos_size_bytes;
+        let num_offset_bytes = layout.num_offset_bytes;
+        let has_idx = false;
+
+        let mut writer = BitWriter::endian(dst, BigEndian);
         writer.write_var(32, GENERIC_BOC_MAGIC)?;
         writer.write_bit(has_idx)?;
-        writer.write_bit(add_crc32)?;
+        writer.write_bit(has_crc32)?;
         writer.write_bit(false)?; // has_cache_bits
         writer.write_var(2, 0)?; // flags
         writer.write_var(3, ref_pos_size_bytes)?;
         writer.write_var(8, num_offset_bytes)?;
         writer.write_var(8 * ref_pos_size_bytes, self.raw_cells.len() as u32)?;
-        writer.write_var(8 * ref_pos_size_bytes, root_count as u32)?;
+        writer.write_var(8 * ref_pos_size_bytes, self.roots_pos.len() as u32)?;
         writer.write_var(8 * ref_pos_size_bytes, 0)?; // Complete BOCs only
-        writer.write_var(8 * num_offset_bytes, full_size)?;
+        writer.write_var(8 * num_offset_bytes, layout.full_size)?;
 
         for &root in &self.roots_pos {
             writer.write_var(8 * ref_pos_size_bytes, root as u32)?;
@@ -142,27 +172,42 @@ impl RawBoC {
             cell.write_to(&mut writer, ref_pos_size_bytes)?;
         }
         writer.byte_align()?;
-        let mut bytes = writer.into_writer();
-        if add_crc32 {
-            bytes.extend(CRC_32_ISCSI.checksum(&bytes).to_le_bytes());
+        Ok(writer.into_writer())
+    }
+
+    fn layout(&self) -> BoCLayout {
+        let ref_size_bits = 32 - (self.raw_cells.len() as u32).leading_zeros();
+        let ref_pos_size_bytes = ref_size_bits.div_ceil(8);
+        let full_size = self.raw_cells.iter().map(|cell| cell.size_in_boc_bytes(ref_pos_size_bytes)).sum::<u32>();
+        let num_offset_bits = 32 - full_size.leading_zeros();
+        BoCLayout {
+            ref_pos_size_bytes,
+            num_offset_bytes: num_offset_bits.div_ceil(8),
+            full_size,
         }
-        Ok(bytes)
     }
 
     //Based on https://github.com/toncenter/tonweb/blob/c2d5d0fc23d2aec55a0412940ce6e580344a288c/src/boc/Cell.js#L198
-    pub(crate) fn into_ton_cells(self) -> Result<Vec<TonCell>, TonCoreError> {
+    pub(crate) fn into_ton_cells(self, max_depth: usize) -> Result<Vec<TonCell>, TonCoreError> {
         let cells_len = self.raw_cells.len();
         let mut cells: Vec<TonCell> = Vec::with_capacity(cells_len);
+        let mut depths: Vec<usize> = Vec::with_capacity(cells_len); // same order as cells
 
         for (cell_index, cell_raw) in self.raw_cells.into_iter().enumerate().rev() {
             let mut refs = RefStorage::with_capacity(cell_raw.refs_pos.len());
+            let mut depth = 0;
             for ref_index in &cell_raw.refs_pos {
                 if *ref_index <= cell_index {
                     bail_ton_core_data!("Invalid BoC: ref to parent cell detected");
                 }
                 refs.push(cells[cells_len - 1 - ref_index].clone());
+                depth = depth.max(depths[cells_len - 1 - ref_index] + 1);
+            }
+            if depth > max_depth {
+                bail_ton_core_data!("Invalid BoC: cell {cell_index} depth exceeds limit {max_depth}");
             }
             cells.push(cell_raw.into_ton_cell(refs));
+            depths.push(depth);
         }
 
         let mut roots = Vec::with_capacity(self.roots_pos.len());
@@ -196,6 +241,48 @@ impl RawBoC {
     }
 }
 
+struct BoCLayout {
+    ref_pos_size_bytes: u32,
+    num_offset_bytes: u32,
+    full_size: u32, // cells data size
+}
+
+impl BoCLayout {
+    fn header_size(&self, root_count: u32) -> u32 {
+        4 + // magic
+        1 + // flags and s_bytes
+        1 + // offset_bytes
+        3 * self.ref_pos_size_bytes + // cells_num, roots, complete
+        self.num_offset_bytes + // full_size
+        root_count * self.ref_pos_size_bytes // root_idx
+    }
+}
+
+/// Passes data through, calculating crc32c on the fly
+struct Crc32cWriter<W: Write> {
+    inner: W,
+    digest: Digest<'static, u32>,
+}
+
+impl<W: Write> Crc32cWriter<W> {
+    fn new(inner: W) -> Self {
+        Self {
+            inner,
+            digest: CRC_32_ISCSI.digest(),
+        }
+    }
+}
+
+impl<W: Write> Write for Crc32cWriter<W> {
+    fn write(&mut self, buf: &[u8]) -> std::io::Result<usize> {
+        let written = self.inner.write(buf)?;
+        self.digest.update(&buf[..written]);
+        Ok(written)
+    }
+
+    fn flush(&mut self) -> std::io::Result<()> { self.inner.flush() }
+}
+
 #[derive(Debug, Clone)]
 struct IndexedCell<'a> {
     cell: &'a TonCell,
@@ -1,13 +1,14 @@
 use crate::bail_ton_core_data;
 use crate::bits_utils::BitsUtils;
 use crate::cell::boc::read_var_size::read_var_size;
-use crate::cell::ton_cell::{CellBitWriter, CellBytesReader, CellData, RefStorage};
+use crate::cell::ton_cell::{CellBytesReader, CellData, RefStorage};
 use crate::cell::{CellBorders, CellMeta, CellType, HashesDepthsStorage, LevelMask, TonCell};
 use crate::errors::TonCoreError;
-use bitstream_io::{BitWrite, ByteRead};
+use bitstream_io::{BigEndian, BitWrite, BitWriter, ByteRead};
 use once_cell;
 use once_cell::sync::OnceCell;
 use smallvec::SmallVec;
+use std::io::Write;
 use std::sync::Arc;
 
 /// References are stored as indices in BagOfCells.
@@ -31,7 +32,11 @@ impl RawCell {
         2 + self.data_len_bytes() as u32 + self.refs_pos.len() as u32 * ref_size_bytes
     }
 
-    pub(crate) fn write_to(&self, writer: &mut CellBitWriter, ref_size_bytes: u32) -> std::io::Result<()> {
+    pub(crate) fn write_to<W: Write>(
+        &self,
+        writer: &mut BitWriter<W, BigEndian>,
+        ref_size_bytes: u32,
+    ) -> std::io::Result<()> {
         let level = self.level_mask;
         let is_exotic = self.cell_type.is_exotic() as u32;
         let num_refs = self.refs_pos.len() as u32;
@@ -1,10 +1,15 @@
 use crate::bail_ton_core_data;
+use crate::bits_utils::BitsUtils;
 use crate::cell::CellMeta;
+use crate::cell::CellParser;
 use crate::cell::cell_meta::CellType;
 use crate::cell::ton_cell::{CellBorders, CellData, RefStorage, TonCell};
-use crate::cell::ton_cell_num::TonCellNum;
+use crate::cell::ton_cell_num::{TonCellNum, min_bits_for};
 use crate::errors::{TonCoreError, TonCoreResult};
+use crate::traits::tlb::TLB;
+use crate::types::tlb_core::TLBEither;
 use bitstream_io::{BigEndian, BitWrite, BitWriter, Integer};
+use num_bigint::BigUint;
 use std::any::type_name;
 use std::cmp::min;
 use std::fmt::Display;
@@ -18,6 +23,14 @@ pub struct CellBuilder {
     data_writer: BitWriter<Vec<u8>, BigEndian>,
     data_len_bits: usize,
     refs: RefStorage,
+    fills: Vec<(FieldHandle, Vec<u8>)>, // reserved field -> value bits, applied in build()
+}
+
+/// Reserved region of the builder data, see `CellBuilder::reserve_bits`
+#[derive(Debug, PartialEq, Eq)]
+pub struct FieldHandle {
+    start_bit: usize,
+    bits_len: usize,
 }
 
 impl CellBuilder {
@@ -29,11 +42,12 @@ impl CellBuilder {
             data_writer: BitWriter::endian(data_store, BigEndian),
             data_len_bits: 0,
             refs: RefStorage::new(),
+            fills: Vec::new(),
         }
     }
 
     pub fn build(self) -> Result<TonCell, TonCoreError> {
-        let (mut cell_data, bits_len) = build_cell_data(self.data_writer)?;
+        let (mut cell_data, bits_len) = build_cell_data(self.data_writer, &self.fills)?;
         cell_data.refs = self.refs;
 
         let borders = CellBorders {
@@ -121,6 +135,25 @@ impl CellBuilder {
         Ok(())
     }
 
+    /// Appends unparsed data & refs of the parser (e.g. message tail), leaving the parser empty
+    pub fn write_remaining(&mut self, parser: &mut CellParser) -> Result<(), TonCoreError> {
+        let data_bits_len = parser.data_bits_left()?;
+        let refs_len = parser.refs_left();
+        if !self.can_fit(data_bits_len, refs_len) {
+            bail_ton_core_data!(
+                "Can't write remaining {data_bits_len} bits and {refs_len} refs: {} bits and {} refs left",
+                self.data_bits_left(),
+                self.refs_left()
+            );
+        }
+        let data = parser.read_bits(data_bits_len)?;
+        self.write_bits(&data, data_bits_len)?;
+        for _ in 0..refs_len {
+            self.write_ref(parser.read_next_ref()?.clone())?;
+        }
+        Ok(())
+    }
+
     pub fn write_ref<T: Into<TonCell>>(&mut self, cell: T) -> Result<(), TonCoreError> {
         if self.refs.len() >= TonCell::MAX_REFS_COUNT {
             bail_ton_core_data!("Can't add more refs: {} refs are written already", TonCell::MAX_REFS_COUNT);
@@ -129,6 +162,29 @@ impl CellBuilder {
         Ok(())
     }
 
+    /// Writes `bits_len` zero bits to be filled later with `fill()` (e.g. length of the data written after it)
+    pub fn reserve_bits(&mut self, bits_len: usize) -> Result<FieldHandle, TonCoreError> {
+        let start_bit = self.data_len_bits;
+        self.write_bits(vec![0; bits_len.div_ceil(8)], bits_len)?;
+        Ok(FieldHandle { start_bit, bits_len })
+    }
+
+    /// Sets value of the reserved field. Data is patched in `build()`, so it doesn't affect the current position
+    pub fn fill<N: TonCellNum>(&mut self, handle: FieldHandle, value: &N) -> Result<(), TonCoreError> {
+        if handle.start_bit + handle.bits_len > self.data_len_bits {
+            bail_ton_core_data!("Can't fill {handle:?}: only {} bits are written", self.data_len_bits);
+        }
+        let mut value_builder = TonCell::builder();
+        value_builder.write_num(value, handle.bits_len)?;
+        value_builder.data_writer.byte_align()?;
+        let value_bits = value_builder.data_writer.into_writer();
+        self.fills.push((handle, value_bits));
+        Ok(())
+    }
+
+    /// Writes `^T`: serializes value into a new cell and adds it as ref
+    pub fn store_ref<T: TLB>(&mut self, value: &T) -> Result<(), TonCoreError> { self.write_ref(value.to_cell()?) }
+
     pub fn write_num<N: TonCellNum>(
         &mut self,
         data: impl Deref<Target = N>,
@@ -141,20 +197,96 @@ impl CellBuilder {
             }
             bail_ton_core_data!("Can't write number {} in 0 bits", data.deref());
         }
-        if data.tcn_min_bits_len() > bits_len {
-            bail_ton_core_data!(
-                "Can't write num {} in {bits_len} bits, min_bits_len {}",
-                data.deref(),
-                data.tcn_min_bits_len()
-            );
+        let min_bits_len = data.tcn_min_bits_len();
+        if min_bits_len > bits_len {
+            if *data < N::zero() {
+                bail_ton_core_data!(
+                    "Can't write num: value {} needs at least {min_bits_len} bits ({} for magnitude + 1 sign), got bits_len={bits_len}",
+                    data.deref(),
+                    min_bits_len - 1
+                );
+            }
+            bail_ton_core_data!("Can't write num {} in {bits_len} bits, min_bits_len {min_bits_len}", data.deref());
         }
         data.tcn_write_bits(self, bits_len)
     }
 
+    /// Writes number as `byte_len` bytes in little-endian order. Position must be byte-aligned
+    pub fn write_num_le<N: TonCellNum>(
+        &mut self,
+        data: impl Deref<Target = N>,
+        byte_len: usize,
+    ) -> Result<(), TonCoreError> {
+        if self.data_len_bits % 8 != 0 {
+            bail_ton_core_data!("Can't write little-endian num: position {} is not byte-aligned", self.data_len_bits);
+        }
+        let mut be_builder = TonCell::builder();
+        be_builder.write_num(data, byte_len * 8)?;
+        let mut bytes = be_builder.data_writer.into_writer();
+        bytes.reverse();
+        self.write_bits(bytes, byte_len * 8)
+    }
+
+    /// Same as `write_num`, but returns `TonCoreError::NumOverflow` with `required_bits` if value doesn't fit
+    pub fn write_num_checked<N: TonCellNum>(
+        &mut self,
+        data: impl Deref<Target = N>,
+        bits_len: usize,
+    ) -> Result<(), TonCoreError> {
+        let required_bits = min_bits_for(data.deref());
+        if required_bits > bits_len {
+            return Err(TonCoreError::NumOverflow {
+                value: data.to_string(),
+                bits_len,
+                required_bits,
+            });
+        }
+        self.write_num(data, bits_len)
+    }
+
+    /// Writes `VarUInteger max_bytes`: length in bytes (`ceil(log2(max_bytes))` bits), then value itself
+    pub fn write_var_uint(&mut self, value: &BigUint, max_bytes: usize) -> Result<(), TonCoreError> {
+        let len_bits_len = var_uint_len_bits_len(max_bytes)?;
+        let bytes_len = value.bits().div_ceil(8) as usize;
+        if bytes_len >= max_bytes {
+            bail_ton_core_data!("Can't write {value} as VarUInteger {max_bytes}: {bytes_len} bytes required");
+        }
+        self.write_num(&bytes_len, len_bits_len)?;
+        self.write_num(value, bytes_len * 8)
+    }
+
+    /// Writes `#<= upper_bound`: value in `ceil(log2(upper_bound + 1))` bits
+    pub fn write_uint_leq(&mut self, value: u64, upper_bound: u64) -> Result<(), TonCoreError> {
+        if value > upper_bound {
+            bail_ton_core_data!("Can't write {value} as #<= {upper_bound}");
+        }
+        self.write_num(&value, uint_leq_bits_len(upper_bound))
+    }
+
+    /// Writes `#< upper_bound` (the same as `#<= upper_bound - 1`)
+    pub fn write_uint_less(&mut self, value: u64, upper_bound: u64) -> Result<(), TonCoreError> {
+        if value >= upper_bound {
+            bail_ton_core_data!("Can't write {value} as #< {upper_bound}");
+        }
+        self.write_num(&value, uint_leq_bits_len(upper_bound - 1))
+    }
+
+    /// Writes `Either L R`: selector bit, then left or right value
+    pub fn write_either<L: TLB, R: TLB>(&mut self, value: &TLBEither<L, R>) -> Result<(), TonCoreError> {
+        value.write(self)
+    }
+
     pub fn data_bits_left(&self) -> usize { TonCell::MAX_DATA_LEN_BITS - self.data_len_bits }
 
     pub fn refs_left(&self) -> usize { TonCell::MAX_REFS_COUNT - self.refs.len() }
 
+    pub fn refs_count(&self) -> usize { self.refs.len() }
+
+    /// Checks if `bits` and `refs` can be written without exceeding cell limits
+    pub fn can_fit(&self, bits: usize, refs: usize) -> bool {
+        bits <= self.data_bits_left() && refs <= self.refs_left()
+    }
+
     pub fn set_type(&mut self, cell_type: CellType) { self.cell_type = cell_type; }
 
     #[inline(always)]
@@ -182,13 +314,30 @@ impl CellBuilder {
     }
 }
 
-fn build_cell_data(mut bit_writer: BitWriter<Vec<u8>, BigEndian>) -> TonCoreResult<(CellData, usize)> {
+pub(crate) fn var_uint_len_bits_len(max_bytes: usize) -> TonCoreResult<usize> {
+    if max_bytes < 2 {
+        bail_ton_core_data!("VarUInteger max_bytes must be >= 2, got {max_bytes}");
+    }
+    Ok((usize::BITS - (max_bytes - 1).leading_zeros()) as usize)
+}
+
+pub(crate) fn uint_leq_bits_len(upper_bound: u64) -> usize { (u64::BITS - upper_bound.leading_zeros()) as usize }
+
+fn build_cell_data(
+    mut bit_writer: BitWriter<Vec<u8>, BigEndian>,
+    fills: &[(FieldHandle, Vec<u8>)],
+) -> TonCoreResult<(CellData, usize)> {
     let mut trailing_zeros = 0;
     while !bit_writer.byte_aligned() {
         bit_writer.write_bit(false)?;
         trailing_zeros += 1;
     }
-    let data = bit_writer.into_writer();
+    let mut data = bit_writer.into_writer();
+    for (handle, value_bits) in fills {
+        if !BitsUtils::rewrite(value_bits, 0, &mut data, handle.start_bit, handle.bits_len) {
+            bail_ton_core_data!("Can't fill {handle:?}");
+        }
+    }
     let bits_len = data.len() * 8 - trailing_zeros;
     let cell_data = CellData {
         data_storage: Arc::new(data),
@@ -202,7 +351,6 @@ mod tests {
     use super::*;
     use crate::cell::TonHash;
     use crate::cell::cell_meta::LevelMask;
-    use num_bigint::BigUint;
     use num_traits::FromPrimitive;
     use std::str::FromStr;
     use tokio_test::{assert_err, assert_ok};
@@ -293,7 +441,12 @@ mod tests {
     #[test]
     fn test_builder_write_num_negative() -> anyhow::Result<()> {
         let mut cell_builder = TonCell::builder();
-        assert!(cell_builder.write_num(&-3i32, 2).is_err());
+        let err = assert_err!(cell_builder.write_num(&-3i32, 2));
+        assert!(err.to_string().contains("value -3 needs at least 3 bits (2 for magnitude + 1 sign), got bits_len=2"));
+        let err = assert_err!(cell_builder.write_num(&-500i16, 9));
+        assert!(
+            err.to_string().contains("value -500 needs at least 10 bits (9 for magnitude + 1 sign), got bits_len=9")
+        );
         cell_builder.write_num(&-3i16, 16)?;
         cell_builder.write_num(&-3i8, 8)?;
         let cell = cell_builder.build()?;
@@ -301,6 +454,82 @@ mod tests {
         Ok(())
     }
 
+    #[test]
+    fn test_builder_write_num_checked() -> anyhow::Result<()> {
+        let required_bits = |res: Result<(), TonCoreError>| match res {
+            Err(TonCoreError::NumOverflow { required_bits, .. }) => Some(required_bits),
+            _ => None,
+        };
+        let mut cell_builder = TonCell::builder();
+        assert_eq!(required_bits(cell_builder.write_num_checked(&-3i32, 2)), Some(3));
+        assert_eq!(required_bits(cell_builder.write_num_checked(&-1i8, 0)), Some(1));
+        assert_eq!(required_bits(cell_builder.write_num_checked(&127i16, 7)), Some(8));
+        assert_eq!(required_bits(cell_builder.write_num_checked(&0b1010u8, 3)), Some(4));
+        assert_eq!(required_bits(cell_builder.write_num_checked(&u64::MAX, 63)), Some(64));
+        assert_eq!(required_bits(cell_builder.write_num_checked(&BigUint::from(256u32), 8)), Some(9));
+
+        cell_builder.write_num_checked(&-3i32, 3)?;
+        cell_builder.write_num_checked(&0b1010u8, 5)?;
+        cell_builder.write_num_checked(&0u8, 0)?;
+        let cell = cell_builder.build()?;
+        assert_eq!(cell.data_len_bits(), 8);
+        assert_eq!(cell.underlying_storage(), &[0b1010_1010]);
+        assert_eq!(min_bits_for(&-3i32), 3);
+        assert!(matches!(TonCell::builder().write_num(&10u8, 3), Err(TonCoreError::DataError { .. })));
+        Ok(())
+    }
+
+    #[test]
+    fn test_builder_write_var_uint() -> anyhow::Result<()> {
+        use crate::traits::tlb::TLB;
+        use crate::types::tlb_core::TLBCoins;
+
+        // VarUInteger 16 (grams): 4 bits of len
+        let mut builder = TonCell::builder();
+        builder.write_var_uint(&BigUint::from(0u32), 16)?;
+        builder.write_var_uint(&BigUint::from(0x0102u32), 16)?;
+        let cell = builder.build()?;
+        assert_eq!(cell.data_len_bits(), 4 + 4 + 16);
+        assert_eq!(cell.underlying_storage(), &[0b0000_0010, 0b0000_0001, 0b0000_0010]);
+
+        // same layout as TLBCoins
+        let mut builder = TonCell::builder();
+        builder.write_var_uint(&BigUint::from(1_000_000_000u32), 16)?;
+        assert_eq!(builder.build()?, TLBCoins::new(1_000_000_000).to_cell()?);
+
+        // VarUInteger 32: 5 bits of len
+        let mut builder = TonCell::builder();
+        builder.write_var_uint(&BigUint::from(0u32), 32)?;
+        builder.write_var_uint(&BigUint::from(0xFFu32), 32)?;
+        let cell = builder.build()?;
+        assert_eq!(cell.data_len_bits(), 5 + 5 + 8);
+
+        let max_grams = (BigUint::from(1u32) << 120) - 1u32;
+        assert_ok!(TonCell::builder().write_var_uint(&max_grams, 16));
+        assert_err!(TonCell::builder().write_var_uint(&(max_grams + 1u32), 16));
+        assert_err!(TonCell::builder().write_var_uint(&BigUint::from(1u32), 1));
+        Ok(())
+    }
+
+    #[test]
+    fn test_builder_write_uint_leq() -> anyhow::Result<()> {
+        for (upper_bound, bits_len) in [(0, 0), (1, 1), (255, 8), (256, 9)] {
+            let mut builder = TonCell::builder();
+            builder.write_uint_leq(0, upper_bound)?;
+            builder.write_uint_leq(upper_bound, upper_bound)?;
+            assert_eq!(builder.build()?.data_len_bits(), bits_len * 2);
+            assert_err!(TonCell::builder().write_uint_leq(upper_bound + 1, upper_bound));
+        }
+        for (upper_bound, bits_len) in [(1, 0), (2, 1), (256, 8), (257, 9)] {
+            let mut builder = TonCell::builder();
+            builder.write_uint_less(upper_bound - 1, upper_bound)?;
+            assert_eq!(builder.build()?.data_len_bits(), bits_len);
+            assert_err!(TonCell::builder().write_uint_less(upper_bound, upper_bound));
+        }
+        assert_err!(TonCell::builder().write_uint_less(0, 0));
+        Ok(())
+    }
+
     #[test]
     fn test_builder_write_num_negative_unaligned() -> anyhow::Result<()> {
         let mut cell_builder = TonCell::builder();
@@ -332,6 +561,68 @@ mod tests {
         Ok(())
     }
 
+    #[test]
+    fn test_builder_write_num_le() -> anyhow::Result<()> {
+        let mut builder = TonCell::builder();
+        builder.write_num_le(&0x01020304u32, 4)?;
+        builder.write_num_le(&0x0102030405060708u64, 8)?;
+        builder.write_num(&0x01020304u32, 32)?;
+        let cell = builder.build()?;
+        assert_eq!(cell.data_bitstring()?, "04030201080706050403020101020304");
+
+        let mut parser = cell.parser();
+        assert_eq!(parser.read_num_le::<u32>(4)?, 0x01020304);
+        assert_eq!(parser.read_num_le::<u64>(8)?, 0x0102030405060708);
+        assert_eq!(parser.read_num::<u32>(32)?, 0x01020304);
+
+        let mut builder = TonCell::builder();
+        builder.write_bit(true)?;
+        assert_err!(builder.write_num_le(&1u32, 4));
+        builder.write_bits([0], 7)?;
+        builder.write_num_le(&1u32, 4)?;
+        let cell = builder.build()?;
+        let mut parser = cell.parser();
+        assert!(parser.read_bit()?);
+        assert_err!(parser.read_num_le::<u32>(4));
+        parser.read_bits(7)?;
+        assert_eq!(parser.read_num_le::<u32>(4)?, 1);
+        Ok(())
+    }
+
+    #[test]
+    fn test_builder_write_remaining() -> anyhow::Result<()> {
+        let mut ref_builder = TonCell::builder();
+        ref_builder.write_bits([1, 2, 3], 24)?;
+        let cell_ref = ref_builder.build()?;
+
+        let mut builder = TonCell::builder();
+        builder.write_num(&0x0f8a7ea5u32, 32)?;
+        builder.write_bits([0b1011_0000], 4)?;
+        builder.write_ref(cell_ref.clone())?;
+        let original = builder.build()?;
+
+        let mut parser = original.parser();
+        let opcode: u32 = parser.read_num(32)?;
+        let mut tail_builder = TonCell::builder();
+        tail_builder.write_remaining(&mut parser)?;
+        assert_ok!(parser.ensure_empty());
+        let tail = tail_builder.build()?;
+        assert_eq!(tail.data_len_bits(), 4);
+        assert_eq!(tail.refs(), &[cell_ref]);
+
+        let mut rebuilt_builder = TonCell::builder();
+        rebuilt_builder.write_num(&opcode, 32)?;
+        rebuilt_builder.write_remaining(&mut tail.parser())?;
+        assert_eq!(rebuilt_builder.build()?, original);
+
+        let mut full_builder = TonCell::builder();
+        full_builder.write_bits([0; 128], TonCell::MAX_DATA_LEN_BITS)?;
+        let mut parser = original.parser();
+        assert_err!(full_builder.write_remaining(&mut parser));
+        assert_eq!(parser.data_bits_left()?, original.data_len_bits());
+        Ok(())
+    }
+
     #[test]
     fn test_builder_write_refs() -> anyhow::Result<()> {
         let mut builder = TonCell::builder();
@@ -588,6 +879,47 @@ mod tests {
         assert_eq!(builder.refs_left(), TonCell::MAX_REFS_COUNT - 1);
         builder.write_ref(cell_ref.clone())?;
         assert_eq!(builder.refs_left(), TonCell::MAX_REFS_COUNT - 2);
+        assert_eq!(builder.refs_count(), 2);
+        assert!(builder.can_fit(TonCell::MAX_DATA_LEN_BITS, 2));
+        assert!(!builder.can_fit(0, 3));
+        builder.write_bit(true)?;
+        assert!(!builder.can_fit(TonCell::MAX_DATA_LEN_BITS, 0));
+        assert!(builder.can_fit(TonCell::MAX_DATA_LEN_BITS - 1, 0));
+        Ok(())
+    }
+
+    #[test]
+    fn test_builder_reserve_fill() -> anyhow::Result<()> {
+        let body = b"hello";
+        let mut builder = TonCell::builder();
+        builder.write_bits([0b1010_0000], 3)?;
+        let len_handle = builder.reserve_bits(8)?;
+        let flag_handle = builder.reserve_bits(1)?;
+        builder.write_bits(body, body.len() * 8)?;
+        builder.write_bit(true)?;
+        builder.fill(len_handle, &(body.len() as u8))?;
+        builder.fill(flag_handle, &1u8)?;
+        let cell = builder.build()?;
+        assert_eq!(cell.data_len_bits(), 3 + 8 + 1 + body.len() * 8 + 1);
+
+        let mut parser = cell.parser();
+        assert_eq!(parser.read_num::<u8>(3)?, 0b101);
+        let len = parser.read_num::<u8>(8)?;
+        assert_eq!(len as usize, body.len());
+        assert!(parser.read_bit()?);
+        assert_eq!(parser.read_bits(len as usize * 8)?, body);
+        assert!(parser.read_bit()?);
+        assert_ok!(parser.ensure_empty());
+
+        // not filled field stays zero
+        let mut builder = TonCell::builder();
+        let _handle = builder.reserve_bits(4)?;
+        builder.write_bits([0xFF], 4)?;
+        assert_eq!(builder.build()?.data_bitstring()?, "0F");
+
+        let mut builder = TonCell::builder();
+        let handle = builder.reserve_bits(4)?;
+        assert_err!(builder.fill(handle, &16u8));
         Ok(())
     }
 }
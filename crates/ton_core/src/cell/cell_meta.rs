@@ -55,6 +55,18 @@ impl CellMeta {
         Ok(depths[level.mask() as usize])
     }
 
+    /// Meta of the cell virtualized at `level`: hashes & depths of higher levels are taken from `level`
+    pub(crate) fn virtualized(&self, cell: &TonCell, level: u8) -> Result<CellMeta, TonCoreError> {
+        let (hashes, depths) = self.get_hashes_depths(cell)?;
+        let virt_index = |index: usize| index.min(level as usize);
+        let hashes = (0..hashes.len()).map(|i| hashes[virt_index(i)].clone()).collect();
+        let depths = (0..depths.len()).map(|i| depths[virt_index(i)]).collect();
+        Ok(CellMeta {
+            level_mask: OnceCell::with_value(self.level_mask(cell).apply(level)),
+            hashes_depths: OnceCell::with_value((hashes, depths)),
+        })
+    }
+
     fn get_hashes_depths(&self, cell: &TonCell) -> Result<(&[TonHash], &[u16]), TonCoreError> {
         let data = self.hashes_depths.get_or_try_init(|| {
             let level_mask = self.level_mask(cell);
@@ -82,7 +94,7 @@ impl CellMeta {
 
     fn level_initialized(&self) -> bool { self.level_mask.get().is_some() }
 
-    fn hash_initialized(&self) -> bool { self.hashes_depths.get().is_some() }
+    pub(crate) fn hash_initialized(&self) -> bool { self.hashes_depths.get().is_some() }
 }
 
 impl Default for CellMeta {
@@ -182,8 +182,7 @@ impl<'a> CellMetaBuilder<'a> {
         let mut depths = Vec::with_capacity(hash_count);
 
         // Iterate through significant levels
-        let sign_levels = (0..=level_mask.level()).filter(|&i| level_mask.is_significant(i));
-        for (hash_pos, level_pos) in sign_levels.enumerate() {
+        for (hash_pos, level_pos) in level_mask.significant_levels().enumerate() {
             if hash_pos < hash_i_offset {
                 continue;
             }
@@ -208,14 +207,17 @@ impl<'a> CellMetaBuilder<'a> {
                     let ref_depth = self.get_ref_depth(cell_ref, level_pos)?;
                     max_ref_depth = max_ref_depth.max(ref_depth);
                 }
-                max_ref_depth + 1
+                max_ref_depth as usize + 1
             };
+            if depth > TonCell::DEFAULT_MAX_DEPTH {
+                bail_ton_core_data!("Cell depth exceeds limit {}", TonCell::DEFAULT_MAX_DEPTH);
+            }
 
             // Calculate Hash
             let repr = self.get_repr_for_data(cur_data, cur_bit_len, level_mask, level_pos)?;
             let hash = TonHash::from_slice(&Sha256::new_with_prefix(repr).finalize())?;
             hashes.push(hash);
-            depths.push(depth);
+            depths.push(depth as u16);
         }
 
         self.resolve_hashes_and_depths(&hashes, &depths, level_mask)
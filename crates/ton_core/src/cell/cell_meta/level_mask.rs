@@ -1,3 +1,5 @@
+use crate::bail_ton_core_data;
+use crate::errors::TonCoreError;
 use std::fmt::Display;
 use std::ops::{BitOr, BitOrAssign, Shr, Sub};
 
@@ -7,14 +9,29 @@ pub struct LevelMask(u8);
 impl LevelMask {
     pub const MIN_LEVEL: LevelMask = LevelMask(0);
     pub const MAX_LEVEL: LevelMask = LevelMask(3);
+    pub const MAX_MASK: u8 = 0b111;
 
     pub const fn new(mask: u8) -> Self { Self(mask) }
+    /// Same as `new`, but rejects masks with levels above 3
+    pub fn try_new(mask: u8) -> Result<Self, TonCoreError> {
+        if mask > Self::MAX_MASK {
+            bail_ton_core_data!("Invalid level mask {mask}: must be <= {}", Self::MAX_MASK);
+        }
+        Ok(Self(mask))
+    }
     pub const fn level(&self) -> u8 { 8 - self.0.leading_zeros() as u8 }
     pub const fn mask(&self) -> u8 { self.0 }
     pub const fn hash_index(&self) -> usize { self.0.count_ones() as usize }
     pub const fn hash_count(&self) -> usize { self.hash_index() + 1 }
     pub const fn is_significant(&self, level: u8) -> bool { level == 0 || ((self.0 >> (level - 1)) % 2 != 0) }
     pub const fn apply(&self, level: u8) -> Self { LevelMask(self.0 & ((1u8 << level) - 1)) }
+    /// Alias for `hash_count`
+    pub const fn hashes_needed(&self) -> usize { self.hash_count() }
+    /// Levels in ascending order, level 0 is always significant
+    pub fn significant_levels(&self) -> impl Iterator<Item = u8> {
+        let mask = *self;
+        (0..=self.level()).filter(move |&level| mask.is_significant(level))
+    }
 }
 
 impl From<LevelMask> for u8 {
@@ -63,4 +80,30 @@ mod tests {
         assert_eq!(mask | 0b100, LevelMask::new(0b1110));
         assert_eq!(mask >> 1, LevelMask::new(0b101));
     }
+
+    #[test]
+    fn test_level_mask_significant_levels() {
+        let cases: [(u8, &[u8]); 5] = [
+            (0, &[0]),
+            (1, &[0, 1]),
+            (3, &[0, 1, 2]),
+            (5, &[0, 1, 3]),
+            (7, &[0, 1, 2, 3]),
+        ];
+        for (mask, expected) in cases {
+            let mask = LevelMask::new(mask);
+            assert_eq!(mask.significant_levels().collect::<Vec<_>>(), expected, "mask {mask}");
+            assert_eq!(mask.hashes_needed(), expected.len(), "mask {mask}");
+            assert_eq!(mask.hashes_needed(), mask.hash_count());
+        }
+    }
+
+    #[test]
+    fn test_level_mask_try_new() {
+        for mask in 0..=7 {
+            assert_eq!(LevelMask::try_new(mask).unwrap(), LevelMask::new(mask));
+        }
+        assert!(LevelMask::try_new(8).is_err());
+        assert!(LevelMask::try_new(u8::MAX).is_err());
+    }
 }
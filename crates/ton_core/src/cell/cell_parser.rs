@@ -1,11 +1,15 @@
 use crate::bail_ton_core_data;
 use crate::cell::TonCell;
+use crate::cell::cell_builder::{uint_leq_bits_len, var_uint_len_bits_len};
 use crate::cell::ton_cell::{CellBitsReader, CellBorders};
 use crate::cell::ton_cell_num::TonCellNum;
 use crate::errors::TonCoreError;
 use crate::errors::TonCoreResult;
+use crate::traits::tlb::TLB;
+use crate::types::tlb_core::TLBEither;
 use bitstream_io::Integer;
 use bitstream_io::{BigEndian, BitRead, BitReader};
+use num_bigint::BigUint;
 use std::any::type_name;
 use std::io::{Cursor, SeekFrom};
 
@@ -67,14 +71,75 @@ impl<'a> CellParser<'a> {
         Ok(())
     }
 
+    /// Zero-copy version of read_bits for byte-aligned position: returns slice of underlying storage
+    pub fn read_bytes_aligned(&mut self, byte_len: usize) -> Result<&'a [u8], TonCoreError> {
+        let reader_pos = self.data_reader.position_in_bits()? as usize;
+        if reader_pos % 8 != 0 {
+            let pos = reader_pos - self.cell.borders.start_bit;
+            bail_ton_core_data!("Can't read aligned bytes: reader position {pos} is not byte-aligned in storage");
+        }
+        self.ensure_enough_bits(byte_len * 8)?;
+        let start = reader_pos / 8;
+        let bytes = &self.cell.cell_data.data_storage[start..start + byte_len];
+        self.data_reader.seek_bits(SeekFrom::Current(byte_len as i64 * 8))?;
+        Ok(bytes)
+    }
+
+    /// Errors if `bits_len` exceeds `N` capacity (`N::TCN_MAX_BITS_LEN`), even if high bits are zero
     pub fn read_num<N: TonCellNum>(&mut self, bits_len: usize) -> Result<N, TonCoreError> {
         if bits_len == 0 {
             return Ok(N::zero());
         }
+        if bits_len > N::TCN_MAX_BITS_LEN {
+            bail_ton_core_data!(
+                "Can't read {bits_len} bits into {} (max bits_len {})",
+                type_name::<N>(),
+                N::TCN_MAX_BITS_LEN
+            );
+        }
         self.ensure_enough_bits(bits_len)?;
         N::tcn_read_bits(self, bits_len)
     }
 
+    /// Reads little-endian number of `byte_len` bytes (e.g. payloads from other chains). Position must be byte-aligned
+    pub fn read_num_le<N: TonCellNum>(&mut self, byte_len: usize) -> Result<N, TonCoreError> {
+        let position = self.cell.data_len_bits() - self.data_bits_left()?;
+        if position % 8 != 0 {
+            bail_ton_core_data!("Can't read little-endian num: position {position} is not byte-aligned");
+        }
+        let mut bytes = self.read_bits(byte_len * 8)?;
+        bytes.reverse();
+        let mut builder = TonCell::builder();
+        builder.write_bits(&bytes, byte_len * 8)?;
+        builder.build()?.parser().read_num(byte_len * 8)
+    }
+
+    /// Reads `VarUInteger max_bytes` (see `CellBuilder::write_var_uint`)
+    pub fn read_var_uint(&mut self, max_bytes: usize) -> Result<BigUint, TonCoreError> {
+        let bytes_len: usize = self.read_num(var_uint_len_bits_len(max_bytes)?)?;
+        self.read_num(bytes_len * 8)
+    }
+
+    /// Reads `#<= upper_bound` (see `CellBuilder::write_uint_leq`)
+    pub fn read_uint_leq(&mut self, upper_bound: u64) -> Result<u64, TonCoreError> {
+        let value: u64 = self.read_num(uint_leq_bits_len(upper_bound))?;
+        if value > upper_bound {
+            bail_ton_core_data!("Read value {value} exceeds #<= {upper_bound}");
+        }
+        Ok(value)
+    }
+
+    /// Reads `#< upper_bound` (see `CellBuilder::write_uint_less`)
+    pub fn read_uint_less(&mut self, upper_bound: u64) -> Result<u64, TonCoreError> {
+        if upper_bound == 0 {
+            bail_ton_core_data!("Can't read #< 0");
+        }
+        self.read_uint_leq(upper_bound - 1)
+    }
+
+    /// Reads `Either L R`: selector bit, then left or right value
+    pub fn read_either<L: TLB, R: TLB>(&mut self) -> Result<TLBEither<L, R>, TonCoreError> { TLBEither::read(self) }
+
     pub fn read_cell(&mut self, bits_len: usize, refs_len: u8) -> Result<TonCell, TonCoreError> {
         let start_bit = self.data_reader.position_in_bits()? as usize - self.cell.borders.start_bit;
         let end_bit = start_bit + bits_len;
@@ -98,6 +163,21 @@ impl<'a> CellParser<'a> {
         self.read_cell(bits_len, refs_len as u8)
     }
 
+    /// Read-only alternative to `read_remaining`: new parser over the same cell, positioned at the current offset
+    ///
+    /// Doesn't create slice (no borders validation & new meta), `self` is not moved.
+    /// Use `read_remaining` if you need the remainder as a cell (e.g. to hash or store it)
+    pub fn peek_remaining(&self) -> CellParser<'a> { self.clone() }
+
+    /// Reads remaining data bits as UTF-8 string (refs are ignored)
+    pub fn read_remaining_string(&mut self) -> Result<String, TonCoreError> {
+        let bits_len = self.data_bits_left()?;
+        if bits_len % 8 != 0 {
+            bail_ton_core_data!("Can't read string: remaining {bits_len} bits are not byte-aligned");
+        }
+        Ok(String::from_utf8(self.read_bits(bits_len)?)?)
+    }
+
     pub fn read_next_ref(&mut self) -> Result<&TonCell, TonCoreError> {
         if self.next_ref_pos == self.cell.borders.end_ref as usize {
             bail_ton_core_data!(
@@ -111,6 +191,17 @@ impl<'a> CellParser<'a> {
         Ok(cell_ref)
     }
 
+    /// Reads `^T`: parses next ref as `T`
+    pub fn load_ref<T: TLB>(&mut self) -> Result<T, TonCoreError> { T::read(&mut self.read_next_ref()?.parser()) }
+
+    /// Same as `load_ref`, but fails if `T` doesn't consume the whole ref
+    pub fn load_ref_exact<T: TLB>(&mut self) -> Result<T, TonCoreError> {
+        let mut ref_parser = self.read_next_ref()?.parser();
+        let value = T::read(&mut ref_parser)?;
+        ref_parser.ensure_empty()?;
+        Ok(value)
+    }
+
     pub fn data_bits_left(&mut self) -> Result<usize, TonCoreError> {
         let reader_pos = self.data_reader.position_in_bits()? as usize;
         Ok(self.cell.borders.end_bit - reader_pos)
@@ -205,7 +296,7 @@ pub struct ParserPosition {
 mod tests {
     use super::*;
     use crate::cell::TonCell;
-    use num_bigint::{BigInt, BigUint};
+    use num_bigint::BigInt;
     use tokio_test::{assert_err, assert_ok};
 
     fn make_test_cell(data: &[u8], bits_len: usize) -> anyhow::Result<TonCell> {
@@ -214,6 +305,108 @@ mod tests {
         Ok(builder.build()?)
     }
 
+    #[test]
+    fn test_parser_read_uint_leq() -> anyhow::Result<()> {
+        let bounds = [0, 1, 255, 256];
+        let mut builder = TonCell::builder();
+        for upper_bound in bounds {
+            builder.write_uint_leq(upper_bound / 2, upper_bound)?;
+            builder.write_uint_leq(upper_bound, upper_bound)?;
+        }
+        builder.write_uint_less(255, 256)?;
+        let cell = builder.build()?;
+
+        let mut parser = cell.parser();
+        for upper_bound in bounds {
+            assert_eq!(parser.read_uint_leq(upper_bound)?, upper_bound / 2);
+            assert_eq!(parser.read_uint_leq(upper_bound)?, upper_bound);
+        }
+        assert_eq!(parser.read_uint_less(256)?, 255);
+        assert_ok!(parser.ensure_empty());
+
+        // 7 fits 3 bits, but exceeds the bound
+        let mut builder = TonCell::builder();
+        builder.write_num(&7u8, 3)?;
+        let cell = builder.build()?;
+        assert_err!(cell.parser().read_uint_leq(5));
+        assert_err!(cell.parser().read_uint_less(0));
+        Ok(())
+    }
+
+    #[test]
+    fn test_parser_read_var_uint() -> anyhow::Result<()> {
+        let max_grams = (BigUint::from(1u32) << 120) - 1u32;
+        let max_var_uint32 = (BigUint::from(1u32) << 248) - 1u32;
+        let mut builder = TonCell::builder();
+        builder.write_var_uint(&BigUint::from(0u32), 16)?;
+        builder.write_var_uint(&max_grams, 16)?;
+        builder.write_var_uint(&BigUint::from(0u32), 32)?;
+        builder.write_var_uint(&max_var_uint32, 32)?;
+        builder.write_var_uint(&BigUint::from(12345u32), 32)?;
+        let cell = builder.build()?;
+
+        let mut parser = cell.parser();
+        assert_eq!(parser.read_var_uint(16)?, BigUint::from(0u32));
+        assert_eq!(parser.read_var_uint(16)?, max_grams);
+        assert_eq!(parser.read_var_uint(32)?, BigUint::from(0u32));
+        assert_eq!(parser.read_var_uint(32)?, max_var_uint32);
+        assert_eq!(parser.read_var_uint(32)?, BigUint::from(12345u32));
+        assert_ok!(parser.ensure_empty());
+        assert_err!(parser.read_var_uint(16));
+        Ok(())
+    }
+
+    #[test]
+    fn test_parser_read_remaining_string() -> anyhow::Result<()> {
+        let mut builder = TonCell::builder();
+        builder.write_num(&0u32, 32)?;
+        builder.write_bits("hello".as_bytes(), 40)?;
+        let cell = builder.build()?;
+        let mut parser = cell.parser();
+        assert_eq!(parser.read_num::<u32>(32)?, 0);
+        assert_eq!(parser.read_remaining_string()?, "hello");
+        assert_eq!(parser.read_remaining_string()?, "");
+
+        let cell = make_test_cell(&[0xC3, 0x28], 16)?;
+        assert_err!(cell.parser().read_remaining_string());
+
+        let cell = make_test_cell("hello".as_bytes(), 39)?;
+        assert_err!(cell.parser().read_remaining_string());
+        Ok(())
+    }
+
+    #[test]
+    fn test_parser_peek_remaining() -> anyhow::Result<()> {
+        let mut builder = TonCell::builder();
+        builder.write_num(&0xAAu8, 8)?;
+        builder.write_num(&0x1234u16, 16)?;
+        builder.write_bits([0b1010_0000], 3)?;
+        builder.write_ref(TonCell::from_bit_str("1")?)?;
+        builder.write_ref(TonCell::from_bit_str("01")?)?;
+        let cell = builder.build()?;
+
+        let mut parser = cell.parser();
+        parser.read_num::<u8>(8)?;
+        parser.read_next_ref()?;
+        let mut peeked = parser.peek_remaining();
+        assert_eq!(peeked.data_bits_left()?, 19);
+        assert_eq!(peeked.refs_left(), 1);
+
+        let remaining = parser.read_remaining()?;
+        let mut remaining_parser = remaining.parser();
+        for parser in [&mut peeked, &mut remaining_parser] {
+            assert_eq!(parser.read_num::<u16>(16)?, 0x1234);
+            assert_eq!(parser.read_bits(3)?, [0b1010_0000]);
+            assert_eq!(parser.read_next_ref()?, &TonCell::from_bit_str("01")?);
+            assert_ok!(parser.ensure_empty());
+        }
+        // peek doesn't move the origin parser
+        let mut parser = cell.parser();
+        assert_eq!(parser.peek_remaining().read_num::<u8>(8)?, 0xAA);
+        assert_eq!(parser.read_num::<u8>(8)?, 0xAA);
+        Ok(())
+    }
+
     #[test]
     fn test_parser_seek_bits() -> anyhow::Result<()> {
         let cell = make_test_cell(&[0b10101001, 0b01010100], 10)?;
@@ -305,6 +498,54 @@ mod tests {
         Ok(())
     }
 
+    #[test]
+    fn test_parser_read_bytes_aligned() -> anyhow::Result<()> {
+        let data = (0..200u8).collect::<Vec<_>>();
+        let cell = make_test_cell(&data[..127], 127 * 8)?;
+        let mut parser = cell.parser();
+        let mut parser_bits = cell.parser();
+        assert_eq!(parser.read_bytes_aligned(0)?, &[] as &[u8]);
+        for chunk_len in [1, 2, 3, 5, 8, 13, 32, 63] {
+            let expected = parser_bits.read_bits(chunk_len * 8)?;
+            assert_eq!(parser.read_bytes_aligned(chunk_len)?, expected.as_slice());
+        }
+        assert_err!(parser.read_bytes_aligned(1));
+        parser.ensure_empty()?;
+
+        // unaligned position
+        let mut parser = cell.parser();
+        parser.read_bit()?;
+        assert_err!(parser.read_bytes_aligned(1));
+        parser.seek_bits(7)?;
+        assert_eq!(parser.read_bytes_aligned(2)?, &data[1..3]);
+
+        // sliced cell starting from unaligned bit
+        let sliced = cell.slice(CellBorders {
+            start_bit: 4,
+            end_bit: 100,
+            start_ref: 0,
+            end_ref: 0,
+        })?;
+        let mut parser = sliced.parser();
+        assert_err!(parser.read_bytes_aligned(1));
+        parser.read_bits(4)?;
+        assert_eq!(parser.read_bytes_aligned(4)?, &data[1..5]);
+        Ok(())
+    }
+
+    #[test]
+    fn test_parser_read_bytes_aligned_many_cells() -> anyhow::Result<()> {
+        let cells =
+            (0..1000u32).map(|i| make_test_cell(&[(i % 256) as u8; 32], 256)).collect::<anyhow::Result<Vec<_>>>()?;
+        for cell in &cells {
+            let expected = cell.parser().read_bits(256)?;
+            let mut parser = cell.parser();
+            assert_eq!(parser.read_bytes_aligned(32)?, expected.as_slice());
+            assert_ok!(parser.ensure_empty());
+        }
+        Ok(())
+    }
+
     #[test]
     fn test_parser_read_num() -> anyhow::Result<()> {
         let cell = make_test_cell(&[0b10101010, 0b01010101], 16)?;
@@ -1,5 +1,6 @@
 use crate::bail_ton_core_data;
 use crate::bits_utils::BitsUtils;
+use crate::cell::BoC;
 use crate::cell::cell_builder::INITIAL_STORAGE_CAPACITY;
 use crate::cell::cell_meta::CellMeta;
 use crate::cell::cell_meta::CellType;
@@ -7,11 +8,13 @@ use crate::cell::raw_boc::RawBoC;
 use crate::cell::ton_hash::TonHash;
 use crate::cell::{CellBuilder, CellParser, LevelMask};
 use crate::errors::TonCoreError;
+use crate::traits::tlb::TLB;
 use bitstream_io::{BigEndian, BitReader, BitWriter, ByteReader};
 use smallvec::SmallVec;
 use std::fmt::Formatter;
 use std::io::Cursor;
 use std::ops::Deref;
+use std::path::Path;
 use std::sync::{Arc, LazyLock};
 
 /// ```rust
@@ -39,13 +42,49 @@ impl TonCell {
         126, 65, 11, 120, 99, 10, 9, 207, 199,
     ]);
     pub const EMPTY_BOC: &'static [u8] = &[181, 238, 156, 114, 1, 1, 1, 1, 0, 2, 0, 0, 0];
+    /// TON limits cell depth by 1024. Used by hash calculation and by BoC parser unless other limit is passed
+    pub const DEFAULT_MAX_DEPTH: usize = 1024;
 
     pub fn empty() -> &'static Self { EMPTY_CELL.deref() }
 
+    /// Same as `TonCell::from_boc`, but fails if BoC has crc32c and it doesn't match the data
+    pub fn from_boc_verified<T: Into<Arc<Vec<u8>>>>(boc: T) -> Result<TonCell, TonCoreError> {
+        BoC::from_bytes_verified(boc)?.single_root()
+    }
+
+    /// Same as `TonCell::from_boc`, but fails if cell tree is deeper than `max_depth`
+    pub fn from_boc_with_max_depth<T: Into<Arc<Vec<u8>>>>(boc: T, max_depth: usize) -> Result<TonCell, TonCoreError> {
+        BoC::from_bytes_with_max_depth(boc, max_depth)?.single_root()
+    }
+
+    /// Writes the cell as BoC file (without crc32), e.g. to keep it as test fixture
+    pub fn save_boc<P: AsRef<Path>>(&self, path: P) -> Result<(), TonCoreError> {
+        Ok(std::fs::write(path, self.to_boc()?)?)
+    }
+
+    pub fn load_boc<P: AsRef<Path>>(path: P) -> Result<TonCell, TonCoreError> {
+        TonCell::from_boc(std::fs::read(path)?)
+    }
+
     pub fn builder() -> CellBuilder { CellBuilder::new(CellType::Ordinary, INITIAL_STORAGE_CAPACITY) }
     pub fn builder_extra(cell_type: CellType, initial_capacity: usize) -> CellBuilder {
         CellBuilder::new(cell_type, initial_capacity)
     }
+
+    /// Builds ordinary cell from a string of `0`/`1`, e.g. `"1000_10"`. Underscores and whitespaces are ignored
+    pub fn from_bit_str(bits: &str) -> Result<TonCell, TonCoreError> {
+        let mut builder = TonCell::builder();
+        for char in bits.chars() {
+            match char {
+                '0' | '1' => builder.write_bit(char == '1')?,
+                '_' => {}
+                _ if char.is_whitespace() => {}
+                _ => bail_ton_core_data!("Invalid char {char:?} in bit string: {bits}"),
+            }
+        }
+        builder.build()
+    }
+
     // This function was originally written to traverse once and avoid allocating unified memory, but benchmarking showed that this implementation performs better.
     pub fn deep_copy(&self) -> Result<TonCell, TonCoreError> {
         let mut raw_boc = RawBoC::from_ton_cells(std::slice::from_ref(self), true)?;
@@ -83,7 +122,7 @@ impl TonCell {
         }
 
         raw_boc.roots_pos = SmallVec::from_elem(0, 1);
-        raw_boc.into_ton_cells().map(|mut vec| vec.swap_remove(0))
+        raw_boc.into_ton_cells(Self::DEFAULT_MAX_DEPTH).map(|mut vec| vec.swap_remove(0))
     }
 
     // Borders are relative to origin cell
@@ -121,7 +160,34 @@ impl TonCell {
 
     pub fn cell_type(&self) -> CellType { self.cell_type }
     pub fn level_mask(&self) -> LevelMask { self.meta.level_mask(self) }
+    pub fn level(&self) -> u8 { self.level_mask().level() }
+    pub fn is_exotic(&self) -> bool { self.cell_type.is_exotic() }
+    /// Guard for TL-B parsers which don't accept exotic cells
+    pub fn assert_ordinary(&self) -> Result<(), TonCoreError> {
+        if self.is_exotic() {
+            bail_ton_core_data!("Expected ordinary cell, got {:?}", self.cell_type);
+        }
+        Ok(())
+    }
     pub fn hash(&self) -> Result<&TonHash, TonCoreError> { self.hash_for_level(LevelMask::MAX_LEVEL) }
+    pub fn hash_hex(&self) -> Result<String, TonCoreError> { Ok(self.hash()?.to_hex()) }
+    pub fn hash_base64(&self) -> Result<String, TonCoreError> { Ok(self.hash()?.to_base64()) }
+    /// Representation hash (the one with max level), the same as `hash()`, but owned
+    pub fn repr_hash(&self) -> Result<TonHash, TonCoreError> { self.hash().cloned() }
+    /// Calculates hashes & depths for all levels of the cell and its subtree, so later calls are cheap
+    pub fn precompute_hashes(&self) -> Result<(), TonCoreError> {
+        // all levels are calculated and cached at once
+        self.hash_for_level(LevelMask::MIN_LEVEL).map(|_| ())
+    }
+    /// Same as `precompute_hashes`, but subtrees are processed in parallel with `rayon` feature enabled
+    ///
+    /// Children are always calculated before their parents, results are cached in cells meta
+    pub fn compute_hashes_parallel(&self) -> Result<(), TonCoreError> {
+        #[cfg(feature = "rayon")]
+        self.compute_children_hashes_parallel(0)?;
+        self.precompute_hashes()
+    }
+    pub fn is_hash_cached(&self) -> bool { self.meta.hash_initialized() }
     pub fn depth(&self) -> Result<u16, TonCoreError> { self.depth_for_level(LevelMask::MAX_LEVEL) }
     pub fn refs(&self) -> &[TonCell] {
         &self.cell_data.refs[self.borders.start_ref as usize..self.borders.end_ref as usize]
@@ -135,9 +201,39 @@ impl TonCell {
         self.meta.depth_for_level(self, level)
     }
 
-    pub fn data_bitstring(&self) -> Result<String, std::fmt::Error> {
-        use std::fmt::Write;
+    /// For `MerkleProof` cell returns its only ref (the proven cell) virtualized at the level of the proof
+    ///
+    /// Levels introduced by pruned branches are cut off in the whole subtree, so `hash()` and `depth()`
+    /// are the ones of the original cells (e.g. level 0 for a regular proof). Pruned branches are still exotic cells.
+    /// The result is meant for parsing & hash checks, not for serialization
+    pub fn virtual_root(&self) -> Result<TonCell, TonCoreError> {
+        if self.cell_type != CellType::MerkleProof {
+            bail_ton_core_data!("virtual_root: expected MerkleProof cell, got {:?}", self.cell_type);
+        }
+        self.refs()[0].virtualize(self.level())
+    }
+
+    /// Compares root data bits only: refs and cell type are ignored
+    pub fn data_eq(&self, other: &TonCell) -> bool {
+        let bits_len = self.data_len_bits();
+        bits_len == other.data_len_bits() && BitsUtils::equal(&self.data_aligned(), &other.data_aligned(), bits_len)
+    }
+
+    /// Compares root refs by hash as a multiset (order is ignored). Returns false if any hash can't be calculated
+    pub fn refs_multiset_eq(&self, other: &TonCell) -> bool {
+        let sorted_hashes = |cell: &TonCell| {
+            let mut hashes = cell.refs().iter().map(|x| x.hash().cloned()).collect::<Result<Vec<_>, _>>()?;
+            hashes.sort();
+            Ok::<_, TonCoreError>(hashes)
+        };
+        match (sorted_hashes(self), sorted_hashes(other)) {
+            (Ok(left), Ok(right)) => left == right,
+            _ => false,
+        }
+    }
 
+    // data bits moved to the beginning of the buffer
+    fn data_aligned(&self) -> Vec<u8> {
         let mut cell_data = vec![0; self.data_len_bits().div_ceil(8)];
         BitsUtils::read_with_offset(
             &self.cell_data.data_storage,
@@ -145,6 +241,48 @@ impl TonCell {
             self.borders.start_bit,
             self.data_len_bits(),
         );
+        cell_data
+    }
+
+    // the same cell with level mask, hashes & depths cut to `level`. Subtrees without higher levels are reused as is
+    fn virtualize(&self, level: u8) -> Result<TonCell, TonCoreError> {
+        if self.level() <= level {
+            return Ok(self.clone());
+        }
+        let refs = self.refs().iter().map(|cell_ref| cell_ref.virtualize(level)).collect::<Result<RefStorage, _>>()?;
+        let borders = CellBorders {
+            start_ref: 0,
+            end_ref: refs.len() as u8,
+            ..self.borders
+        };
+        Ok(TonCell {
+            cell_type: self.cell_type,
+            cell_data: Arc::new(CellData {
+                data_storage: self.cell_data.data_storage.clone(),
+                refs,
+            }),
+            borders,
+            meta: Arc::new(self.meta.virtualized(self, level)?),
+        })
+    }
+
+    #[cfg(feature = "rayon")]
+    fn compute_children_hashes_parallel(&self, depth: usize) -> Result<(), TonCoreError> {
+        use rayon::prelude::*;
+        // too deep trees are left to sequential calculation, which reports the error
+        if self.is_hash_cached() || depth >= Self::DEFAULT_MAX_DEPTH {
+            return Ok(());
+        }
+        self.refs().par_iter().try_for_each(|child| {
+            child.compute_children_hashes_parallel(depth + 1)?;
+            child.precompute_hashes()
+        })
+    }
+
+    pub fn data_bitstring(&self) -> Result<String, std::fmt::Error> {
+        use std::fmt::Write;
+
+        let mut cell_data = self.data_aligned();
 
         let non_complete_bits = self.data_len_bits() % 8;
         if non_complete_bits != 0 {
@@ -173,6 +311,13 @@ impl TonCell {
         Ok(data_display)
     }
 
+    /// Dumps cell tree in the same format as fift `csr.`: `x{...}` per cell, indented by depth
+    pub fn dump_fift(&self) -> String {
+        let mut output = String::new();
+        write_cell_fift(&mut output, self, 0);
+        output
+    }
+
     #[cfg(test)]
     pub(crate) fn underlying_storage(&self) -> &[u8] { &self.cell_data.data_storage }
 }
@@ -256,10 +401,43 @@ fn write_cell_display(f: &mut Formatter<'_>, cell: &TonCell, indent_level: usize
     }
 }
 
+fn write_cell_fift(output: &mut String, cell: &TonCell, indent_level: usize) {
+    use std::fmt::Write;
+
+    let bits_len = cell.data_len_bits();
+    let mut cell_data = cell.data_aligned();
+
+    // fift works with nibbles: the last one gets completion tag if it's not full
+    let nibbles_len = bits_len.div_ceil(4);
+    let non_complete_bits = bits_len % 4;
+    if non_complete_bits != 0 {
+        let last_byte = &mut cell_data[(bits_len - 1) / 8];
+        let used_bits = bits_len % 8;
+        *last_byte &= 0xFFu8 << (8 - used_bits);
+        *last_byte |= 1u8 << (7 - used_bits);
+    }
+    let mut data_hex = String::with_capacity(nibbles_len + 1);
+    for nibble_pos in 0..nibbles_len {
+        let byte = cell_data[nibble_pos / 2];
+        let nibble = if nibble_pos % 2 == 0 { byte >> 4 } else { byte & 0x0F };
+        let _ = write!(data_hex, "{nibble:X}");
+    }
+    if non_complete_bits != 0 {
+        data_hex.push('_');
+    }
+
+    let special = if cell.cell_type.is_exotic() { "SPECIAL " } else { "" };
+    let _ = writeln!(output, "{}{special}x{{{data_hex}}}", " ".repeat(indent_level));
+    for cell_ref in cell.refs() {
+        write_cell_fift(output, cell_ref, indent_level + 1);
+    }
+}
+
 #[cfg(test)]
 mod tests {
-    use crate::cell::{BoC, CellBorders, TonCell};
+    use crate::cell::{BoC, CellBorders, CellType, LevelMask, TonCell, TonHash};
     use std::sync::Arc;
+    use tokio_test::{assert_err, assert_ok};
 
     #[test]
     fn test_ton_cell_slice() -> anyhow::Result<()> {
@@ -344,6 +522,116 @@ mod tests {
         Ok(())
     }
 
+    #[test]
+    fn test_ton_cell_precompute_hashes() -> anyhow::Result<()> {
+        let mut child_builder = TonCell::builder();
+        child_builder.write_num(&7u8, 8)?;
+        let child = child_builder.build()?;
+        let mut builder = TonCell::builder();
+        builder.write_bits([1, 2, 3], 24)?;
+        builder.write_ref(child)?;
+        let cell = builder.build()?;
+
+        assert!(!cell.is_hash_cached());
+        assert!(!cell.refs()[0].is_hash_cached());
+        cell.precompute_hashes()?;
+        assert!(cell.is_hash_cached());
+        assert!(cell.refs()[0].is_hash_cached());
+
+        // slice has its own meta
+        let slice = cell.slice(CellBorders {
+            start_bit: 8,
+            end_bit: 24,
+            start_ref: 0,
+            end_ref: 1,
+        })?;
+        assert!(!slice.is_hash_cached());
+        slice.precompute_hashes()?;
+        assert!(slice.is_hash_cached());
+        Ok(())
+    }
+
+    #[test]
+    fn test_ton_cell_compute_hashes_parallel() -> anyhow::Result<()> {
+        // wide & deep tree: every cell has 4 refs, shared subtrees included
+        let build_tree = |depth: usize| -> anyhow::Result<TonCell> {
+            let mut level = (0..64u32)
+                .map(|i| {
+                    let mut builder = TonCell::builder();
+                    builder.write_num(&i, 32)?;
+                    builder.build()
+                })
+                .collect::<Result<Vec<_>, _>>()?;
+            for d in 0..depth {
+                level = (0..64)
+                    .map(|i| {
+                        let mut builder = TonCell::builder();
+                        builder.write_num(&(d * 64 + i), 32)?;
+                        for j in 0..4 {
+                            builder.write_ref(level[(i + j * 7) % level.len()].clone())?;
+                        }
+                        builder.build()
+                    })
+                    .collect::<Result<Vec<_>, _>>()?;
+            }
+            let mut builder = TonCell::builder();
+            for child in level.into_iter().take(4) {
+                builder.write_ref(child)?;
+            }
+            Ok(builder.build()?)
+        };
+
+        let parallel = build_tree(32)?;
+        let sequential = build_tree(32)?;
+        assert!(!parallel.is_hash_cached());
+        parallel.compute_hashes_parallel()?;
+        assert!(parallel.is_hash_cached());
+        assert!(parallel.refs().iter().all(|x| x.is_hash_cached()));
+        assert_eq!(parallel.hash()?, sequential.hash()?);
+        assert_eq!(parallel.depth()?, sequential.depth()?);
+        Ok(())
+    }
+
+    #[test]
+    fn test_ton_cell_depth_limit() -> anyhow::Result<()> {
+        // chain of empty cells, each one refers to the next one
+        let make_chain_boc = |cells_cnt: usize| -> Vec<u8> {
+            let mut boc = vec![0xb5, 0xee, 0x9c, 0x72, 0x02, 0x02];
+            boc.extend((cells_cnt as u16).to_be_bytes()); // cells
+            boc.extend(1u16.to_be_bytes()); // roots
+            boc.extend(0u16.to_be_bytes()); // absent
+            boc.extend(((cells_cnt as u16 - 1) * 4 + 2).to_be_bytes()); // tot_cells_size
+            boc.extend(0u16.to_be_bytes()); // root_list
+            for i in 1..cells_cnt {
+                boc.extend([1, 0]);
+                boc.extend((i as u16).to_be_bytes());
+            }
+            boc.extend([0, 0]);
+            boc
+        };
+        let max_depth = TonCell::DEFAULT_MAX_DEPTH;
+        let cell = BoC::from_bytes(make_chain_boc(max_depth + 1))?.single_root()?;
+        assert_eq!(cell.depth()?, max_depth as u16);
+
+        let err = BoC::from_bytes(make_chain_boc(max_depth + 2)).err().unwrap();
+        assert!(err.to_string().contains("depth exceeds limit 1024"), "{err}");
+
+        let err = TonCell::from_boc_with_max_depth(make_chain_boc(11), 9).err().unwrap();
+        assert!(err.to_string().contains("depth exceeds limit 9"), "{err}");
+        assert_eq!(TonCell::from_boc_with_max_depth(make_chain_boc(11), 10)?.depth()?, 10);
+
+        let mut cell = TonCell::empty().clone();
+        for _ in 0..max_depth + 1 {
+            let mut builder = TonCell::builder();
+            builder.write_ref(cell)?;
+            cell = builder.build()?;
+        }
+        let err = cell.hash().err().unwrap();
+        assert!(err.to_string().contains("depth exceeds limit 1024"), "{err}");
+        assert!(cell.deep_copy().is_err());
+        Ok(())
+    }
+
     #[test]
     fn test_ton_cell_view() -> anyhow::Result<()> {
         // https://ton.org/tvm.pdf
@@ -362,4 +650,248 @@ mod tests {
         assert!(repr.contains("data: [8A_]"));
         Ok(())
     }
+
+    #[test]
+    fn test_ton_cell_from_bit_str() -> anyhow::Result<()> {
+        let cell = TonCell::from_bit_str("100010")?;
+        assert_eq!(cell.data_len_bits(), 6);
+        assert_eq!(cell.data_bitstring()?, "8A_");
+        assert_eq!(cell.dump_fift(), "x{8A_}\n");
+
+        let cell = TonCell::from_bit_str("1010_1011 1111\n0000")?;
+        assert_eq!(cell.data_len_bits(), 16);
+        assert_eq!(cell.data_bitstring()?, "ABF0");
+        assert_eq!(TonCell::from_bit_str("")?, TonCell::empty().clone());
+
+        assert_err!(TonCell::from_bit_str("10201"));
+        assert_err!(TonCell::from_bit_str(&"1".repeat(TonCell::MAX_DATA_LEN_BITS + 1)));
+        Ok(())
+    }
+
+    #[test]
+    fn test_ton_cell_virtual_root() -> anyhow::Result<()> {
+        let make_proof = |cell: &TonCell| {
+            let mut builder = TonCell::builder_extra(CellType::MerkleProof, 64);
+            builder.write_num(&3u8, 8)?; // cell type
+            builder.write_bits(cell.hash_for_level(LevelMask::new(0))?, TonHash::BITS_LEN)?;
+            builder.write_num(&cell.depth_for_level(LevelMask::new(0))?, 16)?;
+            builder.write_ref(cell.clone())?;
+            builder.build()
+        };
+        let pruned_child = TonCell::from_bit_str("1011")?;
+        let mut builder = TonCell::builder();
+        builder.write_ref(pruned_child.clone())?;
+        builder.write_ref(TonCell::from_bit_str("101")?)?;
+        let child = builder.build()?;
+        let mut builder = TonCell::builder();
+        builder.write_num(&0xABCDu16, 16)?;
+        builder.write_ref(child.clone())?;
+        let cell = builder.build()?;
+
+        // nothing is pruned: virtual root is the cell itself
+        let virtual_root = make_proof(&cell)?.virtual_root()?;
+        assert_eq!(virtual_root.hash()?, cell.hash()?);
+        assert_eq!(virtual_root.parser().read_num::<u16>(16)?, 0xABCD);
+
+        // the first child of the child is pruned
+        let mut builder = TonCell::builder_extra(CellType::PrunedBranch, 64);
+        builder.write_num(&1u8, 8)?; // cell type
+        builder.write_num(&1u8, 8)?; // level mask
+        builder.write_bits(pruned_child.hash()?, TonHash::BITS_LEN)?;
+        builder.write_num(&pruned_child.depth()?, 16)?;
+        let pruned = builder.build()?;
+        let mut builder = TonCell::builder();
+        builder.write_ref(pruned)?;
+        builder.write_ref(TonCell::from_bit_str("101")?)?;
+        let proof_child = builder.build()?;
+        let mut builder = TonCell::builder();
+        builder.write_num(&0xABCDu16, 16)?;
+        builder.write_ref(proof_child)?;
+        let proof_cell = builder.build()?;
+        assert_eq!(proof_cell.level(), 1);
+        assert_ne!(proof_cell.hash()?, cell.hash()?);
+
+        let proof = make_proof(&proof_cell)?;
+        assert_eq!(proof.level(), 0);
+        let virtual_root = proof.virtual_root()?;
+        assert_eq!(virtual_root.level(), 0);
+        assert_eq!(virtual_root.hash()?, cell.hash()?);
+        assert_eq!(virtual_root.depth()?, cell.depth()?);
+        assert_eq!(virtual_root.parser().read_num::<u16>(16)?, 0xABCD);
+
+        let virtual_child = &virtual_root.refs()[0];
+        assert_eq!(virtual_child.hash()?, child.hash()?);
+        assert_eq!(virtual_child.refs()[0].cell_type(), CellType::PrunedBranch);
+        assert_eq!(virtual_child.refs()[0].hash()?, pruned_child.hash()?);
+        assert!(virtual_child.refs()[1].data_eq(&TonCell::from_bit_str("101")?));
+
+        assert_err!(cell.virtual_root());
+        assert_err!(virtual_root.virtual_root());
+        Ok(())
+    }
+
+    #[test]
+    fn test_ton_cell_level_is_exotic() -> anyhow::Result<()> {
+        let child = TonCell::from_bit_str("101")?;
+        let mut builder = TonCell::builder_extra(CellType::PrunedBranch, 64);
+        builder.write_num(&1u8, 8)?; // cell type
+        builder.write_num(&1u8, 8)?; // level mask
+        builder.write_bits(child.hash()?, TonHash::BITS_LEN)?;
+        builder.write_num(&child.depth()?, 16)?;
+        let pruned = builder.build()?;
+
+        let mut builder = TonCell::builder();
+        builder.write_ref(pruned.clone())?;
+        builder.write_ref(child.clone())?;
+        let root = builder.build()?;
+
+        let mut builder = TonCell::builder_extra(CellType::MerkleProof, 64);
+        builder.write_num(&3u8, 8)?; // cell type
+        builder.write_bits(root.hash_for_level(LevelMask::new(0))?, TonHash::BITS_LEN)?;
+        builder.write_num(&root.depth_for_level(LevelMask::new(0))?, 16)?;
+        builder.write_ref(root.clone())?;
+        let proof = builder.build()?;
+
+        let mut builder = TonCell::builder_extra(CellType::MerkleUpdate, 128);
+        builder.write_num(&4u8, 8)?; // cell type
+        builder.write_bits(pruned.hash_for_level(LevelMask::new(0))?, TonHash::BITS_LEN)?;
+        builder.write_bits(pruned.hash_for_level(LevelMask::new(0))?, TonHash::BITS_LEN)?;
+        builder.write_num(&pruned.depth_for_level(LevelMask::new(0))?, 16)?;
+        builder.write_num(&pruned.depth_for_level(LevelMask::new(0))?, 16)?;
+        builder.write_ref(pruned.clone())?;
+        builder.write_ref(pruned.clone())?;
+        let update = builder.build()?;
+
+        let mut builder = TonCell::builder_extra(CellType::LibraryRef, 64);
+        builder.write_num(&2u8, 8)?; // cell type
+        builder.write_bits(TonHash::ZERO, TonHash::BITS_LEN)?;
+        let library = builder.build()?;
+
+        assert_eq!(child.level(), 0);
+        assert_eq!(pruned.level(), 1);
+        assert_eq!(root.level(), 1);
+        assert_eq!(proof.level(), 0);
+        assert_eq!(update.level(), 0);
+        assert_eq!(library.level(), 0);
+
+        assert!(!child.is_exotic());
+        assert!(!root.is_exotic());
+        assert!(pruned.is_exotic());
+        assert!(proof.is_exotic());
+        assert!(update.is_exotic());
+        assert!(library.is_exotic());
+
+        assert_ok!(root.assert_ordinary());
+        assert_err!(pruned.assert_ordinary());
+        assert_err!(library.assert_ordinary());
+        Ok(())
+    }
+
+    #[test]
+    fn test_ton_cell_data_eq_refs_multiset_eq() -> anyhow::Result<()> {
+        let ref_a = TonCell::from_bit_str("1010")?;
+        let ref_b = TonCell::from_bit_str("0110011")?;
+        let make_cell = |bits: &str, refs: &[&TonCell]| {
+            let mut builder = TonCell::builder();
+            builder.write_cell(&TonCell::from_bit_str(bits)?)?;
+            for cell_ref in refs {
+                builder.write_ref((*cell_ref).clone())?;
+            }
+            builder.build()
+        };
+        let cell = make_cell("110100111", &[&ref_a, &ref_b])?;
+        let swapped = make_cell("110100111", &[&ref_b, &ref_a])?;
+        assert_ne!(cell, swapped);
+        assert!(cell.data_eq(&swapped));
+        assert!(cell.refs_multiset_eq(&swapped));
+
+        assert!(!cell.data_eq(&make_cell("110100110", &[&ref_a, &ref_b])?));
+        assert!(!cell.data_eq(&make_cell("1101001110", &[&ref_a, &ref_b])?));
+        assert!(!cell.refs_multiset_eq(&make_cell("110100111", &[&ref_a, &ref_a])?));
+        assert!(!cell.refs_multiset_eq(&make_cell("110100111", &[&ref_a])?));
+
+        // data of a slice starts with offset
+        let mut builder = TonCell::builder();
+        builder.write_bits([0b0110_1001, 0b1100_0000], 11)?;
+        let parent = builder.build()?;
+        let slice = parent.slice(CellBorders {
+            start_bit: 2,
+            end_bit: 11,
+            start_ref: 0,
+            end_ref: 0,
+        })?;
+        assert!(slice.data_eq(&make_cell("101001110", &[])?));
+        assert!(!slice.data_eq(&cell));
+        Ok(())
+    }
+
+    #[test]
+    fn test_ton_cell_view_odd_bits_len() -> anyhow::Result<()> {
+        let make_cell = |data: &[u8], bits_len: usize| -> anyhow::Result<TonCell> {
+            let mut builder = TonCell::builder();
+            builder.write_bits(data, bits_len)?;
+            Ok(builder.build()?)
+        };
+        for (data, bits_len, expected) in [
+            (vec![0b1000_0000], 1, "C0_"),
+            (vec![0b0000_0000], 1, "40_"),
+            (vec![0b1000_1000], 6, "8A_"),
+            (vec![0b1111_1100], 6, "FE_"),
+            (vec![0b1010_1010], 7, "AB_"),
+            (vec![0xFF, 0b1010_1010], 15, "FFAB_"),
+            (vec![0xAB], 8, "AB"),
+        ] {
+            let cell = make_cell(&data, bits_len)?;
+            assert_eq!(cell.data_bitstring()?, expected);
+            assert!(format!("{cell}").contains(&format!("data: [{expected}]")));
+        }
+        Ok(())
+    }
+
+    #[test]
+    fn test_ton_cell_save_load_boc() -> anyhow::Result<()> {
+        let mut builder = TonCell::builder();
+        builder.write_bits([0xAB, 0b1000_0000], 9)?;
+        builder.write_ref(TonCell::from_bit_str("1010")?)?;
+        let cell = builder.build()?;
+
+        let path = std::env::temp_dir().join(format!("ton_cell_save_load_{}.boc", std::process::id()));
+        cell.save_boc(&path)?;
+        let loaded = TonCell::load_boc(&path);
+        std::fs::remove_file(&path)?;
+        assert_eq!(loaded?, cell);
+        assert_err!(TonCell::load_boc(&path));
+        Ok(())
+    }
+
+    #[test]
+    fn test_ton_cell_hash_str() -> anyhow::Result<()> {
+        let cell = TonCell::empty();
+        assert_eq!(cell.hash_hex()?, "96a296d224f285c67bee93c30f8a309157f0daa35dc5b87e410b78630a09cfc7");
+        assert_eq!(cell.hash_hex()?, TonCell::EMPTY_CELL_HASH.to_hex());
+        assert_eq!(cell.hash_base64()?, "lqKW0iTyhcZ77pPDD4owkVfw2qNdxbh+QQt4YwoJz8c=");
+        assert_eq!(cell.repr_hash()?, TonCell::EMPTY_CELL_HASH);
+        Ok(())
+    }
+
+    #[test]
+    fn test_ton_cell_dump_fift() -> anyhow::Result<()> {
+        let mut builder = TonCell::builder();
+        builder.write_bits([0b1000_1000], 6)?;
+        let cell = builder.build()?;
+        assert_eq!(cell.dump_fift(), "x{8A_}\n");
+
+        let mut child_builder = TonCell::builder();
+        child_builder.write_bits([0b1010_0000], 4)?;
+        child_builder.write_ref(TonCell::builder().build()?)?;
+        let child = child_builder.build()?;
+
+        let mut root_builder = TonCell::builder();
+        root_builder.write_bits([0xAB, 0b1000_0000], 9)?;
+        root_builder.write_ref(child)?;
+        root_builder.write_ref(cell)?;
+        let root = root_builder.build()?;
+        assert_eq!(root.dump_fift(), "x{ABC_}\n x{A}\n  x{}\n x{8A_}\n");
+        Ok(())
+    }
 }
@@ -0,0 +1,150 @@
+use crate::bits_utils::BitsUtils;
+use crate::cell::TonCell;
+use crate::cell::TonHash;
+use crate::cell::cell_meta::CellType;
+use std::fmt::{Display, Formatter};
+
+/// First divergence between 2 cell trees, found by `TonCell::diff`
+#[derive(Debug, Clone, PartialEq)]
+pub struct CellDiff {
+    /// Location of divergent cell, e.g. `root.refs[1].refs[0]`
+    pub path: String,
+    pub kind: CellDiffKind,
+}
+
+#[derive(Debug, Clone, PartialEq)]
+pub enum CellDiffKind {
+    CellType {
+        left: CellType,
+        right: CellType,
+    },
+    DataLen {
+        left: usize,
+        right: usize,
+    },
+    Data {
+        bit_pos: usize,
+    },
+    RefsCount {
+        left: usize,
+        right: usize,
+    },
+    // structure is the same, but hashes are different (e.g. one of them can't be hashed)
+    ChildHash {
+        left: Option<TonHash>,
+        right: Option<TonHash>,
+    },
+}
+
+impl TonCell {
+    /// Walks both trees in lockstep and reports the first divergence. Returns None if cells are equal
+    pub fn diff(&self, other: &TonCell) -> Option<CellDiff> { diff_rec(self, other, "root") }
+}
+
+fn diff_rec(left: &TonCell, right: &TonCell, path: &str) -> Option<CellDiff> {
+    if matches!((left.hash(), right.hash()), (Ok(l), Ok(r)) if l == r) {
+        return None;
+    }
+    let make_diff = |kind| {
+        Some(CellDiff {
+            path: path.to_string(),
+            kind,
+        })
+    };
+
+    if left.cell_type != right.cell_type {
+        return make_diff(CellDiffKind::CellType {
+            left: left.cell_type,
+            right: right.cell_type,
+        });
+    }
+    let (left_len, right_len) = (left.data_len_bits(), right.data_len_bits());
+    if left_len != right_len {
+        return make_diff(CellDiffKind::DataLen {
+            left: left_len,
+            right: right_len,
+        });
+    }
+    let common_len = BitsUtils::common_prefix_len(
+        &left.cell_data.data_storage,
+        left.borders.start_bit,
+        &right.cell_data.data_storage,
+        right.borders.start_bit,
+        left_len,
+    );
+    if common_len < left_len {
+        return make_diff(CellDiffKind::Data { bit_pos: common_len });
+    }
+    let (left_refs, right_refs) = (left.refs(), right.refs());
+    if left_refs.len() != right_refs.len() {
+        return make_diff(CellDiffKind::RefsCount {
+            left: left_refs.len(),
+            right: right_refs.len(),
+        });
+    }
+    for (pos, (left_ref, right_ref)) in left_refs.iter().zip(right_refs.iter()).enumerate() {
+        let ref_path = format!("{path}.refs[{pos}]");
+        if let Some(diff) = diff_rec(left_ref, right_ref, &ref_path) {
+            return Some(diff);
+        }
+    }
+    make_diff(CellDiffKind::ChildHash {
+        left: left.hash().ok().cloned(),
+        right: right.hash().ok().cloned(),
+    })
+}
+
+impl Display for CellDiff {
+    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+        match &self.kind {
+            CellDiffKind::CellType { left, right } => write!(f, "{}: cell_type {left:?} != {right:?}", self.path),
+            CellDiffKind::DataLen { left, right } => write!(f, "{}: data_len_bits {left} != {right}", self.path),
+            CellDiffKind::Data { bit_pos } => write!(f, "{}: data differs at bit {bit_pos}", self.path),
+            CellDiffKind::RefsCount { left, right } => write!(f, "{}: refs count {left} != {right}", self.path),
+            CellDiffKind::ChildHash { left, right } => write!(f, "{}: hash {left:?} != {right:?}", self.path),
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn make_tree(leaf_byte: u8) -> anyhow::Result<TonCell> {
+        let mut leaf = TonCell::builder();
+        leaf.write_bits([0xAA, leaf_byte], 16)?;
+
+        let mut middle = TonCell::builder();
+        middle.write_bits([0x01], 8)?;
+        middle.write_ref(leaf.build()?)?;
+
+        let mut root = TonCell::builder();
+        root.write_bits([0xFF], 8)?;
+        root.write_ref(TonCell::builder().build()?)?;
+        root.write_ref(middle.build()?)?;
+        Ok(root.build()?)
+    }
+
+    #[test]
+    fn test_ton_cell_diff() -> anyhow::Result<()> {
+        let left = make_tree(0b0000_1111)?;
+        assert_eq!(left.diff(&make_tree(0b0000_1111)?), None);
+
+        let diff = left.diff(&make_tree(0b0000_1011)?).unwrap();
+        assert_eq!(diff.path, "root.refs[1].refs[0]");
+        assert_eq!(diff.kind, CellDiffKind::Data { bit_pos: 13 });
+        assert_eq!(diff.to_string(), "root.refs[1].refs[0]: data differs at bit 13");
+
+        let mut short = TonCell::builder();
+        short.write_bits([0xFF], 7)?;
+        let diff = left.diff(&short.build()?).unwrap();
+        assert_eq!(diff.path, "root");
+        assert_eq!(diff.kind, CellDiffKind::DataLen { left: 8, right: 7 });
+
+        let mut no_refs = TonCell::builder();
+        no_refs.write_bits([0xFF], 8)?;
+        let diff = left.diff(&no_refs.build()?).unwrap();
+        assert_eq!(diff.kind, CellDiffKind::RefsCount { left: 2, right: 0 });
+        Ok(())
+    }
+}
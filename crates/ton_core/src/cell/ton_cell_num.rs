@@ -23,8 +23,14 @@ use crate::errors::TonCoreResult;
 ///
 /// Caller (CellParser) provides the following guarantees:
 /// * bits_len != 0 (CellBuilder & CellParser handle zero bits_len itself)
+/// * bits_len <= TCN_MAX_BITS_LEN (reading more bits would truncate the value)
 pub trait TonCellNum: Display + Sized + Clone + Zero + PartialOrd {
+    /// Max bits_len which can be read into the type
+    const TCN_MAX_BITS_LEN: usize;
     fn tcn_write_bits(&self, writer: &mut CellBuilder, bits_len: usize) -> TonCoreResult<()>;
     fn tcn_read_bits(reader: &mut CellParser, bits_len: usize) -> TonCoreResult<Self>;
     fn tcn_min_bits_len(&self) -> usize;
 }
+
+/// Minimal number of bits required to store `value` (sign bit included for signed types)
+pub fn min_bits_for<N: TonCellNum>(value: &N) -> usize { value.tcn_min_bits_len() }
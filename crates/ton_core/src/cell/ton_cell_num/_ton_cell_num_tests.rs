@@ -64,7 +64,7 @@ fn test_ton_cell_num_primitives_corner_cases() -> anyhow::Result<()> {
     assert_ton_cell_num_read_write(vec![(0i128, 128), (i128::MAX, 128), (i128::MIN, 128), (i128::MIN / 2, 128)])?;
 
     let size_usize = isize::BITS as usize;
-    assert_ton_cell_num_read_write(vec![(0isize, size_usize), (isize::MAX, size_usize), (isize::MIN, size_usize + 1), (isize::MIN / 2, size_usize)])?;
+    assert_ton_cell_num_read_write(vec![(0isize, size_usize), (isize::MAX, size_usize), (isize::MIN, size_usize), (isize::MIN / 2, size_usize)])?;
     Ok(())
 }
 
@@ -87,6 +87,30 @@ fn test_ton_cell_num_fastnum_corner_cases() -> anyhow::Result<()> {
     Ok(())
 }
 
+#[test]
+fn test_ton_cell_num_read_exceeds_capacity() -> anyhow::Result<()> {
+    let mut builder = TonCell::builder();
+    builder.write_num(&0u32, 32)?;
+    builder.write_num(&U256::from(1u8), 160)?;
+    let cell = builder.build()?;
+
+    let err = assert_err!(cell.parser().read_num::<u8>(9));
+    assert!(err.to_string().contains("Can't read 9 bits into u8 (max bits_len 8)"), "{err}");
+    assert_err!(cell.parser().read_num::<u32>(40));
+    assert_err!(cell.parser().read_num::<i32>(33));
+    assert_err!(cell.parser().read_num::<U128>(129));
+    assert_err!(cell.parser().read_num::<I128>(129));
+    assert_err!(cell.parser().read_num::<BigUint>(1025));
+
+    let mut parser = cell.parser();
+    assert_eq!(parser.read_num::<u8>(8)?, 0);
+    assert_eq!(parser.read_num::<U128>(24)?, U128::from(0u8));
+    assert_eq!(parser.read_num::<U256>(160)?, U256::from(1u8));
+    assert_eq!(u64::TCN_MAX_BITS_LEN, 64);
+    assert_eq!(I512::TCN_MAX_BITS_LEN, 512);
+    Ok(())
+}
+
 #[test]
 fn test_ton_cell_read_write_bits_repr() -> anyhow::Result<()> {
     // unsigned
@@ -251,9 +275,14 @@ fn assert_ton_cell_num_read_write_bits_repr<T: TonCellNum + Debug + Clone>(
 
     let mut parser = cell.parser();
     let _ = parser.read_bits(extra_bits)?;
-    let parsed_value = parser.read_num::<T>(bits_len)?;
-    assert_eq!(value, &parsed_value, "value mismatch for {assert_info}");
-    assert_ok!(parser.ensure_empty(), "ensure_empty failed for {assert_info}");
+    if bits_len > T::TCN_MAX_BITS_LEN {
+        // writing with padding is fine, but reading would truncate the value
+        assert_err!(parser.read_num::<T>(bits_len), "read_num must fail for {assert_info}");
+    } else {
+        let parsed_value = parser.read_num::<T>(bits_len)?;
+        assert_eq!(value, &parsed_value, "value mismatch for {assert_info}");
+        assert_ok!(parser.ensure_empty(), "ensure_empty failed for {assert_info}");
+    }
 
     assert_eq!(expected_storage, cell.underlying_storage(), "wrong storage for {assert_info}");
 
@@ -11,6 +11,8 @@ use crate::bail_ton_core_data;
 /// Slow implementation uses U/I1024 to support bignum
 /// If you use bignum, you likely don't care about performance that much
 impl TonCellNum for BigUint {
+    const TCN_MAX_BITS_LEN: usize = U1024::TCN_MAX_BITS_LEN;
+
     fn tcn_write_bits(&self, writer: &mut CellBuilder, bits_len: usize) -> Result<(), TonCoreError> {
         biguint_to_u1024(self)?.tcn_write_bits(writer, bits_len)
     }
@@ -23,6 +25,8 @@ impl TonCellNum for BigUint {
 }
 
 impl TonCellNum for BigInt {
+    const TCN_MAX_BITS_LEN: usize = I1024::TCN_MAX_BITS_LEN;
+
     fn tcn_write_bits(&self, writer: &mut CellBuilder, bits_len: usize) -> Result<(), TonCoreError> {
         bigint_to_i1024(self)?.tcn_write_bits(writer, bits_len)
     }
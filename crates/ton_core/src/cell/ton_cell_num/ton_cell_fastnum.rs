@@ -10,6 +10,8 @@ use fastnum::{U128, U256, U512, U1024};
 macro_rules! ton_cell_num_fastnum_unsigned_impl {
     ($src:ty) => {
         impl TonCellNum for $src {
+            const TCN_MAX_BITS_LEN: usize = size_of::<Self>() * 8;
+
             fn tcn_write_bits(&self, writer: &mut CellBuilder, bits_len: usize) -> TonCoreResult<()> {
                 let slice = self.to_radix_be(256);
                 let slice_len_bits = slice.len() * 8;
@@ -23,18 +25,13 @@ macro_rules! ton_cell_num_fastnum_unsigned_impl {
 
             fn tcn_read_bits(reader: &mut CellParser, bits_len: usize) -> TonCoreResult<Self> {
                 let type_size_bits = size_of::<Self>() * 8;
-                let padding_bits = bits_len.saturating_sub(type_size_bits);
-                if padding_bits > 0 {
-                    reader.read_bits_to(padding_bits, &mut [0u8; 128])?; // skip padding
-                }
                 let mut dst = [0u8; size_of::<Self>()];
-                let bits_to_read = bits_len - padding_bits;
-                reader.read_bits_to(bits_to_read, &mut dst)?;
+                reader.read_bits_to(bits_len, &mut dst)?;
                 let value = match Self::from_be_slice(&dst) {
                     Some(v) => v,
                     None => bail_ton_core_data!("Failed to read {} from slice: {dst:?}", type_name::<Self>()),
                 };
-                Ok(value >> (type_size_bits - bits_to_read))
+                Ok(value >> (type_size_bits - bits_len))
             }
 
             fn tcn_min_bits_len(&self) -> usize { size_of::<Self>() * 8 - self.leading_zeros() as usize }
@@ -45,6 +42,8 @@ macro_rules! ton_cell_num_fastnum_unsigned_impl {
 macro_rules! ton_cell_num_fastnum_signed_impl {
     ($src:ty,$u_src:ty) => {
         impl TonCellNum for $src {
+            const TCN_MAX_BITS_LEN: usize = size_of::<Self>() * 8;
+
             fn tcn_write_bits(&self, writer: &mut CellBuilder, bits_len: usize) -> Result<(), TonCoreError> {
                 let slice = self.to_radix_be(256);
                 let slice_len_bits = slice.len() * 8;
@@ -59,18 +58,13 @@ macro_rules! ton_cell_num_fastnum_signed_impl {
 
             fn tcn_read_bits(reader: &mut CellParser, bits_len: usize) -> Result<Self, TonCoreError> {
                 let type_size_bits = size_of::<Self>() * 8;
-                let padding_bits = bits_len.saturating_sub(type_size_bits);
-                if padding_bits > 0 {
-                    reader.read_bits_to(padding_bits, &mut [0u8; 128])?; // skip padding
-                }
-                let bits_to_read = bits_len - padding_bits;
                 let mut dst = [0u8; size_of::<Self>()];
-                reader.read_bits_to(bits_to_read, &mut dst)?;
+                reader.read_bits_to(bits_len, &mut dst)?;
                 let value = match Self::from_be_slice(&dst) {
                     Some(v) => v,
                     None => bail_ton_core_data!("Failed to read {} from slice: {dst:?}", type_name::<Self>()),
                 };
-                Ok(value >> (type_size_bits - bits_to_read))
+                Ok(value >> (type_size_bits - bits_len))
             }
 
             fn tcn_min_bits_len(&self) -> usize {
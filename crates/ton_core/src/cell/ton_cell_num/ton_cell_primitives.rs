@@ -7,6 +7,8 @@ use crate::errors::{TonCoreError, TonCoreResult};
 macro_rules! ton_cell_num_primitive_unsigned_impl {
     ($src:ty) => {
         impl TonCellNum for $src {
+            const TCN_MAX_BITS_LEN: usize = Self::BITS as usize;
+
             fn tcn_write_bits(&self, writer: &mut CellBuilder, bits_len: usize) -> TonCoreResult<()> {
                 let padding_bits = bits_len.saturating_sub(Self::BITS as usize);
                 if padding_bits > 0 {
@@ -16,11 +18,7 @@ macro_rules! ton_cell_num_primitive_unsigned_impl {
             }
 
             fn tcn_read_bits(reader: &mut CellParser, bits_len: usize) -> TonCoreResult<Self> {
-                let padding_bits = bits_len.saturating_sub(Self::BITS as usize);
-                if padding_bits > 0 {
-                    reader.read_bits_to(padding_bits, &mut [0u8; 128])?; // skip padding
-                }
-                reader.read_unsigned_primitive(bits_len - padding_bits)
+                reader.read_unsigned_primitive(bits_len)
             }
 
             fn tcn_min_bits_len(&self) -> usize { (Self::BITS - self.leading_zeros()) as usize }
@@ -31,6 +29,8 @@ macro_rules! ton_cell_num_primitive_unsigned_impl {
 macro_rules! ton_cell_num_primitive_signed_impl {
     ($src:ty,$u_src:ty) => {
         impl TonCellNum for $src {
+            const TCN_MAX_BITS_LEN: usize = Self::BITS as usize;
+
             fn tcn_write_bits(&self, writer: &mut CellBuilder, bits_len: usize) -> Result<(), TonCoreError> {
                 let padding_bits = bits_len.saturating_sub(Self::BITS as usize);
                 if padding_bits > 0 {
@@ -68,6 +68,7 @@ macro_rules! ton_cell_num_primitive_signed_impl {
 
 #[rustfmt::skip]
 impl TonCellNum for usize {
+    const TCN_MAX_BITS_LEN: usize = Self::BITS as usize;
     fn tcn_write_bits(&self, writer: &mut CellBuilder, bits_len: usize) -> TonCoreResult<()> { (*self as u64).tcn_write_bits(writer, bits_len) }
     fn tcn_read_bits(reader: &mut CellParser, bits_len: usize) -> TonCoreResult<Self> { Ok(u64::tcn_read_bits(reader, bits_len)? as usize) }
     fn tcn_min_bits_len(&self) -> usize { (Self::BITS - self.leading_zeros()) as usize }
@@ -39,10 +39,14 @@ impl TonHash {
         Ok(Self::from_slice_sized(slice))
     }
 
+    /// Fails if the value is negative or doesn't fit into 256 bits
     pub fn from_i512(num: &I512) -> Result<Self, TonCoreError> {
+        if num.is_negative() {
+            bail_ton_core_data!("Can't convert negative I512 {num} to TonHash");
+        }
         let num_bytes = num.to_radix_le(256);
-        if num_bytes.len() > 32 {
-            bail_ton_core_data!("I512 value is too large to fit into TonHash");
+        if num_bytes.len() > Self::BYTES_LEN {
+            bail_ton_core_data!("I512 {num} is too large to fit into TonHash");
         }
         TonHash::from_u256(&U256::from_radix_le(&num_bytes, 256).unwrap())
     }
@@ -52,6 +56,7 @@ impl TonHash {
         U256::from_radix_le(self.as_slice(), 256).unwrap()
     }
 
+    /// Result is always non-negative and fits into 256 bits
     pub fn to_i512(&self) -> I512 {
         // unwrap is save: TonHash always has 32 bytes
         I512::from_radix_le(self.as_slice(), 256).unwrap()
@@ -324,4 +329,21 @@ mod tests {
         assert_eq!(max_num, U256::MAX);
         Ok(())
     }
+
+    #[test]
+    fn test_ton_hash_from_i512() -> anyhow::Result<()> {
+        let max = (I512::ONE << 256) - I512::ONE;
+        let max_hash = TonHash::from_i512(&max)?;
+        assert_eq!(max_hash, TonHash::from([255u8; 32]));
+        assert_eq!(max_hash.to_i512(), max);
+
+        let hash = TonHash::from_str("9f7326961a51bcfac8d32e8ef0b79007bb5390b744a35a2fa8ab6d4c5ac17ed7")?;
+        assert_eq!(TonHash::from_i512(&hash.to_i512())?, hash);
+        assert_eq!(TonHash::from_i512(&I512::ZERO)?, TonHash::ZERO);
+
+        assert_err!(TonHash::from_i512(&(I512::ONE << 256)));
+        assert_err!(TonHash::from_i512(&I512::from(-1)));
+        assert_err!(TonHash::from_i512(&I512::MIN));
+        Ok(())
+    }
 }
@@ -22,9 +22,19 @@ pub enum TonCoreError {
     #[error("DataError: [{producer}] {msg}")]
     DataError { producer: String, msg: String },
 
+    #[error("NumOverflow: {}", num_overflow_msg(value, *bits_len, *required_bits))]
+    NumOverflow {
+        value: String,
+        bits_len: usize,
+        required_bits: usize,
+    },
+
     // tlb
-    #[error("TLBWrongPrefix: expected={exp}, given={given}, exp_bits={bits_exp}, left_bits={bits_left}")]
+    #[error(
+        "TLBWrongPrefix: type={type_name}, expected={exp}, given={given}, exp_bits={bits_exp}, left_bits={bits_left}"
+    )]
     TLBWrongPrefix {
+        type_name: &'static str,
         exp: usize,
         given: usize,
         bits_exp: usize,
@@ -78,6 +88,16 @@ impl TonCoreError {
 
 pub type TonCoreResult<T> = Result<T, TonCoreError>;
 
+fn num_overflow_msg(value: &str, bits_len: usize, required_bits: usize) -> String {
+    if value.starts_with('-') {
+        let magnitude_bits = required_bits - 1;
+        return format!(
+            "can't write num: value {value} needs at least {required_bits} bits ({magnitude_bits} for magnitude + 1 sign), got bits_len={bits_len}"
+        );
+    }
+    format!("can't write num: value {value} needs at least {required_bits} bits, got bits_len={bits_len}")
+}
+
 #[cfg(test)]
 mod tests {
     use super::*;
@@ -1,4 +1,5 @@
-use crate::cell::TonHash;
+use crate::cell::{TonCell, TonHash};
+use crate::traits::tlb::TLB;
 use crate::types::*;
 use ::serde::de::Error;
 use ::serde::{Deserialize, Deserializer, Serialize, Serializer};
@@ -98,6 +99,29 @@ pub mod serde_ton_hash_vec_base64 {
     }
 }
 
+// TonCell
+pub mod serde_cell_boc_base64 {
+    use super::*;
+
+    pub fn serialize<S: Serializer>(cell: &TonCell, serializer: S) -> Result<S::Ok, S::Error> {
+        serializer.serialize_str(&cell.to_boc_base64().map_err(::serde::ser::Error::custom)?)
+    }
+    pub fn deserialize<'de, D: Deserializer<'de>>(deserializer: D) -> Result<TonCell, D::Error> {
+        TonCell::from_boc_base64(&String::deserialize(deserializer)?).map_err(Error::custom)
+    }
+}
+
+pub mod serde_cell_boc_hex {
+    use super::*;
+
+    pub fn serialize<S: Serializer>(cell: &TonCell, serializer: S) -> Result<S::Ok, S::Error> {
+        serializer.serialize_str(&cell.to_boc_hex().map_err(::serde::ser::Error::custom)?)
+    }
+    pub fn deserialize<'de, D: Deserializer<'de>>(deserializer: D) -> Result<TonCell, D::Error> {
+        TonCell::from_boc_hex(&String::deserialize(deserializer)?).map_err(Error::custom)
+    }
+}
+
 // TonAddress
 pub mod serde_ton_address_hex {
     pub use super::*;
@@ -114,7 +138,7 @@ pub mod serde_ton_address_base64_url {
     pub use super::*;
 
     pub fn serialize<S: Serializer>(address: &TonAddress, serializer: S) -> Result<S::Ok, S::Error> {
-        serializer.serialize_str(&address.to_base64(true, true, true))
+        serializer.serialize_str(&address.to_base64_url(address.is_bounceable(), false))
     }
     pub fn deserialize<'de, D: Deserializer<'de>>(deserializer: D) -> Result<TonAddress, D::Error> {
         TonAddress::from_str(&String::deserialize(deserializer)?).map_err(Error::custom)
@@ -125,7 +149,7 @@ pub mod serde_ton_address_base64_url_testnet {
     pub use super::*;
 
     pub fn serialize<S: Serializer>(address: &TonAddress, serializer: S) -> Result<S::Ok, S::Error> {
-        serializer.serialize_str(&address.to_base64(false, true, true))
+        serializer.serialize_str(&address.to_base64_url(address.is_bounceable(), true))
     }
     pub fn deserialize<'de, D: Deserializer<'de>>(deserializer: D) -> Result<TonAddress, D::Error> {
         TonAddress::from_str(&String::deserialize(deserializer)?).map_err(Error::custom)
@@ -247,6 +271,37 @@ mod tests {
         Ok(())
     }
 
+    #[test]
+    fn test_serde_cell_boc() -> anyhow::Result<()> {
+        use crate::cell::TonCell;
+
+        #[derive(Serialize, Deserialize, Debug, PartialEq)]
+        struct TestStruct {
+            #[serde(with = "serde_cell_boc_base64")]
+            cell: TonCell,
+            #[serde(with = "serde_cell_boc_hex")]
+            cell_hex: TonCell,
+        }
+
+        let mut builder = TonCell::builder();
+        builder.write_num(&0x12345678u32, 32)?;
+        builder.write_ref(TonCell::builder().build()?)?;
+        let cell = builder.build()?;
+
+        let val = TestStruct {
+            cell: cell.clone(),
+            cell_hex: cell.clone(),
+        };
+        let val_json = serde_json::to_value(&val)?;
+        assert_eq!(val_json["cell"], cell.to_boc_base64()?);
+        assert_eq!(val_json["cell_hex"], cell.to_boc_hex()?);
+
+        let parsed_val = serde_json::from_value::<TestStruct>(val_json)?;
+        assert_eq!(parsed_val, val);
+        assert!(serde_json::from_str::<TestStruct>(r#"{"cell": "invalid", "cell_hex": "00"}"#).is_err());
+        Ok(())
+    }
+
     #[test]
     fn test_default_serde_ton_address_ton_hash() -> anyhow::Result<()> {
         use serde_json::json;
@@ -295,6 +350,13 @@ mod tests {
         );
         let parsed_none = serde_json::from_str::<TestStruct>(&val_none_json.to_string())?;
         assert_eq!(parsed_none, val_none);
+
+        // serde keeps bounceable flag, same as Display
+        let non_bounceable = TonAddress::from_str("UQCGScrZe1xbyWqWDvdI6mzP-GAcAWFv6ZXuaJOuSqemxhZ9")?;
+        let json_str = serde_json::to_string(&non_bounceable)?;
+        assert_eq!(json_str, format!("\"{non_bounceable}\""));
+        let parsed = serde_json::from_str::<TonAddress>(&json_str)?;
+        assert!(!parsed.is_bounceable());
         Ok(())
     }
 }
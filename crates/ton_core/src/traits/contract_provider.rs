@@ -2,7 +2,12 @@ use crate::cell::TonHash;
 use crate::errors::TonCoreError;
 use crate::types::{TonAddress, TxLTHash};
 use async_trait::async_trait;
+use futures_util::StreamExt;
+use futures_util::stream::BoxStream;
 use std::sync::Arc;
+use std::time::Duration;
+
+pub const MC_BLOCKS_POLL_INTERVAL: Duration = Duration::from_secs(1);
 
 #[async_trait]
 #[rustfmt::skip]
@@ -16,6 +21,27 @@ pub trait TonProvider: Send + Sync + 'static {
     async fn load_libs(&self, lib_ids: Vec<TonHash>, mc_seqno: Option<u32>) -> Result<Vec<(TonHash, Vec<u8>)>, TonCoreError>;
     
     async fn load_latest_tx_per_address(&self, mc_seqno: u32) -> Result<Vec<(TonAddress, TxLTHash)>, TonCoreError>;
+
+    /// Stream of new masterchain seqnos, starting from last_mc_seqno
+    /// Default implementation polls last_mc_seqno every MC_BLOCKS_POLL_INTERVAL and yields all seqnos up to it.
+    /// It holds weak reference to provider, so stream ends once provider is dropped
+    async fn subscribe_mc_blocks(self: Arc<Self>) -> Result<BoxStream<'static, u32>, TonCoreError> {
+        let first_seqno = self.last_mc_seqno().await?;
+        let state = (Arc::downgrade(&self), first_seqno);
+        let next_seqnos = futures_util::stream::unfold(state, |(weak_provider, prev_seqno)| async move {
+            loop {
+                tokio::time::sleep(MC_BLOCKS_POLL_INTERVAL).await;
+                let provider = weak_provider.upgrade()?;
+                match provider.last_mc_seqno().await {
+                    Ok(seqno) if seqno > prev_seqno => return Some((prev_seqno + 1..=seqno, (weak_provider, seqno))),
+                    Ok(_) => {}
+                    Err(err) => log::warn!("[subscribe_mc_blocks] fail to get last_mc_seqno: {err}"),
+                }
+            }
+        });
+        let seqnos = futures_util::stream::iter([first_seqno]).chain(next_seqnos.flat_map(futures_util::stream::iter));
+        Ok(seqnos.boxed())
+    }
 }
 
 #[derive(Debug, Clone, Eq, PartialEq, Hash)]
@@ -28,3 +54,47 @@ pub struct TonContractState {
     pub frozen_hash: Option<TonHash>,
     pub balance: i64,
 }
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use std::sync::atomic::AtomicU32;
+    use std::sync::atomic::Ordering::Relaxed;
+
+    struct PollingProvider {
+        mc_seqno: AtomicU32,
+    }
+
+    #[async_trait]
+    impl TonProvider for PollingProvider {
+        async fn last_mc_seqno(&self) -> Result<u32, TonCoreError> { Ok(self.mc_seqno.load(Relaxed)) }
+        async fn load_state(&self, _: TonAddress, _: Option<TxLTHash>) -> Result<TonContractState, TonCoreError> {
+            unimplemented!()
+        }
+        async fn load_bc_config(&self, _: Option<u32>) -> Result<Vec<u8>, TonCoreError> { unimplemented!() }
+        async fn load_libs(&self, _: Vec<TonHash>, _: Option<u32>) -> Result<Vec<(TonHash, Vec<u8>)>, TonCoreError> {
+            unimplemented!()
+        }
+        async fn load_latest_tx_per_address(&self, _: u32) -> Result<Vec<(TonAddress, TxLTHash)>, TonCoreError> {
+            unimplemented!()
+        }
+    }
+
+    #[tokio::test(start_paused = true)]
+    async fn test_subscribe_mc_blocks_default_polling() -> anyhow::Result<()> {
+        let provider = Arc::new(PollingProvider {
+            mc_seqno: AtomicU32::new(100),
+        });
+        let mut stream = provider.clone().subscribe_mc_blocks().await?;
+        assert_eq!(stream.next().await, Some(100));
+
+        provider.mc_seqno.store(103, Relaxed);
+        let seqnos: Vec<_> = stream.by_ref().take(3).collect().await;
+        assert_eq!(seqnos, [101, 102, 103]);
+
+        // stream doesn't hold the provider
+        drop(provider);
+        assert_eq!(stream.next().await, None);
+        Ok(())
+    }
+}
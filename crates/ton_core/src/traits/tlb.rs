@@ -111,6 +111,7 @@ pub trait TLB: Sized {
 
         let prefix_error = |given, bits_left| {
             Err(TonCoreError::TLBWrongPrefix {
+                type_name: Self::tlb_type_name(),
                 exp: Self::PREFIX.value,
                 given,
                 bits_exp: Self::PREFIX.bits_len,
@@ -143,6 +144,9 @@ pub trait TLB: Sized {
     // so for all types except TonCell & TonCellRef we return Ordinary, but for them we return proper type
     // it's required to build a proper BOC
     fn ton_cell_type(&self) -> CellType { CellType::Ordinary }
+
+    // used in error messages, derive(TLB) overrides it with short type name
+    fn tlb_type_name() -> &'static str { type_name::<Self>() }
 }
 
 #[derive(Debug, Clone, PartialEq)]
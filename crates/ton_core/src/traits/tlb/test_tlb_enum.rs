@@ -143,3 +143,26 @@ fn test_tlb_enum_out_of_option() -> anyhow::Result<()> {
     }
     Ok(())
 }
+
+#[test]
+fn test_tlb_wrong_prefix_type_name() -> anyhow::Result<()> {
+    #[derive(TLB, Eq, PartialEq, Debug)]
+    struct Outer {
+        value: u8,
+        inner: Struct1,
+    }
+
+    // Struct3 is stored in place of Struct1
+    let mut builder = crate::cell::TonCell::builder();
+    7u8.write(&mut builder)?;
+    Struct3.write(&mut builder)?;
+    let cell = builder.build()?;
+
+    let err = assert_err!(Outer::from_cell(&cell));
+    match &err {
+        TonCoreError::TLBWrongPrefix { type_name, .. } => assert_eq!(*type_name, "Struct1"),
+        _ => panic!("Unexpected error type: {err}"),
+    }
+    assert!(err.to_string().starts_with("TLBWrongPrefix: type=Struct1, expected=1, given=0"));
+    Ok(())
+}
@@ -2,6 +2,10 @@ use crate::bail_ton_core_data;
 use crate::errors::{TonCoreError, TonCoreResult};
 use num_traits::{ToPrimitive, Zero};
 use std::fmt::Debug;
+use std::ops::{Add, Sub};
+
+const TON_DECIMALS: usize = 9;
+const NANO_IN_TON: u128 = 1_000_000_000;
 
 /// A safe wrapper around u128 to represent coin amounts with checked arithmetic operations
 /// Supports conversion from various numeric types and TLBCoins
@@ -15,6 +19,8 @@ pub trait IntoCoins {
 impl Coins {
     pub const ZERO: Coins = Coins(0);
     pub const ONE: Coins = Coins(1);
+    /// Max amount which fits into Grams (VarUInteger 16): 120 bits
+    pub const MAX_GRAMS: Coins = Coins((1 << 120) - 1);
 
     pub const fn new(amount: u128) -> Self { Self(amount) }
 
@@ -27,6 +33,57 @@ impl Coins {
 
     pub fn inner(&self) -> u128 { self.0 }
 
+    pub const fn from_nano(nano: u128) -> Self { Self(nano) }
+    pub fn to_nano(&self) -> u128 { self.0 }
+
+    /// Parses decimal TON amount (e.g. "1.5") into nanotons
+    pub fn from_ton_str(ton: &str) -> TonCoreResult<Self> {
+        let (int_part, frac_part) = ton.split_once('.').unwrap_or((ton, ""));
+        let is_digits = |x: &str| x.bytes().all(|c| c.is_ascii_digit());
+        if int_part.is_empty() || !is_digits(int_part) || !is_digits(frac_part) || ton.ends_with('.') {
+            bail_ton_core_data!("Can't parse TON amount from '{ton}'")
+        }
+        if frac_part.len() > TON_DECIMALS {
+            bail_ton_core_data!("TON amount '{ton}' has more than {TON_DECIMALS} decimals")
+        }
+        let Ok(int_val) = int_part.parse::<u128>() else {
+            bail_ton_core_data!("Can't parse TON amount from '{ton}'")
+        };
+        let frac_val = if frac_part.is_empty() {
+            0
+        } else {
+            frac_part.parse::<u128>()? * 10u128.pow((TON_DECIMALS - frac_part.len()) as u32)
+        };
+        Coins::new(int_val).checked_mul(Coins::new(NANO_IN_TON))?.checked_add(Coins::new(frac_val))
+    }
+
+    /// Formats nanotons as decimal TON amount without trailing zeros (e.g. "1.5")
+    pub fn to_ton_string(&self) -> String {
+        let (int_part, frac_part) = (self.0 / NANO_IN_TON, self.0 % NANO_IN_TON);
+        if frac_part == 0 {
+            return int_part.to_string();
+        }
+        let frac_str = format!("{frac_part:0TON_DECIMALS$}");
+        format!("{int_part}.{}", frac_str.trim_end_matches('0'))
+    }
+
+    /// Formats nanotons as decimal TON amount with exactly `decimals` fractional digits, rounding half up
+    pub fn to_ton_string_fixed(&self, decimals: usize) -> String {
+        if decimals >= TON_DECIMALS {
+            let (int_part, frac_part) = (self.0 / NANO_IN_TON, self.0 % NANO_IN_TON);
+            return format!("{int_part}.{frac_part:0TON_DECIMALS$}{}", "0".repeat(decimals - TON_DECIMALS));
+        }
+        let divisor = 10u128.pow((TON_DECIMALS - decimals) as u32);
+        // can't overflow: divisor > 1
+        let rounded = self.0 / divisor + (self.0 % divisor >= divisor.div_ceil(2)) as u128;
+        let frac_mul = 10u128.pow(decimals as u32);
+        let (int_part, frac_part) = (rounded / frac_mul, rounded % frac_mul);
+        match decimals {
+            0 => int_part.to_string(),
+            _ => format!("{int_part}.{frac_part:0decimals$}"),
+        }
+    }
+
     pub fn checked_add<T: IntoCoins>(&self, other: T) -> TonCoreResult<Coins> {
         let other_coins = other.into_coins()?;
         let Some(res) = self.0.checked_add(other_coins.0) else {
@@ -83,6 +140,16 @@ impl Coins {
     }
 }
 
+impl Add for Coins {
+    type Output = TonCoreResult<Coins>;
+    fn add(self, rhs: Coins) -> Self::Output { self.checked_add(rhs) }
+}
+
+impl Sub for Coins {
+    type Output = TonCoreResult<Coins>;
+    fn sub(self, rhs: Coins) -> Self::Output { self.checked_sub(rhs) }
+}
+
 #[rustfmt::skip]
 mod traits_impl {
     use fastnum::*;
@@ -115,7 +182,21 @@ mod traits_impl {
 
     }
 
-    try_from_impl!(i8, i16, i32, i64, i128, u8, u16, u32, u64, u128, usize, f32, f64, BigInt, BigUint, I128, I256, I512, I1024, U128, U256, U512, U1024, D256, D512);
+    try_from_impl!(i8, i16, i32, i64, i128, u8, u16, u32, u64, u128, usize, f32, f64, BigInt, BigUint, I128, I256, I1024, U128, U256, U512, U1024, D256, D512);
+
+    // I512 comes from TVM stack, so the value must be a valid Grams amount
+    impl TryFrom<I512> for Coins {
+        type Error = TonCoreError;
+        fn try_from(value: I512) -> Result<Self, Self::Error> {
+            if value.is_negative() {
+                bail_ton_core_data!("Can't convert {value} to Coins: value is negative");
+            }
+            match value.to_u128() {
+                Ok(amount) if amount <= Coins::MAX_GRAMS.0 => Ok(Coins(amount)),
+                _ => bail_ton_core_data!("Can't convert {value} to Coins: value exceeds VarUInteger 16 max {}", Coins::MAX_GRAMS.0),
+            }
+        }
+    }
 
     impl<T> IntoCoins for T
     where
@@ -156,4 +237,85 @@ mod tests {
 
         Ok(())
     }
+
+    #[test]
+    fn test_coins_add_sub_ops() -> TonCoreResult<()> {
+        let a = Coins::new(1_000_000);
+        let b = Coins::new(500_000);
+        assert_eq!((a + b)?, Coins::new(1_500_000));
+        assert_eq!((a - b)?, Coins::new(500_000));
+        assert!((b - a).is_err());
+        assert!((Coins::new(u128::MAX) + Coins::ONE).is_err());
+        Ok(())
+    }
+
+    #[test]
+    fn test_coins_try_from_i512() -> TonCoreResult<()> {
+        assert_eq!(Coins::try_from(fastnum::I512::from_u128(1_500_000_000).unwrap())?, Coins::new(1_500_000_000));
+        assert_eq!(Coins::try_from(fastnum::I512::from_u128(Coins::MAX_GRAMS.0).unwrap())?, Coins::MAX_GRAMS);
+
+        let err = Coins::try_from(fastnum::I512::from(-5)).unwrap_err();
+        assert!(err.to_string().contains("Can't convert -5 to Coins: value is negative"), "{err}");
+        let err = Coins::try_from(fastnum::I512::from_u128(Coins::MAX_GRAMS.0 + 1).unwrap()).unwrap_err();
+        assert!(err.to_string().contains("exceeds VarUInteger 16 max"), "{err}");
+        assert!(Coins::try_from(fastnum::I512::from_u128(u128::MAX).unwrap()).is_err());
+        Ok(())
+    }
+
+    #[test]
+    fn test_coins_nano() -> TonCoreResult<()> {
+        for nano in [0, 1, 1_500_000_000, u128::MAX] {
+            assert_eq!(Coins::from_nano(nano).to_nano(), nano);
+        }
+        assert_eq!(Coins::from_nano(42), Coins::new(42));
+        Ok(())
+    }
+
+    #[test]
+    fn test_coins_from_ton_str() -> TonCoreResult<()> {
+        assert_eq!(Coins::from_ton_str("1.5")?.to_nano(), 1_500_000_000);
+        assert_eq!(Coins::from_ton_str("0")?.to_nano(), 0);
+        assert_eq!(Coins::from_ton_str("42")?.to_nano(), 42_000_000_000);
+        assert_eq!(Coins::from_ton_str("0.000000001")?.to_nano(), 1);
+        assert_eq!(Coins::from_ton_str("123.456789012")?.to_nano(), 123_456_789_012);
+        assert_eq!(Coins::from_ton_str("0.10")?.to_nano(), 100_000_000);
+
+        for bad in ["", ".5", "1.", "1.2.3", "-1", "1,5", "abc", "0.0000000001", " 1"] {
+            assert!(Coins::from_ton_str(bad).is_err(), "'{bad}' must be rejected");
+        }
+        // overflow
+        assert!(Coins::from_ton_str(&u128::MAX.to_string()).is_err());
+        Ok(())
+    }
+
+    #[test]
+    fn test_coins_to_ton_string() -> TonCoreResult<()> {
+        assert_eq!(Coins::ZERO.to_ton_string(), "0");
+        assert_eq!(Coins::new(1_000_000_000).to_ton_string(), "1");
+        assert_eq!(Coins::new(1_234_567_890).to_ton_string(), "1.23456789");
+        assert_eq!(Coins::new(1).to_ton_string(), "0.000000001");
+        assert_eq!(Coins::new(42_500_000_000).to_ton_string(), "42.5");
+        for ton in ["0.1", "123.456789012", "1000"] {
+            assert_eq!(Coins::from_ton_str(ton)?.to_ton_string(), ton);
+        }
+        assert_eq!(Coins::new(u128::MAX).to_ton_string(), "340282366920938463463374607431.768211455");
+        Ok(())
+    }
+
+    #[test]
+    fn test_coins_to_ton_string_fixed() {
+        let coins = Coins::new(1_234_567_890);
+        assert_eq!(coins.to_ton_string_fixed(0), "1");
+        assert_eq!(coins.to_ton_string_fixed(2), "1.23");
+        assert_eq!(coins.to_ton_string_fixed(4), "1.2346");
+        assert_eq!(coins.to_ton_string_fixed(9), "1.234567890");
+        assert_eq!(coins.to_ton_string_fixed(11), "1.23456789000");
+        assert_eq!(coins.to_ton_string_fixed(50).len(), 52);
+        assert_eq!(Coins::ZERO.to_ton_string_fixed(2), "0.00");
+        assert_eq!(Coins::new(1).to_ton_string_fixed(2), "0.00");
+        assert_eq!(Coins::new(5_000_000).to_ton_string_fixed(2), "0.01");
+        assert_eq!(Coins::new(1_995_000_000).to_ton_string_fixed(2), "2.00");
+        assert_eq!(Coins::new(1_500_000_000).to_ton_string_fixed(0), "2");
+        assert_eq!(Coins::new(u128::MAX).to_ton_string_fixed(0), "340282366920938463463374607432");
+    }
 }
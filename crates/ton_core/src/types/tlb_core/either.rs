@@ -68,4 +68,21 @@ mod tests {
         assert!(parser.read_bit()?);
         Ok(())
     }
+
+    #[test]
+    fn test_either_parser_builder_helpers() -> anyhow::Result<()> {
+        let left: TLBEither<TestType1, TestType2> = TLBEither::Left(TestType1(-1));
+        let right: TLBEither<TestType1, TestType2> = TLBEither::Right(TestType2(i64::MAX));
+        let mut builder = TonCell::builder();
+        builder.write_either(&left)?;
+        builder.write_either(&right)?;
+        let cell = builder.build()?;
+        assert_eq!(cell.data_len_bits(), 1 + 32 + 1 + 64);
+
+        let mut parser = cell.parser();
+        assert_eq!(parser.read_either::<TestType1, TestType2>()?, left);
+        assert_eq!(parser.read_either::<TestType1, TestType2>()?, right);
+        parser.ensure_empty()?;
+        Ok(())
+    }
 }
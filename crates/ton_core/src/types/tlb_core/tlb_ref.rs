@@ -43,6 +43,7 @@ mod traits_impl {
 #[cfg(test)]
 mod tests {
     use super::*;
+    use tokio_test::{assert_err, assert_ok};
     use ton_macros::TLB;
 
     #[derive(TLB, PartialEq, Debug)]
@@ -51,6 +52,12 @@ mod tests {
         pub b: TLBRef<u8>,
     }
 
+    #[derive(TLB, PartialEq, Debug)]
+    struct Inner {
+        a: u8,
+        b: TLBRef<u32>,
+    }
+
     #[test]
     fn test_tlb_ref_opt_derive() -> anyhow::Result<()> {
         let expected = TestStruct {
@@ -86,4 +93,30 @@ mod tests {
         assert_eq!(parsed_cell, cell);
         Ok(())
     }
+
+    #[test]
+    fn test_tlb_ref_load_store_ref() -> anyhow::Result<()> {
+        let inner = Inner { a: 7, b: 42.into() };
+        let mut builder = TonCell::builder();
+        builder.write_bit(true)?;
+        builder.store_ref(&inner)?;
+        builder.store_ref(&inner)?;
+        let cell = builder.build()?;
+        assert_eq!(cell.refs().len(), 2);
+        assert_eq!(cell.refs()[0].refs().len(), 1);
+
+        let mut parser = cell.parser();
+        assert!(parser.read_bit()?);
+        assert_eq!(parser.load_ref::<Inner>()?, inner);
+        assert_eq!(parser.load_ref_exact::<Inner>()?, inner);
+        assert_ok!(parser.ensure_empty());
+
+        // u8 doesn't consume the whole ref
+        let mut parser = cell.parser();
+        parser.read_bit()?;
+        assert_eq!(parser.load_ref::<u8>()?, 7);
+        assert_err!(parser.load_ref_exact::<u8>());
+        assert_err!(parser.load_ref::<Inner>()); // no more refs
+        Ok(())
+    }
 }
@@ -12,18 +12,29 @@ use base64::engine::general_purpose::{STANDARD, URL_SAFE_NO_PAD};
 use crc::Crc;
 use std::cmp::Ordering;
 use std::fmt::{Debug, Display};
+use std::hash::{Hash, Hasher};
 use std::str::FromStr;
 
 const CRC_16_XMODEM: Crc<u16> = Crc::<u16>::new(&crc::CRC_16_XMODEM);
+const TAG_BOUNCEABLE: u8 = 0x11;
+const TAG_NON_BOUNCEABLE_FLAG: u8 = 0x40;
+const TAG_TESTNET_FLAG: u8 = 0x80;
 
 /// User-friendly representation of TON address
 ///
 /// Can be converted to/from TLB MsgAddress (can represent MsgAddressInt and MsgAddressNone)
-/// Default string representation is URL-safe base64 with bounceable mainnet tag
-#[derive(Clone, PartialEq, Eq, Hash)]
+/// Default string representation is URL-safe base64 with mainnet tag,
+/// bounceable unless the address was parsed from non-bounceable form.
+/// Alternate format (`{:#}`) gives raw "wc:hex" form, same as `to_raw_string()`
+///
+/// Equality and hashing ignore the bounceable flag, serde (base64_url) keeps bounceable flag as Display does
+///
+/// Has private fields: use `TonAddress::new(workchain, hash)` instead of struct literal
+#[derive(Clone)]
 pub struct TonAddress {
     pub workchain: i32,
     pub hash: TonHash,
+    bounceable: bool,
 }
 
 impl TonAddress {
@@ -34,7 +45,20 @@ impl TonAddress {
     pub fn is_zero(&self) -> bool { self == &TonAddress::ZERO }
     pub fn is_native(&self) -> bool { self == &TonAddress::NATIVE }
 
-    pub const fn new(workchain: i32, hash: TonHash) -> Self { Self { workchain, hash } }
+    pub const fn new(workchain: i32, hash: TonHash) -> Self {
+        Self {
+            workchain,
+            hash,
+            bounceable: true,
+        }
+    }
+
+    pub fn is_bounceable(&self) -> bool { self.bounceable }
+
+    pub fn with_bounceable(mut self, bounceable: bool) -> Self {
+        self.bounceable = bounceable;
+        self
+    }
 
     pub fn from_msg_address<T: Into<MsgAddress>>(msg_address: T) -> Result<Self, TonCoreError> {
         match msg_address.into() {
@@ -47,27 +71,35 @@ impl TonAddress {
         }
     }
 
-    pub fn to_hex(&self) -> String { format!("{}:{}", self.workchain, hex::encode(self.hash.as_slice())) }
+    pub fn to_hex(&self) -> String { self.to_raw_string() }
+
+    pub fn to_raw_string(&self) -> String { format!("{}:{}", self.workchain, hex::encode(self.hash.as_slice())) }
+
+    pub fn to_base64(&self, bounceable: bool, testnet: bool) -> String {
+        STANDARD.encode(self.to_user_friendly_bytes(bounceable, testnet))
+    }
+
+    pub fn to_base64_url(&self, bounceable: bool, testnet: bool) -> String {
+        URL_SAFE_NO_PAD.encode(self.to_user_friendly_bytes(bounceable, testnet))
+    }
 
-    pub fn to_base64(&self, mainnet: bool, bounce: bool, urlsafe: bool) -> String {
+    // tag (1 byte) + workchain (1 byte) + hash (32 bytes) + crc16 (2 bytes)
+    fn to_user_friendly_bytes(&self, bounceable: bool, testnet: bool) -> [u8; 36] {
         let mut buf = [0; 36];
-        let tag: u8 = match (mainnet, bounce) {
-            (true, true) => 0x11,
-            (true, false) => 0x51,
-            (false, true) => 0x91,
-            (false, false) => 0xD1,
-        };
+        let mut tag = TAG_BOUNCEABLE;
+        if !bounceable {
+            tag |= TAG_NON_BOUNCEABLE_FLAG;
+        }
+        if testnet {
+            tag |= TAG_TESTNET_FLAG;
+        }
         buf[0] = tag;
         buf[1] = (self.workchain & 0xff) as u8;
         buf[2..34].clone_from_slice(self.hash.as_slice());
         let crc = CRC_16_XMODEM.checksum(&buf[0..34]);
         buf[34] = ((crc >> 8) & 0xff) as u8;
         buf[35] = (crc & 0xff) as u8;
-        if urlsafe {
-            URL_SAFE_NO_PAD.encode(buf)
-        } else {
-            STANDARD.encode(buf)
-        }
+        buf
     }
 
     pub fn to_msg_address_none(&self) -> Result<MsgAddressNone, TonCoreError> {
@@ -114,9 +146,25 @@ impl TryFrom<&str> for TonAddress {
     fn try_from(value: &str) -> Result<Self, Self::Error> { TonAddress::from_str(value) }
 }
 
+/// Strict conversion: only MsgAddressInt is accepted, addr_none and addr_extern are rejected
+///
+/// Use `TonAddress::from_msg_address` to get `TonAddress::ZERO` for addr_none
+impl TryFrom<MsgAddress> for TonAddress {
+    type Error = TonCoreError;
+    fn try_from(value: MsgAddress) -> Result<Self, Self::Error> {
+        match value {
+            MsgAddress::Int(int) => from_msg_address_int(&int),
+            MsgAddress::Ext(ext) => bail_ton_core_data!("Can't make TonAddress from external address: {ext:?}"),
+        }
+    }
+}
+
 impl Display for TonAddress {
     fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
-        f.write_str(&self.to_base64(true, true, true))
+        if f.alternate() {
+            return f.write_str(&self.to_raw_string());
+        }
+        f.write_str(&self.to_base64_url(self.bounceable, false))
     }
 }
 
@@ -126,6 +174,19 @@ impl Debug for TonAddress {
     }
 }
 
+impl PartialEq for TonAddress {
+    fn eq(&self, other: &Self) -> bool { self.workchain == other.workchain && self.hash == other.hash }
+}
+
+impl Eq for TonAddress {}
+
+impl Hash for TonAddress {
+    fn hash<H: Hasher>(&self, state: &mut H) {
+        self.workchain.hash(state);
+        self.hash.hash(state);
+    }
+}
+
 impl PartialOrd for TonAddress {
     fn partial_cmp(&self, other: &Self) -> Option<Ordering> {
         if self == other {
@@ -174,6 +235,9 @@ fn from_hex<T: AsRef<str>>(addr: T) -> Result<TonAddress, TonCoreError> {
     }
 
     let wc = parts[0].parse::<i32>()?;
+    if parts[1].len() != 64 {
+        raise_address_error(addr_str, format!("expecting 64 hex chars in hash, got {}", parts[1].len()))?;
+    }
 
     let hash = TonHash::from_vec(hex::decode(parts[1])?)?;
     Ok(TonAddress::new(wc, hash))
@@ -189,9 +253,15 @@ fn from_bytes(bytes: &[u8], addr_str: &str) -> Result<TonAddress, TonCoreError>
         raise_address_error(addr_str, "crc32 mismatch")?;
     }
 
+    let tag = bytes[0];
+    if tag & !(TAG_NON_BOUNCEABLE_FLAG | TAG_TESTNET_FLAG) != TAG_BOUNCEABLE {
+        raise_address_error(addr_str, format!("unexpected tag {tag:#04x}"))?;
+    }
+
     let address = TonAddress {
         workchain: bytes[1] as i8 as i32,
         hash: TonHash::from_slice(&bytes[2..34])?,
+        bounceable: tag & TAG_NON_BOUNCEABLE_FLAG == 0,
     };
     Ok(address)
 }
@@ -253,12 +323,12 @@ mod tests {
         let bytes = TonHash::from_str("e4d954ef9f4e1250a26b5bbad76a1cdd17cfd08babad6f4c23e372270aef6f76")?;
         let addr = TonAddress::new(0, bytes);
         assert_eq!(addr.to_hex(), "0:e4d954ef9f4e1250a26b5bbad76a1cdd17cfd08babad6f4c23e372270aef6f76");
-        assert_eq!(addr.to_base64(true, true, true), "EQDk2VTvn04SUKJrW7rXahzdF8_Qi6utb0wj43InCu9vdjrR");
-        assert_eq!(addr.to_base64(true, true, false), "EQDk2VTvn04SUKJrW7rXahzdF8/Qi6utb0wj43InCu9vdjrR");
-        assert_eq!(addr.to_base64(true, true, true), addr.to_string());
+        assert_eq!(addr.to_base64_url(true, false), "EQDk2VTvn04SUKJrW7rXahzdF8_Qi6utb0wj43InCu9vdjrR");
+        assert_eq!(addr.to_base64(true, false), "EQDk2VTvn04SUKJrW7rXahzdF8/Qi6utb0wj43InCu9vdjrR");
+        assert_eq!(addr.to_base64_url(true, false), addr.to_string());
 
         let elector = TonAddress::from_str("-1:3333333333333333333333333333333333333333333333333333333333333333")?;
-        assert_eq!(elector.to_base64(false, true, true), "kf8zMzMzMzMzMzMzMzMzMzMzMzMzMzMzMzMzMzMzMzMzM_BP");
+        assert_eq!(elector.to_base64_url(true, true), "kf8zMzMzMzMzMzMzMzMzMzMzMzMzMzMzMzMzMzMzMzMzM_BP");
         Ok(())
     }
 
@@ -272,6 +342,66 @@ mod tests {
         Ok(())
     }
 
+    #[test]
+    fn test_ton_address_user_friendly_flags() -> anyhow::Result<()> {
+        let hash = TonHash::from_str("e4d954ef9f4e1250a26b5bbad76a1cdd17cfd08babad6f4c23e372270aef6f76")?;
+        let addr = TonAddress::new(0, hash);
+        assert_eq!(addr.to_base64_url(true, false), "EQDk2VTvn04SUKJrW7rXahzdF8_Qi6utb0wj43InCu9vdjrR");
+        assert_eq!(addr.to_base64_url(false, false), "UQDk2VTvn04SUKJrW7rXahzdF8_Qi6utb0wj43InCu9vdmcU");
+        assert_eq!(addr.to_base64_url(true, true), "kQDk2VTvn04SUKJrW7rXahzdF8_Qi6utb0wj43InCu9vdoFb");
+        assert_eq!(addr.to_base64_url(false, true), "0QDk2VTvn04SUKJrW7rXahzdF8_Qi6utb0wj43InCu9vdtye");
+        assert_eq!(addr.to_base64(false, false), "UQDk2VTvn04SUKJrW7rXahzdF8/Qi6utb0wj43InCu9vdmcU");
+
+        for (addr_str, bounceable) in [
+            ("EQDk2VTvn04SUKJrW7rXahzdF8_Qi6utb0wj43InCu9vdjrR", true),
+            ("UQDk2VTvn04SUKJrW7rXahzdF8_Qi6utb0wj43InCu9vdmcU", false),
+            ("kQDk2VTvn04SUKJrW7rXahzdF8_Qi6utb0wj43InCu9vdoFb", true),
+            ("0QDk2VTvn04SUKJrW7rXahzdF8/Qi6utb0wj43InCu9vdtye", false),
+        ] {
+            let parsed = TonAddress::from_str(addr_str)?;
+            assert_eq!(parsed, addr);
+            assert_eq!(parsed.is_bounceable(), bounceable);
+        }
+
+        // default round-trip keeps bounceable flag
+        let non_bounceable = TonAddress::from_str("UQDk2VTvn04SUKJrW7rXahzdF8_Qi6utb0wj43InCu9vdmcU")?;
+        assert_eq!(non_bounceable.to_string(), "UQDk2VTvn04SUKJrW7rXahzdF8_Qi6utb0wj43InCu9vdmcU");
+        assert_eq!(non_bounceable.clone().with_bounceable(true).to_string(), addr.to_string());
+
+        // unknown tag
+        let mut bytes = addr.to_user_friendly_bytes(true, false);
+        bytes[0] = 0x12;
+        let crc = CRC_16_XMODEM.checksum(&bytes[0..34]);
+        bytes[34..].copy_from_slice(&crc.to_be_bytes());
+        assert_err!(TonAddress::from_str(&URL_SAFE_NO_PAD.encode(bytes)));
+        Ok(())
+    }
+
+    #[test]
+    fn test_ton_address_raw() -> anyhow::Result<()> {
+        for raw in [
+            "0:e4d954ef9f4e1250a26b5bbad76a1cdd17cfd08babad6f4c23e372270aef6f76",
+            "-1:3333333333333333333333333333333333333333333333333333333333333333",
+        ] {
+            let addr = TonAddress::from_str(raw)?;
+            assert_eq!(addr.to_raw_string(), raw);
+            assert_eq!(format!("{addr:#}"), raw);
+            // Display doesn't depend on the parsed form
+            assert_eq!(addr.to_string(), addr.to_base64_url(true, false));
+            assert_eq!(TonAddress::from_str(&addr.to_string())?, addr);
+        }
+        let elector = TonAddress::from_str("-1:3333333333333333333333333333333333333333333333333333333333333333")?;
+        assert_eq!(elector.workchain, -1);
+        assert_eq!(elector, TonAddress::from_str("Ef8zMzMzMzMzMzMzMzMzMzMzMzMzMzMzMzMzMzMzMzMzM0vF")?);
+
+        // hash must be exactly 64 hex chars
+        assert_err!(TonAddress::from_str("0:e4d954ef9f4e1250a26b5bbad76a1cdd17cfd08babad6f4c23e372270aef6f7"));
+        assert_err!(TonAddress::from_str("0:e4d954ef9f4e1250a26b5bbad76a1cdd17cfd08babad6f4c23e372270aef6f7600"));
+        assert_err!(TonAddress::from_str("0:z4d954ef9f4e1250a26b5bbad76a1cdd17cfd08babad6f4c23e372270aef6f76"));
+        assert_err!(TonAddress::from_str("x:e4d954ef9f4e1250a26b5bbad76a1cdd17cfd08babad6f4c23e372270aef6f76"));
+        Ok(())
+    }
+
     #[test]
     fn test_ton_address_try_from_str() -> anyhow::Result<()> {
         let bytes = TonHash::from_str("e4d954ef9f4e1250a26b5bbad76a1cdd17cfd08babad6f4c23e372270aef6f76")?;
@@ -319,6 +449,18 @@ mod tests {
         Ok(())
     }
 
+    #[test]
+    fn test_ton_address_try_from_msg_address() -> anyhow::Result<()> {
+        let address = TonAddress::from_str("EQDk2VTvn04SUKJrW7rXahzdF8_Qi6utb0wj43InCu9vdjrR")?;
+        assert_eq!(TonAddress::try_from(address.to_msg_address())?, address);
+        assert_err!(TonAddress::try_from(MsgAddress::NONE));
+        assert_eq!(TonAddress::from_msg_address(MsgAddress::NONE)?, TonAddress::ZERO);
+        assert_err!(TonAddress::try_from(MsgAddress::from(MsgAddressExtern {
+            address: VarLenBits::new(vec![1, 2, 3], 24),
+        })));
+        Ok(())
+    }
+
     #[test]
     fn test_ton_address_tlb_type() -> anyhow::Result<()> {
         for addr in [
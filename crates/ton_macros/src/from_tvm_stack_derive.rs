@@ -9,6 +9,13 @@ struct HeaderAttributes {
     allow_extra: Option<bool>,  // use false as default
 }
 
+#[derive(deluxe::ExtractAttributes)]
+#[deluxe(attributes(from_tvm_stack))]
+struct FieldAttributes {
+    #[deluxe(default)]
+    skip: bool, // drop stack value, use Default::default() for the field
+}
+
 pub fn from_tvm_stack_derive_impl(input: proc_macro::TokenStream) -> TokenStream {
     let mut input = syn::parse::<syn::DeriveInput>(input).unwrap();
     let header_attrs: HeaderAttributes = match deluxe::extract_attributes(&mut input) {
@@ -20,7 +27,7 @@ pub fn from_tvm_stack_derive_impl(input: proc_macro::TokenStream) -> TokenStream
 
     let name = input.ident;
     let fields = if let syn::Data::Struct(syn::DataStruct {
-        fields: syn::Fields::Named(syn::FieldsNamed { ref named, .. }),
+        fields: syn::Fields::Named(syn::FieldsNamed { ref mut named, .. }),
         ..
     }) = input.data
     {
@@ -30,13 +37,28 @@ pub fn from_tvm_stack_derive_impl(input: proc_macro::TokenStream) -> TokenStream
         unimplemented!("Now it is implemented only for ordinary structs with named fields")
     };
 
+    let mut skipped = Vec::with_capacity(fields.len());
+    for field in fields.iter_mut() {
+        let field_attrs: FieldAttributes = match deluxe::extract_attributes(&mut field.attrs) {
+            Ok(desc) => desc,
+            Err(e) => return e.into_compile_error(),
+        };
+        skipped.push(field_attrs.skip);
+    }
+
     let members_count = fields.len();
-    let names = fields.clone().into_iter().map(|f| {
+    let names = fields.iter().map(|f| {
         let name = &f.ident;
         quote! {#name}
     });
-    let assigns = fields.into_iter().rev().map(|f| {
+    let assigns = fields.iter().zip(skipped).rev().map(|(f, skip)| {
         let name = &f.ident;
+        if skip {
+            return quote! {
+                let _ = stack.pop_checked()?;
+                let #name = Default::default();
+            };
+        }
         quote! {
             let #name = #crate_path::block_tlb::FromTVMStack::from_stack(stack)?;
         }
@@ -21,6 +21,8 @@ pub fn tlb_derive(input: TokenStream) -> TokenStream { tlb_derive_impl(input).in
 /// Supports `#[from_tvm_stack(ensure_empty = true)]` to reject remaining stack
 /// values after parsing and `#[from_tvm_stack(allow_extra = true)]` to discard
 /// top stack values above the derived struct fields before parsing.
+/// Field attribute `#[from_tvm_stack(skip)]` drops the corresponding stack value
+/// and initializes the field with `Default::default()`.
 #[proc_macro_derive(FromTVMStack, attributes(from_tvm_stack))]
 pub fn from_tvm_stack_derive(input: TokenStream) -> TokenStream { from_tvm_stack_derive_impl(input).into() }
 
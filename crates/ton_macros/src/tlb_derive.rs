@@ -76,6 +76,7 @@ pub(crate) fn tlb_derive_impl(input: proc_macro::TokenStream) -> TokenStream {
     };
 
     let ident = &input.ident;
+    let ident_str = ident.to_string();
 
     // Use original generics for type usage (may include defaults), but strip defaults for impl
     let ty_generics = input.generics.split_for_impl().1;
@@ -111,6 +112,8 @@ pub(crate) fn tlb_derive_impl(input: proc_macro::TokenStream) -> TokenStream {
             fn write_definition(&self, builder: &mut #crate_path::cell::CellBuilder) -> Result<(), #crate_path::errors::TonCoreError> {
                 #write_def_tokens
             }
+
+            fn tlb_type_name() -> &'static str { #ident_str }
         }
 
         #extra_impl_tokens
@@ -0,0 +1,29 @@
+// Builds a signed wallet transfer entirely offline.
+// Works with `ton = { default-features = false }`, so no lite_client / tonlib / tokio are involved:
+// cargo run -p examples --example no_network --no-default-features
+use std::str::FromStr;
+use ton::ton_wallet::{TonWallet, WalletTransfer, WalletVersion};
+use ton_core::cell::TonCell;
+use ton_core::traits::tlb::TLB;
+use ton_core::types::{Coins, TonAddress};
+
+const MNEMONIC_STR: &str = "fancy carpet hello mandate penalty trial consider property top vicious exit rebuild tragic profit urban major total month holiday sudden rib gather media vicious";
+
+fn main() -> anyhow::Result<()> {
+    let wallet = TonWallet::new_with_creds(WalletVersion::V4R2, MNEMONIC_STR, None)?;
+    let dest = TonAddress::from_str("EQCxE6mUtQJKFnGfaROTKOt1lZbDiiX1kCixRv7Nw2Id_sDs")?;
+    let transfer = WalletTransfer::new(dest, Coins::new(10_000_000));
+
+    // seqno and valid_until must be taken from the wallet state & current time when sending for real
+    let seqno = 0;
+    let valid_until = 1_900_000_000;
+    let ext_msg = wallet.build_external(&[transfer], seqno, valid_until)?;
+    let boc = ext_msg.to_boc()?;
+
+    // the same bytes can later be sent by any client
+    assert_eq!(TonCell::from_boc(boc.clone())?, ext_msg);
+    println!("wallet: {}", wallet.address);
+    println!("msg_hash: {}", ext_msg.cell_hash()?);
+    println!("boc: {}", hex::encode(boc));
+    Ok(())
+}